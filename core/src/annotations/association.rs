@@ -1,4 +1,4 @@
-use annotation_rs::Annotation;
+use annotation_rs::{Annotation, AnnotationEnumValue};
 
 /// Annotation of Association.
 /// Used on owning side field.
@@ -21,6 +21,26 @@ pub struct Association {
     /// unique association
     #[field(default = false)]
     pub unique: bool,
+    /// Action on the owning side when the referenced row is deleted. default to be `no_action`.
+    #[field(enum_value = true, default = "no_action")]
+    pub on_delete: ReferentialAction,
+    /// Action on the owning side when the referenced columns are updated. default to be
+    /// `no_action`.
+    #[field(enum_value = true, default = "no_action")]
+    pub on_update: ReferentialAction,
+}
+
+/// Referential action of foreign key
+/// * "no_action" and "restrict" reject the change while the referenced row is still associated
+/// * "cascade" deletes or updates the owning side rows together
+/// * "set_null" sets the columns of owning side to null, field must be nullable
+#[derive(AnnotationEnumValue, Copy, Clone, Debug, Eq, PartialEq)]
+#[mod_path = "yukino::annotations"]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
 }
 
 /// Annotation of InverseAssociation.
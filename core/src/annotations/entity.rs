@@ -15,6 +15,26 @@ pub struct Entity {
     pub name: Option<String>,
     /// Index annotations of entity, mapped by index name.
     pub indexes: Option<HashMap<String, Index>>,
+    /// Check constraints of entity, boolean expressions on fields mapped by constraint name.
+    pub checks: Option<HashMap<String, String>>,
+}
+
+/// Annotation of embeddable struct.
+/// Declare a struct to be a value object which can be embedded into entities by `Embedded` field.
+/// It will not generate a table.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct Embeddable;
+
+/// Annotation of tree structured entity.
+/// Used with `Entity` on entity which associates with itself by a parent field, helpers for
+/// ancestors and descendants will be generated on entity proxy.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct Tree {
+    /// Name of the nullable self-referencing association field. default to be `parent`.
+    #[field(default = "parent")]
+    pub parent: String,
 }
 
 /// Annotation of Index
@@ -22,7 +42,8 @@ pub struct Entity {
 #[derive(Annotation, Clone)]
 #[mod_path = "yukino::annotations"]
 pub struct Index {
-    /// Name of fields contained in the index.
+    /// Key parts of the index. Each of them is a field name or an expression on fields, optionally
+    /// followed by `asc` or `desc`, e.g. `["lower(email)", "created_at desc"]`.
     pub fields: Vec<String>,
     /// Index method. default to be `b_tree`.
     #[field(enum_value = true, default = "b_tree")]
@@ -30,6 +51,10 @@ pub struct Index {
     /// Is unique index. default to be false.
     #[field(default = false)]
     pub unique: bool,
+    /// Name of non-key fields covered by the index, not available on `mysql` and `sqlite`.
+    pub include: Option<Vec<String>>,
+    /// Condition of partial index, a boolean expression on fields. Not available on `mysql`.
+    pub condition: Option<String>,
 }
 
 /// Index Method enum
@@ -0,0 +1,26 @@
+#[doc(hidden)]
+use annotation_rs::{Annotation, AnnotationEnumValue};
+
+/// Annotation of enumeration.
+/// It can be used on enum declared in schema file. Only unit variants are supported, and the enum
+/// can be used as the type of entity fields. If a enum doesn't have a Enumeration annotation, it
+/// will be stored as string.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct Enumeration {
+    /// Column type used to store the variants. default to be `string`.
+    #[field(enum_value = true, default = "string")]
+    pub storage: EnumStorage,
+}
+
+/// Storage of enumeration
+/// * "string" stores the name of variant
+/// * "integer" stores the discriminant of variant
+/// * "native" stores the name of variant in native `ENUM` column
+#[derive(AnnotationEnumValue, Copy, Clone, Debug, Eq, PartialEq)]
+#[mod_path = "yukino::annotations"]
+pub enum EnumStorage {
+    String,
+    Integer,
+    Native,
+}
@@ -10,6 +10,27 @@ use std::collections::HashMap;
 #[mod_path = "yukino::annotations"]
 pub struct ID;
 
+/// Announce a field as version of optimistic locking.
+/// It can be used on integer or date time field of entity. Version is increased on every update,
+/// and the update fails if the row has been changed since the entity was loaded.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct Version;
+
+/// Announce a field as creation time of entity.
+/// It can be used on date time or timestamp field of entity, the field is filled by current time
+/// when entity is inserted.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct CreatedAt;
+
+/// Announce a field as last modification time of entity.
+/// It can be used on date time or timestamp field of entity, the field is filled by current time
+/// when entity is inserted, and refreshed when other fields of entity are changed.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct UpdatedAt;
+
 /// Annotation of field.
 /// It can be used on field of entity.
 /// If a field doesn't have a Field annotation, it will be generate automatically.
@@ -25,6 +46,23 @@ pub struct Field {
     /// Auto increase field. default to be false.
     #[field(default = false)]
     pub auto_increase: bool,
+    /// Default value of column, an expression checked against the type of field, e.g. `0` or
+    /// `now()`.
+    pub default: Option<String>,
+    /// Expression of generated column, computed by database from other fields of entity.
+    pub generated: Option<String>,
+    /// Check constraint of field, a boolean expression in which `value` is the field itself, e.g.
+    /// `value > 0`.
+    pub check: Option<String>,
+    /// Max length of string field, `VARCHAR(255)` is used if empty.
+    pub length: Option<u32>,
+    /// Store string field in `TEXT` column without length limit. default to be false.
+    #[field(default = false)]
+    pub text: bool,
+    /// Character set of string column, only available on `mysql`.
+    pub charset: Option<String>,
+    /// Collation of string column.
+    pub collation: Option<String>,
     /// Optional config.
     pub options: Option<HashMap<String, String>>,
 }
@@ -33,3 +71,25 @@ pub struct Field {
 #[derive(Annotation, Clone)]
 #[mod_path = "yukino::annotations"]
 pub struct Ignore;
+
+/// Annotation of embedded field.
+/// It can be used on field of entity, and the type of the field must be a struct marked as
+/// `Embeddable`. Fields of the struct will be flattened into columns of the owning table.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct Embedded {
+    /// Prefix of flattened column names. If empty, it will be generated based on the name of the
+    /// field, e.g. `shipping_` for field `shipping`.
+    pub prefix: Option<String>,
+}
+
+/// Annotation of newtype field.
+/// Tuple structs with a single field declared in schema file are recognized automatically, this
+/// annotation is only needed for newtypes declared outside, which must implement
+/// `yukino::resolver::Newtype`.
+#[derive(Annotation, Clone)]
+#[mod_path = "yukino::annotations"]
+pub struct Newtype {
+    /// Wrapped type, e.g. `u64` for `struct UserId(u64)`.
+    pub inner: String,
+}
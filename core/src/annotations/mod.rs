@@ -1,26 +1,40 @@
 mod association;
 mod entity;
+mod enumeration;
 mod field;
 
 use annotation_rs::AnnotationStructure;
 pub use association::*;
 pub use entity::*;
+pub use enumeration::*;
 pub use field::*;
 use quote::ToTokens;
 use syn::{Attribute, Error};
 
+#[derive(Clone)]
 pub enum FieldAnnotation {
     ID(ID),
+    Version(Version),
+    CreatedAt(CreatedAt),
+    UpdatedAt(UpdatedAt),
     Ignore(Ignore),
     Field(Field),
     Association(Association),
     InverseAssociation(InverseAssociation),
+    Embedded(Embedded),
+    Newtype(Newtype),
 }
 
 impl FieldAnnotation {
     pub fn from_attr(attr: &Attribute) -> Result<Self, Error> {
         if attr.path == ID::get_path() {
             Ok(FieldAnnotation::ID(ID))
+        } else if attr.path == Version::get_path() {
+            Ok(FieldAnnotation::Version(Version))
+        } else if attr.path == CreatedAt::get_path() {
+            Ok(FieldAnnotation::CreatedAt(CreatedAt))
+        } else if attr.path == UpdatedAt::get_path() {
+            Ok(FieldAnnotation::UpdatedAt(UpdatedAt))
         } else if attr.path == Field::get_path() {
             Ok(FieldAnnotation::Field(Field::from_meta(
                 &attr.parse_meta()?,
@@ -33,6 +47,14 @@ impl FieldAnnotation {
             Ok(FieldAnnotation::InverseAssociation(
                 InverseAssociation::from_meta(&attr.parse_meta()?)?,
             ))
+        } else if attr.path == Embedded::get_path() {
+            Ok(FieldAnnotation::Embedded(Embedded::from_meta(
+                &attr.parse_meta()?,
+            )?))
+        } else if attr.path == Newtype::get_path() {
+            Ok(FieldAnnotation::Newtype(Newtype::from_meta(
+                &attr.parse_meta()?,
+            )?))
         } else {
             Err(Error::new_spanned(
                 attr,
@@ -0,0 +1,574 @@
+use crate::annotations::{Association, FieldAnnotation, ReferentialAction};
+use crate::association::{AssociatedEntityTypeResolver, FakeEntity};
+use crate::definitions::{
+    AssociationDefinition, ColumnDefinition, ColumnType, FieldDefinition, ForeignKeyDefinition,
+    TableDefinition, TableType,
+};
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
+    FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, TypePathResolver, ValueConverter,
+};
+use crate::types::{TypeResolver, ValuePack};
+use crate::Entity;
+use heck::SnakeCase;
+use iroha::ToTokens;
+use proc_macro2::Ident;
+use quote::{format_ident, quote, ToTokens};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use syn::{GenericArgument, PathArguments, Type, TypePath};
+
+/// Entities associated by membership table, changes are recorded until they are committed
+#[derive(Clone)]
+pub struct AssociatedCollection<E>
+where
+    E: Entity + Clone,
+{
+    entities: Option<Vec<E>>,
+    added: Vec<E>,
+    removed: Vec<ValuePack>,
+}
+
+impl<E> AssociatedCollection<E>
+where
+    E: Entity + Clone,
+{
+    pub fn resolved(&self) -> bool {
+        self.entities.is_some()
+    }
+
+    pub fn get(&self) -> Option<&Vec<E>> {
+        self.entities.as_ref()
+    }
+
+    /// Resolve collection by entities loaded from database, pending changes are applied on them
+    pub fn resolve(&mut self, entities: Vec<E>) -> Result<(), DataConvertError> {
+        let mut result = vec![];
+
+        for entity in entities {
+            if !self.removed.contains(&entity.primary_key_values()?) {
+                result.push(entity);
+            }
+        }
+
+        result.extend(self.added.iter().cloned());
+        self.entities = Some(result);
+
+        Ok(())
+    }
+
+    pub fn add(&mut self, entity: E) -> Result<(), DataConvertError> {
+        let primary_key = entity.primary_key_values()?;
+
+        if let Some(entities) = self.entities.as_mut() {
+            entities.push(entity.clone());
+        }
+
+        // Re-added entity is still stored in membership table
+        let removed_count = self.removed.len();
+        self.removed.retain(|removed| removed != &primary_key);
+
+        if self.removed.len() == removed_count {
+            self.added.push(entity);
+        }
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, entity: &E) -> Result<(), DataConvertError> {
+        let primary_key = entity.primary_key_values()?;
+
+        if let Some(entities) = self.entities.as_mut() {
+            let mut result = vec![];
+            for entity in entities.drain(..) {
+                if entity.primary_key_values()? != primary_key {
+                    result.push(entity);
+                }
+            }
+
+            *entities = result;
+        }
+
+        let added_count = self.added.len();
+        let mut added = vec![];
+        for entity in self.added.drain(..) {
+            if entity.primary_key_values()? != primary_key {
+                added.push(entity);
+            }
+        }
+
+        // Pending added entity is not stored in membership table yet
+        if added.len() == added_count && !self.removed.contains(&primary_key) {
+            self.removed.push(primary_key);
+        }
+
+        self.added = added;
+
+        Ok(())
+    }
+
+    /// Entities added since the collection was loaded
+    pub fn added(&self) -> &[E] {
+        &self.added
+    }
+
+    /// Primary key values of entities removed since the collection was loaded
+    pub fn removed(&self) -> &[ValuePack] {
+        &self.removed
+    }
+}
+
+impl<E> Default for AssociatedCollection<E>
+where
+    E: Entity + Clone,
+{
+    fn default() -> Self {
+        AssociatedCollection {
+            entities: None,
+            added: vec![],
+            removed: vec![],
+        }
+    }
+}
+
+/// Value converter of associated collection field, values are stored in membership table
+#[derive(ToTokens)]
+#[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+pub struct AssociatedCollectionValueConverter<E: Entity + Clone> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: Entity + Clone> ValueConverter<AssociatedCollection<E>>
+    for AssociatedCollectionValueConverter<E>
+{
+    fn to_field_value(
+        &self,
+        _values: &ValuePack,
+    ) -> Result<AssociatedCollection<E>, DataConvertError> {
+        Ok(AssociatedCollection::default())
+    }
+
+    fn to_database_values_by_ref(
+        &self,
+        _value: &AssociatedCollection<E>,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+
+    fn primary_column_values_by_ref(
+        &self,
+        _value: &AssociatedCollection<E>,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+pub struct AssociatedCollectionFieldResolverSeed;
+
+impl FieldResolverSeed for AssociatedCollectionFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        AssociatedCollectionFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(AssociatedCollectionFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let unexpected_generic = || {
+            Some(Err(ResolveError::UnexpectedFieldGeneric(
+                entity_name.clone(),
+                ident.to_string(),
+            )))
+        };
+
+        let type_path = match field_type {
+            Type::Path(type_path) => type_path_resolver.get_full_path(type_path.clone()),
+            _ => return None,
+        };
+
+        let last_segment = type_path.path.segments.last()?;
+        if last_segment.ident != "AssociatedCollection" {
+            return None;
+        }
+
+        let proxy_type_path = match &last_segment.arguments {
+            PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+                match arguments.args.first() {
+                    Some(GenericArgument::Type(Type::Path(nested_type_path))) => {
+                        nested_type_path.clone()
+                    }
+                    _ => return unexpected_generic(),
+                }
+            }
+            _ => return unexpected_generic(),
+        };
+
+        let target_ident = match proxy_type_path.path.get_ident() {
+            Some(target_ident) => target_ident.clone(),
+            None => return unexpected_generic(),
+        };
+
+        if Self::is_primary_key(annotations) {
+            return Some(Err(ResolveError::Others(format!(
+                "Associated collection field({} in {}) can not be primary key",
+                ident, entity_name
+            ))));
+        }
+
+        let association = annotations
+            .iter()
+            .filter_map(|attr| match attr {
+                FieldAnnotation::Association(association) => Some(association.clone()),
+                _ => None,
+            })
+            .next()
+            .unwrap_or(Association {
+                mapped_by: None,
+                unique: false,
+                on_delete: ReferentialAction::NoAction,
+                on_update: ReferentialAction::NoAction,
+            });
+
+        let inner_type_path = TypePath {
+            qself: None,
+            path: format_ident!("{}Inner", target_ident).into(),
+        };
+
+        Some(Ok(Box::new(AssociatedCollectionFieldResolver {
+            field_path: (entity_name, ident.to_string()),
+            status: FieldResolverStatus::WaitingForEntity(target_ident.to_string()),
+            association,
+            proxy_type: Type::Path(proxy_type_path),
+            inner_type: Type::Path(inner_type_path),
+            owner: None,
+            target: None,
+        })))
+    }
+}
+
+/// Resolved side of membership table, table name and list of (membership column, referenced column)
+struct MembershipSide {
+    table: String,
+    columns: Vec<ColumnDefinition>,
+    column_map: Vec<(String, String)>,
+}
+
+pub struct AssociatedCollectionFieldResolver {
+    field_path: FieldPath,
+    status: FieldResolverStatus,
+    association: Association,
+    proxy_type: Type,
+    inner_type: Type,
+    owner: Option<MembershipSide>,
+    target: Option<MembershipSide>,
+}
+
+impl AssociatedCollectionFieldResolver {
+    fn resolve_side(
+        &self,
+        resolver: &EntityResolver,
+        fields: &[String],
+        prefix: &str,
+    ) -> Result<MembershipSide, ResolveError> {
+        if fields.is_empty() {
+            return Err(ResolveError::MissingPrimaryKey(
+                self.field_path.0.clone(),
+                self.field_path.1.clone(),
+                resolver.entity_name(),
+            ));
+        } else if !resolver.is_unique_fields(fields)? {
+            return Err(ResolveError::MappingFieldsNotUnique(
+                self.field_path.0.clone(),
+                self.field_path.1.clone(),
+            ));
+        }
+
+        let mut columns = vec![];
+        let mut column_map = vec![];
+
+        for field in fields {
+            for definition in resolver.get_field_resolver(field)?.columns.iter() {
+                let name = format!("{}_{}", prefix, definition.name);
+
+                column_map.push((name.clone(), definition.name.clone()));
+                columns.push(ColumnDefinition {
+                    name,
+                    ty: ColumnType::VisualColumn,
+                    data_type: definition.data_type,
+                    unique: false,
+                    auto_increase: false,
+                    primary_key: true,
+                    nullable: false,
+                    default: None,
+                    charset: definition.charset.clone(),
+                    collation: definition.collation.clone(),
+                    enum_variants: definition.enum_variants.clone(),
+                });
+            }
+        }
+
+        Ok(MembershipSide {
+            table: resolver.table_name(),
+            columns,
+            column_map,
+        })
+    }
+
+    fn resolve_owner(&mut self, resolver: &EntityResolver) -> Result<(), ResolveError> {
+        let primary_fields = resolver.get_primary_columns()?;
+        let owner = self.resolve_side(resolver, &primary_fields, &resolver.table_name())?;
+        self.owner = Some(owner);
+
+        Ok(())
+    }
+
+    fn resolve_target(&mut self, resolver: &EntityResolver) -> Result<(), ResolveError> {
+        let fields = match &self.association.mapped_by {
+            Some(fields) => fields.clone(),
+            None => resolver.get_primary_columns()?,
+        };
+        let target = self.resolve_side(resolver, &fields, &self.field_path.1.to_snake_case())?;
+        self.target = Some(target);
+
+        Ok(())
+    }
+
+    fn membership_table_name(&self) -> String {
+        format!(
+            "{}_{}",
+            self.owner.as_ref().unwrap().table,
+            self.field_path.1.to_snake_case()
+        )
+    }
+}
+
+impl FieldResolver for AssociatedCollectionFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        self.status.clone()
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        match self.status.clone() {
+            FieldResolverStatus::WaitingForEntity(waited_entity) => {
+                assert_eq!(waited_entity, resolver.entity_name());
+
+                if self.target.is_none() {
+                    self.resolve_target(resolver)?;
+                }
+
+                if resolver.entity_name() == self.field_path.0 {
+                    self.resolve_owner(resolver)?;
+                }
+
+                self.status = if self.owner.is_some() {
+                    FieldResolverStatus::WaitingAssemble
+                } else {
+                    FieldResolverStatus::WaitingForEntity(self.field_path.0.clone())
+                };
+
+                Ok(self.status())
+            }
+            s => Err(ResolveError::UnexpectedFieldResolverStatus(
+                self.field_path.0.clone(),
+                self.field_path.1.clone(),
+                "WaitingForEntity".to_string(),
+                s,
+            )),
+        }
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        _resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn is_deferred(&self) -> bool {
+        true
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let (owner, target) = match (&self.status, &self.owner, &self.target) {
+            (FieldResolverStatus::WaitingAssemble, Some(owner), Some(target)) => (owner, target),
+            _ => {
+                return Err(ResolveError::UnexpectedFieldResolverStatus(
+                    self.field_path.0.clone(),
+                    self.field_path.1.clone(),
+                    "WaitingAssemble".to_string(),
+                    self.status(),
+                ))
+            }
+        };
+
+        let entity_name = self.field_path.0.clone();
+        let field_snake_case = self.field_path.1.to_snake_case();
+        let membership_table = self.membership_table_name();
+        let target_name = self.proxy_type.to_token_stream().to_string();
+
+        let converter_getter_name = self.converter_getter_ident();
+        let convert = AssociatedCollectionValueConverter {
+            _marker: PhantomData::<FakeEntity>::default(),
+        };
+        let inner_type = &self.inner_type;
+
+        let data_converter_token_stream = quote! {
+            pub fn #converter_getter_name() -> yukino::resolver::field_resolver_seeds::AssociatedCollectionValueConverter::<#inner_type> {
+                #convert
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let adder_name = format_ident!("add_{}", field_snake_case);
+        let remover_name = format_ident!("remove_{}", field_snake_case);
+        let field_ident = format_ident!("{}", self.field_path.1);
+        let proxy_type = &self.proxy_type;
+        let (membership_owner_columns, owner_columns): (Vec<_>, Vec<_>) =
+            owner.column_map.iter().cloned().unzip();
+        let (membership_target_columns, target_columns): (Vec<_>, Vec<_>) =
+            target.column_map.iter().cloned().unzip();
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> Vec<#proxy_type> {
+                use yukino::EntityProxy;
+                let inner = self.get_inner();
+
+                if !inner.#field_ident.resolved() {
+                    let values = yukino::Entity::to_database_values(inner).unwrap();
+                    let conditions: std::collections::HashMap<String, yukino::types::DatabaseValue> = vec![
+                        #((#membership_owner_columns.to_string(), values.get(#owner_columns).unwrap().clone())),*
+                    ].into_iter().collect();
+
+                    let result = self.get_transaction()
+                        .get_repository::<#inner_type>()
+                        .find_by_membership(
+                            #membership_table,
+                            &conditions,
+                            &[#((#membership_target_columns.to_string(), #target_columns.to_string())),*]
+                        );
+
+                    let mut_inner = self.get_inner_mut();
+                    mut_inner.#field_ident.resolve(result).unwrap();
+                }
+
+                inner.#field_ident.get().unwrap().iter().cloned().map(
+                    |entity| self.get_transaction().create_entity(move || entity)
+                ).collect()
+            }
+        };
+
+        let field_setter_token_stream = quote! {
+            pub fn #adder_name(
+                &mut self,
+                value: #proxy_type
+            ) -> Result<&mut Self, yukino::resolver::error::DataConvertError> {
+                use yukino::EntityProxy;
+                let mut_inner = self.get_inner_mut();
+                mut_inner.#field_ident.add(value.inner())?;
+
+                Ok(self)
+            }
+
+            pub fn #remover_name(
+                &mut self,
+                value: &#proxy_type
+            ) -> Result<&mut Self, yukino::resolver::error::DataConvertError> {
+                use yukino::EntityProxy;
+                let mut_inner = self.get_inner_mut();
+                mut_inner.#field_ident.remove(&value.inner())?;
+
+                Ok(self)
+            }
+        };
+
+        let mut columns = owner.columns.clone();
+        columns.extend(target.columns.iter().cloned());
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: vec![],
+            joined_table: vec![TableDefinition {
+                name: membership_table.clone(),
+                ty: TableType::MembershipTable(entity_name.clone(), target_name.clone()),
+                columns,
+                indexes: vec![],
+                // Membership rows never outlive either side of association
+                foreign_keys: vec![
+                    ForeignKeyDefinition {
+                        name: format!("__{}", owner.table),
+                        referenced_table: owner.table.clone(),
+                        column_map: owner.column_map.clone(),
+                        on_delete: ReferentialAction::Cascade,
+                        on_update: ReferentialAction::Cascade,
+                    },
+                    ForeignKeyDefinition {
+                        name: format!("__{}", self.field_path.1),
+                        referenced_table: target.table.clone(),
+                        column_map: target.column_map.clone(),
+                        on_delete: ReferentialAction::Cascade,
+                        on_update: ReferentialAction::Cascade,
+                    },
+                ],
+                checks: vec![],
+                version: None,
+                created_at: None,
+                updated_at: None,
+            }],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: converter_getter_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: adder_name,
+            field_setter_token_stream,
+            field_type: syn::parse_quote! {
+                yukino::collection::AssociatedCollection<#inner_type>
+            },
+            field_definition: FieldDefinition {
+                entity: entity_name,
+                name: self.field_path.1.clone(),
+                type_resolver_name: AssociatedEntityTypeResolver::seed().name(),
+                field_type: target_name.clone(),
+                nullable: false,
+                length: None,
+                default: None,
+                columns: vec![],
+                tables: vec![membership_table.clone()],
+                association: Some(AssociationDefinition {
+                    referenced_entity: target_name,
+                    is_list: true,
+                    column_map: owner
+                        .column_map
+                        .iter()
+                        .map(|(membership, column)| (column.clone(), membership.clone()))
+                        .collect(),
+                    membership: Some((membership_table, target.column_map.clone())),
+                }),
+            },
+            nested_field_definitions: vec![],
+        })
+    }
+}
@@ -1,13 +1,13 @@
-use crate::annotations::{Association, FieldAnnotation, IndexMethod};
+use crate::annotations::{Association, FieldAnnotation, IndexMethod, ReferentialAction};
 use crate::association::FakeEntity;
 use crate::definitions::{
     AssociationDefinition, ColumnDefinition, ColumnType, FieldDefinition, ForeignKeyDefinition,
-    IndexDefinition,
+    IndexColumn, IndexDefinition,
 };
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{
     Binary, BinaryOperator, ColumnIdent, Expr, JoinClause, JoinOn, JoinType, Literal, Locatable,
-    TableReference,
+    Location, TableReference,
 };
 use crate::query::type_check::TypeKind;
 use crate::resolver::error::{DataConvertError, ResolveError};
@@ -302,6 +302,8 @@ impl FieldResolverSeed for AssociatedEntityFieldResolverSeed {
             .unwrap_or(Association {
                 mapped_by: None,
                 unique: false,
+                on_delete: ReferentialAction::NoAction,
+                on_update: ReferentialAction::NoAction,
             });
 
         let last = type_path.path.segments.last_mut().unwrap();
@@ -311,17 +313,43 @@ impl FieldResolverSeed for AssociatedEntityFieldResolverSeed {
             *arg = GenericArgument::Type(Type::Path(nested_type.clone()));
         }
 
+        let primary_key = Self::is_primary_key(annotations);
+        // Entity name without path and generics, e.g. `Bar` of `crate::Bar<'t>`
+        let referenced_entity = match old_nested_type.path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => {
+                return Some(Err(ResolveError::UnexpectedFieldGeneric(
+                    entity_name,
+                    ident.to_string(),
+                )))
+            }
+        };
+        if primary_key && referenced_entity == entity_name {
+            return Some(Err(ResolveError::SelfReferencingPrimaryKey(
+                entity_name,
+                ident.to_string(),
+            )));
+        }
+
+        let set_null = association.on_delete == ReferentialAction::SetNull
+            || association.on_update == ReferentialAction::SetNull;
+        if set_null && (!nullable || primary_key) {
+            return Some(Err(ResolveError::InvalidReferentialAction(
+                entity_name,
+                ident.to_string(),
+            )));
+        }
+
         Some(Ok(Box::new(AssociatedEntityFieldResolver {
             field_path: (entity_name, ident.to_string()),
             field_type: Type::Path(type_path),
             proxy_type: Type::Path(old_nested_type.clone()),
             inner_type: Type::Path(nested_type),
-            primary_key: Self::is_primary_key(annotations),
+            primary_key,
             nullable,
             association,
-            status: FieldResolverStatus::WaitingForEntity(
-                old_nested_type.to_token_stream().to_string(),
-            ),
+            status: FieldResolverStatus::WaitingForEntity(referenced_entity.clone()),
+            referenced_entity,
             referenced_table: None,
             columns: vec![],
             column_map: vec![],
@@ -339,6 +367,7 @@ pub struct AssociatedEntityFieldResolver {
     nullable: bool,
     association: Association,
     status: FieldResolverStatus,
+    referenced_entity: EntityName,
     referenced_table: Option<String>,
     columns: Vec<ColumnDefinition>,
     column_map: Vec<(String, String)>,
@@ -422,6 +451,10 @@ impl FieldResolver for AssociatedEntityFieldResolver {
                                     auto_increase: false,
                                     primary_key: self.primary_key,
                                     nullable: false,
+                                    default: None,
+                                    charset: definition.charset.clone(),
+                                    collation: definition.collation.clone(),
+                                    enum_variants: definition.enum_variants.clone(),
                                 })
                                 .collect())
                         },
@@ -453,6 +486,11 @@ impl FieldResolver for AssociatedEntityFieldResolver {
         unreachable!()
     }
 
+    /// Self-referencing field waits for its own entity, so the entity must not wait for it
+    fn is_deferred(&self) -> bool {
+        self.referenced_entity == self.field_path.0
+    }
+
     fn assemble(
         &mut self,
         _entity_resolver: &EntityResolver,
@@ -469,10 +507,12 @@ impl FieldResolver for AssociatedEntityFieldResolver {
                         columns: self
                             .columns
                             .iter()
-                            .map(|definition| definition.name.clone())
+                            .map(|definition| IndexColumn::column(definition.name.clone()))
                             .collect(),
                         method: IndexMethod::BTree,
                         unique: true,
+                        include: vec![],
+                        condition: None,
                     }]
                 }
             } else {
@@ -592,6 +632,8 @@ impl FieldResolver for AssociatedEntityFieldResolver {
                     name: format!("__{}", self.field_path.1),
                     referenced_table: self.referenced_table.clone().unwrap(),
                     column_map: self.column_map.clone(),
+                    on_delete: self.association.on_delete,
+                    on_update: self.association.on_update,
                 }],
                 data_converter_token_stream,
                 converter_getter_ident: converter_getter_name,
@@ -601,11 +643,13 @@ impl FieldResolver for AssociatedEntityFieldResolver {
                 field_setter_token_stream,
                 field_type: self.field_type.clone(),
                 field_definition: FieldDefinition {
-                    entity: self.field_path.1.clone(),
+                    entity: self.field_path.0.clone(),
                     name: self.field_path.1.clone(),
                     type_resolver_name: AssociatedEntityTypeResolver::seed().name(),
                     field_type: self.proxy_type.to_token_stream().to_string(),
                     nullable: self.nullable,
+                    length: None,
+                    default: None,
                     columns: self
                         .columns
                         .iter()
@@ -613,11 +657,13 @@ impl FieldResolver for AssociatedEntityFieldResolver {
                         .collect(),
                     tables: vec![],
                     association: Some(AssociationDefinition {
-                        referenced_entity: self.proxy_type.to_token_stream().to_string(),
+                        referenced_entity: self.referenced_entity.clone(),
                         is_list: false,
                         column_map: self.column_map.clone(),
+                        membership: None,
                     }),
                 },
+                nested_field_definitions: vec![],
             })
         } else {
             Err(ResolveError::UnexpectedFieldResolverStatus(
@@ -632,6 +678,57 @@ impl FieldResolver for AssociatedEntityFieldResolver {
 
 pub struct AssociatedEntityTypeResolver;
 
+impl AssociatedEntityTypeResolver {
+    /// Inner join `table` as `alias` on columns in `column_map` of `self_alias` and `alias`
+    fn join_on(
+        self_alias: &str,
+        column_map: &[(String, String)],
+        table: &str,
+        alias: &str,
+        location: Location,
+    ) -> JoinClause {
+        let mut exprs: Vec<_> = column_map
+            .iter()
+            .map(|(left_field, right_field)| {
+                Expr::Binary(Binary {
+                    operator: BinaryOperator::Eq,
+                    left: Box::new(Expr::ColumnIdent(ColumnIdent {
+                        segments: vec![self_alias.to_string(), left_field.clone()],
+                        location,
+                    })),
+                    right: Box::new(Expr::ColumnIdent(ColumnIdent {
+                        segments: vec![alias.to_string(), right_field.clone()],
+                        location,
+                    })),
+                    location,
+                })
+            })
+            .collect();
+
+        let mut on = exprs.pop().unwrap();
+
+        for expr in exprs {
+            on = Expr::Binary(Binary {
+                operator: BinaryOperator::And,
+                left: Box::new(on),
+                right: Box::new(expr),
+                location,
+            })
+        }
+
+        JoinClause::JoinOn(JoinOn {
+            ty: JoinType::Inner,
+            table: TableReference {
+                name: table.to_string(),
+                alias: Some(alias.to_string()),
+                location,
+            },
+            on,
+            location,
+        })
+    }
+}
+
 impl TypeResolver for AssociatedEntityTypeResolver {
     fn seed() -> Box<dyn TypeResolver>
     where
@@ -685,50 +782,41 @@ impl TypeResolver for AssociatedEntityTypeResolver {
             "__{}_{}_{}",
             &field_definition.entity, &field_definition.name, &association.referenced_entity
         );
-        let mut exprs: Vec<_> = association
-            .column_map
-            .iter()
-            .map(|(left_field, right_field)| {
-                Expr::Binary(Binary {
-                    operator: BinaryOperator::Eq,
-                    left: Box::new(Expr::ColumnIdent(ColumnIdent {
-                        segments: vec![self_alias.clone(), left_field.clone()],
+
+        let joins = match &association.membership {
+            Some((membership_table, membership_column_map)) => {
+                let membership_alias =
+                    format!("__{}_{}", field_definition.entity, membership_table);
+
+                vec![
+                    Self::join_on(
+                        self_alias,
+                        &association.column_map,
+                        membership_table,
+                        &membership_alias,
                         location,
-                    })),
-                    right: Box::new(Expr::ColumnIdent(ColumnIdent {
-                        segments: vec![ref_alias.clone(), right_field.clone()],
+                    ),
+                    Self::join_on(
+                        &membership_alias,
+                        membership_column_map,
+                        &association.referenced_entity,
+                        &ref_alias,
                         location,
-                    })),
-                    location,
-                })
-            })
-            .collect();
-
-        let mut on = exprs.pop().unwrap();
-
-        for expr in exprs {
-            on = Expr::Binary(Binary {
-                operator: BinaryOperator::And,
-                left: Box::new(on),
-                right: Box::new(expr),
-                location,
-            })
-        }
-
-        let join = JoinClause::JoinOn(JoinOn {
-            ty: JoinType::Inner,
-            table: TableReference {
-                name: association.referenced_entity.clone(),
-                alias: Some(ref_alias.clone()),
+                    ),
+                ]
+            }
+            None => vec![Self::join_on(
+                self_alias,
+                &association.column_map,
+                &association.referenced_entity,
+                &ref_alias,
                 location,
-            },
-            on,
-            location,
-        });
+            )],
+        };
 
         let mut segments = ident.segments.clone();
 
-        segments.pop();
+        segments.remove(1);
         segments[0] = ref_alias;
 
         if segments.len() > 2
@@ -736,7 +824,7 @@ impl TypeResolver for AssociatedEntityTypeResolver {
         {
             Ok((
                 IdentResolveStatus::Unresolved(ColumnIdent { segments, location }),
-                vec![join],
+                joins,
             ))
         } else {
             Ok((
@@ -746,12 +834,77 @@ impl TypeResolver for AssociatedEntityTypeResolver {
                         resolver_name: self.name(),
                         field_type: field_definition.field_type.clone(),
                         nullable: field_definition.nullable,
+                        length: None,
                         type_kind: TypeKind::Object(association.referenced_entity.clone()),
                     },
                     location,
                 }),
-                vec![join],
+                joins,
             ))
         }
     }
 }
+
+#[test]
+fn test_many_to_many_type_check() {
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let definition_getter = |entity: &str, field: &str| match (entity, field) {
+        ("foo", "tags") => Some(FieldDefinition {
+            entity: "foo".to_string(),
+            columns: vec![],
+            tables: vec!["foo_tags".to_string()],
+            association: Some(AssociationDefinition {
+                referenced_entity: "Tag".to_string(),
+                is_list: true,
+                column_map: vec![("id".to_string(), "foo_id".to_string())],
+                membership: Some((
+                    "foo_tags".to_string(),
+                    vec![("tags_id".to_string(), "id".to_string())],
+                )),
+            }),
+            ..FieldDefinition {
+                entity: "test".to_string(),
+                name: "tags".to_string(),
+                type_resolver_name: "associated_object".to_string(),
+                field_type: "Tag".to_string(),
+                nullable: false,
+                length: None,
+                default: None,
+                columns: vec!["tags".to_string()],
+                tables: vec![],
+                association: None,
+            }
+        }),
+        ("Tag", "name") => Some(FieldDefinition {
+            entity: "Tag".to_string(),
+            name: "name".to_string(),
+            type_resolver_name: "string".to_string(),
+            field_type: "String".to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: vec!["name".to_string()],
+            tables: vec![],
+            association: None,
+        }),
+        _ => None,
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check("SELECT f.tags.name AS n FROM foo f WHERE f.tags.name = \"rust\"").unwrap();
+
+    assert_eq!(
+        checked.result_types.get("n").map(|s| s.as_str()),
+        Some("String")
+    );
+
+    assert!(check("SELECT f.tags.title AS n FROM foo f").is_err());
+}
@@ -0,0 +1,370 @@
+use crate::annotations::FieldAnnotation;
+use crate::association::{AssociatedEntityTypeResolver, FakeEntity};
+use crate::definitions::{AssociationDefinition, FieldDefinition};
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
+    FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, TypePathResolver, ValueConverter,
+};
+use crate::types::{TypeResolver, ValuePack};
+use crate::Entity;
+use iroha::ToTokens;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use syn::{GenericArgument, PathArguments, Type, TypePath};
+
+/// Owning side entities of inverse association field, they are loaded by getter of entity proxy
+#[derive(Clone)]
+pub enum InversedEntities<E>
+where
+    E: Entity + Clone,
+{
+    Unresolved,
+    Resolved(Vec<E>),
+}
+
+impl<E> InversedEntities<E>
+where
+    E: Entity + Clone,
+{
+    pub fn resolved(&self) -> bool {
+        matches!(self, Self::Resolved(_))
+    }
+
+    pub fn get(&self) -> Option<&Vec<E>> {
+        match self {
+            Self::Resolved(entities) => Some(entities),
+            _ => None,
+        }
+    }
+}
+
+impl<E> Default for InversedEntities<E>
+where
+    E: Entity + Clone,
+{
+    fn default() -> Self {
+        Self::Unresolved
+    }
+}
+
+/// Value converter of inverse association field, it has no column
+#[derive(ToTokens)]
+#[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+pub struct InversedEntitiesValueConverter<E: Entity + Clone> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: Entity + Clone> ValueConverter<InversedEntities<E>> for InversedEntitiesValueConverter<E> {
+    fn to_field_value(&self, _values: &ValuePack) -> Result<InversedEntities<E>, DataConvertError> {
+        Ok(InversedEntities::Unresolved)
+    }
+
+    fn to_database_values_by_ref(
+        &self,
+        _value: &InversedEntities<E>,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+
+    fn primary_column_values_by_ref(
+        &self,
+        _value: &InversedEntities<E>,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+pub struct InverseAssociationFieldResolverSeed;
+
+impl FieldResolverSeed for InverseAssociationFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        InverseAssociationFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(InverseAssociationFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let inverse_association = annotations
+            .iter()
+            .filter_map(|attr| match attr {
+                FieldAnnotation::InverseAssociation(inverse_association) => {
+                    Some(inverse_association)
+                }
+                _ => None,
+            })
+            .next()?;
+
+        let field_path = (entity_name.clone(), ident.to_string());
+        let unexpected_generic = || {
+            Some(Err(ResolveError::UnexpectedFieldGeneric(
+                entity_name.clone(),
+                ident.to_string(),
+            )))
+        };
+
+        let (nullable, nested_type) =
+            match Self::unwrap_option(field_type, field_path.clone(), type_path_resolver) {
+                Ok(result) => result,
+                Err(e) => return Some(Err(e)),
+            };
+
+        // `Vec<Owner>` for many owners, `Option<Owner>` for unique owner
+        let (is_list, proxy_type_path) = match &nested_type {
+            Type::Path(type_path) if nullable => (false, type_path.clone()),
+            Type::Path(type_path) => {
+                let last_segment = type_path.path.segments.last()?;
+
+                if last_segment.ident != "Vec" {
+                    return unexpected_generic();
+                }
+
+                match &last_segment.arguments {
+                    PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+                        match arguments.args.first() {
+                            Some(GenericArgument::Type(Type::Path(nested_type_path))) => {
+                                (true, nested_type_path.clone())
+                            }
+                            _ => return unexpected_generic(),
+                        }
+                    }
+                    _ => return unexpected_generic(),
+                }
+            }
+            _ => return unexpected_generic(),
+        };
+
+        let owner_ident = match proxy_type_path.path.get_ident() {
+            Some(owner_ident) => owner_ident.clone(),
+            None => return unexpected_generic(),
+        };
+        let inner_type_path: TypePath = TypePath {
+            qself: None,
+            path: format_ident!("{}Inner", owner_ident).into(),
+        };
+
+        Some(Ok(Box::new(InverseAssociationFieldResolver {
+            field_path,
+            status: FieldResolverStatus::WaitingForFields(vec![(
+                owner_ident.to_string(),
+                inverse_association.inversed_by.clone(),
+            )]),
+            is_list,
+            proxy_type: Type::Path(proxy_type_path),
+            inner_type: Type::Path(inner_type_path),
+            column_map: vec![],
+        })))
+    }
+}
+
+pub struct InverseAssociationFieldResolver {
+    field_path: FieldPath,
+    status: FieldResolverStatus,
+    is_list: bool,
+    proxy_type: Type,
+    inner_type: Type,
+    /// Column map of owning side field, (owning side column, column of this entity)
+    column_map: Vec<(String, String)>,
+}
+
+impl FieldResolver for InverseAssociationFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        self.status.clone()
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        _resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        let (entity_name, field_name) = self.field_path.clone();
+        let owning = match resolvers.first() {
+            Some(owning) => owning,
+            None => {
+                return Err(ResolveError::UnexpectedFieldResolverStatus(
+                    entity_name,
+                    field_name,
+                    "WaitingAssemble".to_string(),
+                    self.status(),
+                ))
+            }
+        };
+
+        let association = owning
+            .field_definition
+            .association
+            .as_ref()
+            .filter(|association| {
+                !association.is_list && association.referenced_entity == entity_name
+            })
+            .ok_or_else(|| {
+                ResolveError::InvalidInversedField(
+                    owning.field_path.0.clone(),
+                    owning.field_path.1.clone(),
+                    entity_name.clone(),
+                )
+            })?;
+
+        let owning_unique = owning.unique() || owning.indexes.iter().any(|index| index.unique);
+        if owning_unique == self.is_list {
+            return Err(ResolveError::AssociationCardinalityConflict(
+                entity_name,
+                field_name,
+            ));
+        }
+
+        self.column_map = association.column_map.clone();
+        self.status = FieldResolverStatus::WaitingAssemble;
+
+        Ok(FieldResolverStatus::WaitingAssemble)
+    }
+
+    fn is_deferred(&self) -> bool {
+        true
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        if !matches!(self.status, FieldResolverStatus::WaitingAssemble) {
+            return Err(ResolveError::UnexpectedFieldResolverStatus(
+                self.field_path.0.clone(),
+                self.field_path.1.clone(),
+                "WaitingAssemble".to_string(),
+                self.status(),
+            ));
+        }
+
+        let converter_getter_name = self.converter_getter_ident();
+        let convert = InversedEntitiesValueConverter {
+            _marker: PhantomData::<FakeEntity>::default(),
+        };
+        let inner_type = &self.inner_type;
+
+        let data_converter_token_stream = quote! {
+            pub fn #converter_getter_name() -> yukino::resolver::field_resolver_seeds::InversedEntitiesValueConverter::<#inner_type> {
+                #convert
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", self.field_path.1);
+        let proxy_type = &self.proxy_type;
+        let owning_columns: Vec<_> = self.column_map.iter().map(|(owning, _)| owning).collect();
+        let columns: Vec<_> = self.column_map.iter().map(|(_, column)| column).collect();
+
+        let (output_type, output) = if self.is_list {
+            (
+                quote! { Vec<#proxy_type> },
+                quote! {
+                    entities.iter().cloned().map(
+                        |entity| self.get_transaction().create_entity(move || entity)
+                    ).collect()
+                },
+            )
+        } else {
+            (
+                quote! { Option<#proxy_type> },
+                quote! {
+                    entities.first().cloned().map(
+                        |entity| self.get_transaction().create_entity(move || entity)
+                    )
+                },
+            )
+        };
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> #output_type {
+                use yukino::EntityProxy;
+                let inner = self.get_inner();
+
+                if let yukino::collection::InversedEntities::Unresolved = &inner.#field_ident {
+                    let values = yukino::Entity::to_database_values(inner).unwrap();
+                    let conditions: std::collections::HashMap<String, yukino::types::DatabaseValue> = vec![
+                        #((#owning_columns.to_string(), values.get(#columns).unwrap().clone())),*
+                    ].into_iter().collect();
+
+                    let result = self.get_transaction()
+                        .get_repository::<#inner_type>()
+                        .find_all(&conditions);
+
+                    let mut_inner = self.get_inner_mut();
+                    mut_inner.#field_ident = yukino::collection::InversedEntities::Resolved(result);
+                }
+
+                let entities = inner.#field_ident.get().unwrap();
+
+                #output
+            }
+        };
+
+        let entity_name = self.field_path.0.clone();
+        let owner_name = self.proxy_type.to_token_stream().to_string();
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: vec![],
+            joined_table: vec![],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: converter_getter_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            // Inverse side is read only, association is maintained by owning side
+            field_setter_token_stream: TokenStream::new(),
+            field_type: syn::parse_quote! {
+                yukino::collection::InversedEntities<#inner_type>
+            },
+            field_definition: FieldDefinition {
+                entity: entity_name,
+                name: self.field_path.1.clone(),
+                type_resolver_name: AssociatedEntityTypeResolver::seed().name(),
+                field_type: owner_name.clone(),
+                nullable: !self.is_list,
+                length: None,
+                default: None,
+                columns: vec![],
+                tables: vec![],
+                association: Some(AssociationDefinition {
+                    referenced_entity: owner_name,
+                    is_list: self.is_list,
+                    column_map: self
+                        .column_map
+                        .iter()
+                        .map(|(owning, column)| (column.clone(), owning.clone()))
+                        .collect(),
+                    membership: None,
+                }),
+            },
+            nested_field_definitions: vec![],
+        })
+    }
+}
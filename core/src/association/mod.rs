@@ -1,10 +1,14 @@
+mod associated_collection;
 mod associated_entity;
+mod inverse_association;
 
 use crate::definitions::{FieldDefinition, TableDefinition};
 use crate::resolver::error::DataConvertError;
 use crate::types::ValuePack;
 use crate::Entity;
+pub use associated_collection::*;
 pub use associated_entity::*;
+pub use inverse_association::*;
 
 #[derive(Clone)]
 struct FakeEntity;
@@ -0,0 +1,691 @@
+use crate::annotations::{IndexMethod, ReferentialAction};
+use crate::definitions::{
+    ColumnDefault, ColumnDefinition, ForeignKeyDefinition, IndexColumn, IndexDefinition,
+    IndexOrder, TableDefinition,
+};
+use crate::types::DatabaseType;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DdlError {
+    #[error("UnsupportedIndexMethod: Method({2}) of index({1}) in table {0} is not supported by dialect")]
+    UnsupportedIndexMethod(String, String, String),
+    #[error("UnsupportedIndexOption: Option({2}) of index({1}) in table {0} is not supported by dialect")]
+    UnsupportedIndexOption(String, String, &'static str),
+    #[error("UnsupportedColumnOption: Option({2}) of column({1}) in table {0} is not supported by dialect")]
+    UnsupportedColumnOption(String, String, &'static str),
+}
+
+/// Database dialect of data definition statements
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Dialect {
+    MySQL,
+    PostgreSQL,
+    SQLite,
+}
+
+impl Dialect {
+    pub fn quote(&self, ident: &str) -> String {
+        match self {
+            Dialect::MySQL => format!("`{}`", ident),
+            _ => format!("\"{}\"", ident),
+        }
+    }
+
+    pub fn column_type(&self, table: &TableDefinition, column: &ColumnDefinition) -> String {
+        let ty = match (self, column.data_type) {
+            (Dialect::SQLite, DatabaseType::Bool)
+            | (Dialect::SQLite, DatabaseType::SmallInteger)
+            | (Dialect::SQLite, DatabaseType::UnsignedSmallInteger)
+            | (Dialect::SQLite, DatabaseType::Integer)
+            | (Dialect::SQLite, DatabaseType::UnsignedInteger)
+            | (Dialect::SQLite, DatabaseType::BigInteger)
+            | (Dialect::SQLite, DatabaseType::UnsignedBigInteger)
+            | (Dialect::SQLite, DatabaseType::Timestamp) => "INTEGER",
+            (Dialect::SQLite, DatabaseType::Float) | (Dialect::SQLite, DatabaseType::Double) => {
+                "REAL"
+            }
+            (Dialect::SQLite, DatabaseType::Binary) => "BLOB",
+            (Dialect::SQLite, _) => "TEXT",
+
+            (Dialect::MySQL, DatabaseType::Bool) => "BOOL",
+            (Dialect::PostgreSQL, DatabaseType::Bool) => "BOOLEAN",
+            (_, DatabaseType::SmallInteger) => "SMALLINT",
+            (Dialect::MySQL, DatabaseType::UnsignedSmallInteger) => "SMALLINT UNSIGNED",
+            (Dialect::PostgreSQL, DatabaseType::UnsignedSmallInteger) => "INTEGER",
+            (Dialect::MySQL, DatabaseType::Integer) => "INT",
+            (Dialect::PostgreSQL, DatabaseType::Integer) => "INTEGER",
+            (Dialect::MySQL, DatabaseType::UnsignedInteger) => "INT UNSIGNED",
+            (Dialect::PostgreSQL, DatabaseType::UnsignedInteger) => "BIGINT",
+            (_, DatabaseType::BigInteger) => "BIGINT",
+            (Dialect::MySQL, DatabaseType::UnsignedBigInteger) => "BIGINT UNSIGNED",
+            (Dialect::PostgreSQL, DatabaseType::UnsignedBigInteger) => "NUMERIC(20)",
+            (Dialect::MySQL, DatabaseType::Float) => "FLOAT",
+            (Dialect::PostgreSQL, DatabaseType::Float) => "REAL",
+            (Dialect::MySQL, DatabaseType::Double) => "DOUBLE",
+            (Dialect::PostgreSQL, DatabaseType::Double) => "DOUBLE PRECISION",
+            #[cfg(any(feature = "decimal"))]
+            (_, DatabaseType::Decimal(precision, scale)) => {
+                return format!("DECIMAL({}, {})", precision, scale)
+            }
+            (Dialect::MySQL, DatabaseType::Binary) => "BLOB",
+            (Dialect::PostgreSQL, DatabaseType::Binary) => "BYTEA",
+            #[cfg(any(feature = "data-time"))]
+            (_, DatabaseType::Time) => "TIME",
+            #[cfg(any(feature = "data-time"))]
+            (_, DatabaseType::Date) => "DATE",
+            #[cfg(any(feature = "data-time"))]
+            (Dialect::MySQL, DatabaseType::DateTime) => "DATETIME",
+            #[cfg(any(feature = "data-time"))]
+            (Dialect::PostgreSQL, DatabaseType::DateTime) => "TIMESTAMP",
+            (_, DatabaseType::Timestamp) => "TIMESTAMP",
+            (_, DatabaseType::Character) => "CHAR(1)",
+            (_, DatabaseType::String(length)) => return format!("VARCHAR({})", length),
+            (_, DatabaseType::Text) => "TEXT",
+            (Dialect::MySQL, DatabaseType::Enum) => {
+                return format!("ENUM({})", enum_variants(column))
+            }
+            (Dialect::PostgreSQL, DatabaseType::Enum) => {
+                return self.quote(&enum_type_name(table, column))
+            }
+            #[cfg(any(feature = "json"))]
+            (Dialect::MySQL, DatabaseType::Json) => "JSON",
+            #[cfg(any(feature = "json"))]
+            (Dialect::PostgreSQL, DatabaseType::Json) => "JSONB",
+        };
+
+        ty.to_string()
+    }
+
+    /// Name of index method, `None` if the method is not available in dialect
+    #[allow(unreachable_patterns)]
+    fn index_method(&self, method: IndexMethod) -> Option<&'static str> {
+        match (self, method) {
+            (Dialect::MySQL, IndexMethod::BTree) => Some("BTREE"),
+            (_, IndexMethod::BTree) => Some("btree"),
+            #[cfg(any(feature = "mysql", feature = "postgre-sql"))]
+            (Dialect::MySQL, IndexMethod::Hash) => Some("HASH"),
+            #[cfg(any(feature = "mysql", feature = "postgre-sql"))]
+            (Dialect::PostgreSQL, IndexMethod::Hash) => Some("hash"),
+            #[cfg(any(feature = "postgre-sql"))]
+            (Dialect::PostgreSQL, IndexMethod::Gin) => Some("gin"),
+            #[cfg(any(feature = "postgre-sql"))]
+            (Dialect::PostgreSQL, IndexMethod::SPGin) => Some("spgist"),
+            #[cfg(any(feature = "postgre-sql"))]
+            (Dialect::PostgreSQL, IndexMethod::Gist) => Some("gist"),
+            #[cfg(any(feature = "postgre-sql"))]
+            (Dialect::PostgreSQL, IndexMethod::Brin) => Some("brin"),
+            _ => None,
+        }
+    }
+}
+
+impl ReferentialAction {
+    pub fn to_sql(&self) -> &'static str {
+        match self {
+            ReferentialAction::NoAction => "NO ACTION",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+        }
+    }
+}
+
+/// Statements creating tables of definitions. Foreign keys are added after all tables are created,
+/// except in SQLite which only supports foreign keys declared in `CREATE TABLE`. Native enums are
+/// types created before tables in PostgreSQL
+pub fn create_tables(
+    definitions: &[TableDefinition],
+    dialect: Dialect,
+) -> Result<Vec<String>, DdlError> {
+    let mut statements = vec![];
+
+    for table in definitions {
+        if dialect == Dialect::PostgreSQL {
+            statements.extend(
+                table
+                    .columns
+                    .iter()
+                    .filter(|column| matches!(column.data_type, DatabaseType::Enum))
+                    .map(|column| {
+                        format!(
+                            "CREATE TYPE {} AS ENUM ({});",
+                            dialect.quote(&enum_type_name(table, column)),
+                            enum_variants(column)
+                        )
+                    }),
+            );
+        }
+
+        statements.push(create_table(table, dialect)?);
+
+        for index in table.indexes.iter() {
+            statements.push(create_index(table, index, dialect)?);
+        }
+    }
+
+    if dialect != Dialect::SQLite {
+        for table in definitions {
+            statements.extend(table.foreign_keys.iter().map(|foreign_key| {
+                format!(
+                    "ALTER TABLE {} ADD {};",
+                    dialect.quote(&table.name),
+                    foreign_key_constraint(table, foreign_key, dialect)
+                )
+            }));
+        }
+    }
+
+    Ok(statements)
+}
+
+fn create_table(table: &TableDefinition, dialect: Dialect) -> Result<String, DdlError> {
+    let mut lines = table
+        .columns
+        .iter()
+        .map(|column| column_definition(table, column, dialect))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let primary_keys: Vec<_> = table
+        .columns
+        .iter()
+        .filter(|column| column.primary_key)
+        .map(|column| dialect.quote(&column.name))
+        .collect();
+
+    if !primary_keys.is_empty() {
+        lines.push(format!("PRIMARY KEY ({})", primary_keys.join(", ")));
+    }
+
+    lines.extend(table.checks.iter().map(|check| {
+        format!(
+            "CONSTRAINT {} CHECK ({})",
+            dialect.quote(&format!("{}_{}", table.name, check.name)),
+            check.expr
+        )
+    }));
+
+    if dialect == Dialect::SQLite {
+        lines.extend(
+            table
+                .foreign_keys
+                .iter()
+                .map(|foreign_key| foreign_key_constraint(table, foreign_key, dialect)),
+        );
+    }
+
+    Ok(format!(
+        "CREATE TABLE {} (\n    {}\n);",
+        dialect.quote(&table.name),
+        lines.join(",\n    ")
+    ))
+}
+
+/// Character set of column is only available in MySQL
+fn column_definition(
+    table: &TableDefinition,
+    column: &ColumnDefinition,
+    dialect: Dialect,
+) -> Result<String, DdlError> {
+    let mut result = format!(
+        "{} {}",
+        dialect.quote(&column.name),
+        dialect.column_type(table, column)
+    );
+
+    match (&column.charset, dialect) {
+        (Some(charset), Dialect::MySQL) => result.push_str(&format!(" CHARACTER SET {}", charset)),
+        (Some(_), _) => {
+            return Err(DdlError::UnsupportedColumnOption(
+                table.name.clone(),
+                column.name.clone(),
+                "charset",
+            ))
+        }
+        (None, _) => {}
+    }
+
+    match (&column.collation, dialect) {
+        (Some(collation), Dialect::PostgreSQL) => {
+            result.push_str(&format!(" COLLATE {}", dialect.quote(collation)))
+        }
+        (Some(collation), _) => result.push_str(&format!(" COLLATE {}", collation)),
+        (None, _) => {}
+    }
+
+    // Expressions are emitted as written, bare idents in them are expected to be column names
+    match &column.default {
+        Some(ColumnDefault::Literal(literal)) => {
+            result.push_str(&format!(" DEFAULT {}", sql_literal(literal)))
+        }
+        Some(ColumnDefault::Expression(expr)) => result.push_str(&format!(" DEFAULT ({})", expr)),
+        Some(ColumnDefault::Generated(expr)) => {
+            result.push_str(&format!(" GENERATED ALWAYS AS ({}) STORED", expr))
+        }
+        None => {}
+    }
+
+    if !column.nullable {
+        result.push_str(" NOT NULL");
+    }
+
+    if column.auto_increase {
+        match dialect {
+            Dialect::MySQL => result.push_str(" AUTO_INCREMENT"),
+            Dialect::PostgreSQL => result.push_str(" GENERATED BY DEFAULT AS IDENTITY"),
+            // Integer primary key is alias of rowid in SQLite
+            Dialect::SQLite => {}
+        }
+    }
+
+    if column.unique && !column.primary_key {
+        result.push_str(" UNIQUE");
+    }
+
+    Ok(result)
+}
+
+/// Type names are scoped by database in PostgreSQL, so they are prefixed by table name
+fn enum_type_name(table: &TableDefinition, column: &ColumnDefinition) -> String {
+    format!("{}_{}", table.name, column.name)
+}
+
+fn enum_variants(column: &ColumnDefinition) -> String {
+    column
+        .enum_variants
+        .iter()
+        .map(|variant| format!("'{}'", variant))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// String literals are double quoted in query syntax, but single quoted in SQL
+fn sql_literal(literal: &str) -> String {
+    if literal.len() >= 2 && literal.starts_with('"') && literal.ends_with('"') {
+        format!(
+            "'{}'",
+            literal[1..literal.len() - 1]
+                .replace("\\\"", "\"")
+                .replace('\'', "''")
+        )
+    } else {
+        literal.to_string()
+    }
+}
+
+/// Index names are scoped by database in PostgreSQL and SQLite, so they are prefixed by table name
+fn create_index(
+    table: &TableDefinition,
+    index: &IndexDefinition,
+    dialect: Dialect,
+) -> Result<String, DdlError> {
+    let unsupported_option =
+        |option| DdlError::UnsupportedIndexOption(table.name.clone(), index.name.clone(), option);
+
+    let method = dialect.index_method(index.method).ok_or_else(|| {
+        DdlError::UnsupportedIndexMethod(
+            table.name.clone(),
+            index.name.clone(),
+            format!("{:?}", index.method),
+        )
+    })?;
+
+    if !index.include.is_empty() && dialect != Dialect::PostgreSQL {
+        return Err(unsupported_option("include"));
+    }
+
+    if index.condition.is_some() && dialect == Dialect::MySQL {
+        return Err(unsupported_option("condition"));
+    }
+
+    let name = dialect.quote(&format!("{}_{}", table.name, index.name));
+    let unique = if index.unique { "UNIQUE " } else { "" };
+    let columns: Vec<_> = index
+        .columns
+        .iter()
+        .map(|column| index_column(column, dialect))
+        .collect();
+
+    let mut result = match dialect {
+        Dialect::PostgreSQL => format!(
+            "CREATE {}INDEX {} ON {} USING {} ({})",
+            unique,
+            name,
+            dialect.quote(&table.name),
+            method,
+            columns.join(", ")
+        ),
+        Dialect::MySQL => format!(
+            "CREATE {}INDEX {} ON {} ({}) USING {}",
+            unique,
+            name,
+            dialect.quote(&table.name),
+            columns.join(", "),
+            method
+        ),
+        Dialect::SQLite => format!(
+            "CREATE {}INDEX {} ON {} ({})",
+            unique,
+            name,
+            dialect.quote(&table.name),
+            columns.join(", ")
+        ),
+    };
+
+    if !index.include.is_empty() {
+        let include: Vec<_> = index
+            .include
+            .iter()
+            .map(|column| dialect.quote(column))
+            .collect();
+        result.push_str(&format!(" INCLUDE ({})", include.join(", ")));
+    }
+
+    if let Some(condition) = &index.condition {
+        result.push_str(&format!(" WHERE {}", condition));
+    }
+
+    result.push(';');
+
+    Ok(result)
+}
+
+/// Expressions of key parts are wrapped by parentheses
+fn index_column(column: &IndexColumn, dialect: Dialect) -> String {
+    let (key, order) = match column {
+        IndexColumn::Column(name, order) => (dialect.quote(name), order),
+        IndexColumn::Expression(expr, order) => (format!("({})", expr), order),
+    };
+
+    match order {
+        IndexOrder::Asc => key,
+        IndexOrder::Desc => format!("{} DESC", key),
+    }
+}
+
+fn foreign_key_constraint(
+    table: &TableDefinition,
+    foreign_key: &ForeignKeyDefinition,
+    dialect: Dialect,
+) -> String {
+    let (columns, referenced_columns): (Vec<_>, Vec<_>) = foreign_key
+        .column_map
+        .iter()
+        .map(|(column, referenced_column)| {
+            (dialect.quote(column), dialect.quote(referenced_column))
+        })
+        .unzip();
+
+    format!(
+        "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({}) ON DELETE {} ON UPDATE {}",
+        dialect.quote(&format!("{}_{}", table.name, foreign_key.name)),
+        columns.join(", "),
+        dialect.quote(&foreign_key.referenced_table),
+        referenced_columns.join(", "),
+        foreign_key.on_delete.to_sql(),
+        foreign_key.on_update.to_sql()
+    )
+}
+
+#[test]
+fn test_foreign_key() {
+    use crate::definitions::{CheckDefinition, ColumnType, TableType};
+
+    let column = |name: &str, primary_key: bool, nullable: bool| ColumnDefinition {
+        name: name.to_string(),
+        ty: ColumnType::NormalColumn(name.to_string()),
+        data_type: DatabaseType::UnsignedInteger,
+        unique: false,
+        auto_increase: false,
+        primary_key,
+        nullable,
+        default: None,
+        charset: None,
+        collation: None,
+        enum_variants: vec![],
+    };
+
+    let definitions = vec![TableDefinition {
+        name: "category".to_string(),
+        ty: TableType::NormalEntityTable("Category".to_string()),
+        columns: vec![column("id", true, false), column("parent_id", false, true)],
+        indexes: vec![],
+        foreign_keys: vec![ForeignKeyDefinition {
+            name: "__parent".to_string(),
+            referenced_table: "category".to_string(),
+            column_map: vec![("parent_id".to_string(), "id".to_string())],
+            on_delete: ReferentialAction::SetNull,
+            on_update: ReferentialAction::Cascade,
+        }],
+        checks: vec![CheckDefinition {
+            name: "parent_check".to_string(),
+            expr: "parent_id <> id".to_string(),
+        }],
+        version: None,
+        created_at: None,
+        updated_at: None,
+    }];
+
+    let statements = create_tables(&definitions, Dialect::PostgreSQL).unwrap();
+    assert_eq!(
+        statements,
+        vec![
+            "CREATE TABLE \"category\" (\n    \"id\" BIGINT NOT NULL,\n    \"parent_id\" BIGINT,\n    \
+            PRIMARY KEY (\"id\"),\n    CONSTRAINT \"category_parent_check\" CHECK (parent_id <> id)\n);"
+                .to_string(),
+            "ALTER TABLE \"category\" ADD CONSTRAINT \"category___parent\" FOREIGN KEY (\"parent_id\") \
+            REFERENCES \"category\" (\"id\") ON DELETE SET NULL ON UPDATE CASCADE;"
+                .to_string()
+        ]
+    );
+
+    let statements = create_tables(&definitions, Dialect::SQLite).unwrap();
+    assert_eq!(statements.len(), 1);
+    assert!(statements[0].contains(
+        "CONSTRAINT \"category___parent\" FOREIGN KEY (\"parent_id\") REFERENCES \"category\" \
+        (\"id\") ON DELETE SET NULL ON UPDATE CASCADE"
+    ));
+}
+
+#[test]
+fn test_column_default() {
+    use crate::definitions::{ColumnType, TableType};
+
+    let table = TableDefinition {
+        name: "order".to_string(),
+        ty: TableType::NormalEntityTable("Order".to_string()),
+        columns: vec![],
+        indexes: vec![],
+        foreign_keys: vec![],
+        checks: vec![],
+        version: None,
+        created_at: None,
+        updated_at: None,
+    };
+
+    let column = |name: &str, data_type: DatabaseType, default: ColumnDefault| ColumnDefinition {
+        name: name.to_string(),
+        ty: ColumnType::NormalColumn(name.to_string()),
+        data_type,
+        unique: false,
+        auto_increase: false,
+        primary_key: false,
+        nullable: false,
+        default: Some(default),
+        charset: None,
+        collation: None,
+        enum_variants: vec![],
+    };
+
+    assert_eq!(
+        column_definition(
+            &table,
+            &column(
+                "status",
+                DatabaseType::String(255),
+                ColumnDefault::Literal("\"it's\"".to_string())
+            ),
+            Dialect::PostgreSQL
+        )
+        .unwrap(),
+        "\"status\" VARCHAR(255) DEFAULT 'it''s' NOT NULL"
+    );
+
+    assert_eq!(
+        column_definition(
+            &table,
+            &column(
+                "total",
+                DatabaseType::Integer,
+                ColumnDefault::Generated("price * quantity".to_string())
+            ),
+            Dialect::MySQL
+        )
+        .unwrap(),
+        "`total` INT GENERATED ALWAYS AS (price * quantity) STORED NOT NULL"
+    );
+}
+
+#[test]
+fn test_column_collation() {
+    use crate::definitions::{ColumnType, TableType};
+
+    let table = TableDefinition {
+        name: "user".to_string(),
+        ty: TableType::NormalEntityTable("User".to_string()),
+        columns: vec![],
+        indexes: vec![],
+        foreign_keys: vec![],
+        checks: vec![],
+        version: None,
+        created_at: None,
+        updated_at: None,
+    };
+
+    let mut column = ColumnDefinition {
+        name: "name".to_string(),
+        ty: ColumnType::NormalColumn("User".to_string()),
+        data_type: DatabaseType::String(64),
+        unique: false,
+        auto_increase: false,
+        primary_key: false,
+        nullable: true,
+        default: None,
+        charset: Some("utf8mb4".to_string()),
+        collation: Some("utf8mb4_bin".to_string()),
+        enum_variants: vec![],
+    };
+
+    assert_eq!(
+        column_definition(&table, &column, Dialect::MySQL).unwrap(),
+        "`name` VARCHAR(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin"
+    );
+
+    assert!(matches!(
+        column_definition(&table, &column, Dialect::PostgreSQL),
+        Err(DdlError::UnsupportedColumnOption(_, _, "charset"))
+    ));
+
+    column.charset = None;
+    column.collation = Some("C".to_string());
+    column.data_type = DatabaseType::Text;
+    assert_eq!(
+        column_definition(&table, &column, Dialect::PostgreSQL).unwrap(),
+        "\"name\" TEXT COLLATE \"C\""
+    );
+}
+
+#[test]
+fn test_index() {
+    use crate::definitions::TableType;
+
+    let mut table = TableDefinition {
+        name: "user".to_string(),
+        ty: TableType::NormalEntityTable("User".to_string()),
+        columns: vec![],
+        indexes: vec![IndexDefinition {
+            name: "email".to_string(),
+            columns: vec![
+                IndexColumn::Expression("lower(email)".to_string(), IndexOrder::Asc),
+                IndexColumn::Column("created_at".to_string(), IndexOrder::Desc),
+            ],
+            method: IndexMethod::BTree,
+            unique: true,
+            include: vec!["name".to_string()],
+            condition: Some("deleted = false".to_string()),
+        }],
+        foreign_keys: vec![],
+        checks: vec![],
+        version: None,
+        created_at: None,
+        updated_at: None,
+    };
+
+    assert_eq!(
+        create_index(&table, &table.indexes[0], Dialect::PostgreSQL).unwrap(),
+        "CREATE UNIQUE INDEX \"user_email\" ON \"user\" USING btree ((lower(email)), \
+        \"created_at\" DESC) INCLUDE (\"name\") WHERE deleted = false;"
+    );
+
+    assert!(matches!(
+        create_index(&table, &table.indexes[0], Dialect::SQLite),
+        Err(DdlError::UnsupportedIndexOption(_, _, "include"))
+    ));
+
+    table.indexes[0].include = vec![];
+    assert!(matches!(
+        create_index(&table, &table.indexes[0], Dialect::MySQL),
+        Err(DdlError::UnsupportedIndexOption(_, _, "condition"))
+    ));
+
+    table.indexes[0].condition = None;
+    assert_eq!(
+        create_index(&table, &table.indexes[0], Dialect::MySQL).unwrap(),
+        "CREATE UNIQUE INDEX `user_email` ON `user` ((lower(email)), `created_at` DESC) USING BTREE;"
+    );
+}
+
+#[test]
+fn test_native_enum() {
+    use crate::definitions::{ColumnType, TableType};
+
+    let table = TableDefinition {
+        name: "user".to_string(),
+        ty: TableType::NormalEntityTable("User".to_string()),
+        columns: vec![ColumnDefinition {
+            name: "status".to_string(),
+            ty: ColumnType::NormalColumn("User".to_string()),
+            data_type: DatabaseType::Enum,
+            unique: false,
+            auto_increase: false,
+            primary_key: false,
+            nullable: false,
+            default: None,
+            charset: None,
+            collation: None,
+            enum_variants: vec!["Active".to_string(), "Inactive".to_string()],
+        }],
+        indexes: vec![],
+        foreign_keys: vec![],
+        checks: vec![],
+        version: None,
+        created_at: None,
+        updated_at: None,
+    };
+
+    assert_eq!(
+        column_definition(&table, &table.columns[0], Dialect::MySQL).unwrap(),
+        "`status` ENUM('Active', 'Inactive') NOT NULL"
+    );
+    assert_eq!(
+        column_definition(&table, &table.columns[0], Dialect::SQLite).unwrap(),
+        "\"status\" TEXT NOT NULL"
+    );
+    assert_eq!(
+        create_tables(&[table], Dialect::PostgreSQL).unwrap(),
+        vec![
+            "CREATE TYPE \"user_status\" AS ENUM ('Active', 'Inactive');".to_string(),
+            "CREATE TABLE \"user\" (\n    \"status\" \"user_status\" NOT NULL\n);".to_string()
+        ]
+    );
+}
@@ -1,4 +1,4 @@
-use crate::annotations::IndexMethod;
+use crate::annotations::{IndexMethod, ReferentialAction};
 use crate::types::DatabaseType;
 use iroha::ToTokens;
 
@@ -26,6 +26,14 @@ pub struct TableDefinition {
     pub indexes: Vec<IndexDefinition>,
     /// foreign keys in table
     pub foreign_keys: Vec<ForeignKeyDefinition>,
+    /// check constraints in table
+    pub checks: Vec<CheckDefinition>,
+    /// version column of optimistic locking
+    pub version: Option<String>,
+    /// column of creation time, filled on insert
+    pub created_at: Option<String>,
+    /// column of last modification time, filled on insert and update
+    pub updated_at: Option<String>,
 }
 
 /// Column type enumeration
@@ -56,6 +64,59 @@ pub struct ColumnDefinition {
     pub primary_key: bool,
     /// nullable
     pub nullable: bool,
+    /// default value or expression of generated column
+    pub default: Option<ColumnDefault>,
+    /// character set of string column
+    pub charset: Option<String>,
+    /// collation of string column
+    pub collation: Option<String>,
+    /// variants of native enum column
+    pub enum_variants: Vec<String>,
+}
+
+/// Default of column, parameter is the expression in Yukino query syntax
+#[derive(ToTokens, Clone)]
+#[Iroha(mod_path = "yukino::definitions")]
+pub enum ColumnDefault {
+    /// Literal default value
+    Literal(String),
+    /// Default value evaluated by database on insert
+    Expression(String),
+    /// Generated column computed by database, which can not be assigned
+    Generated(String),
+}
+
+impl ColumnDefault {
+    pub fn expr(&self) -> &str {
+        match self {
+            ColumnDefault::Literal(expr)
+            | ColumnDefault::Expression(expr)
+            | ColumnDefault::Generated(expr) => expr,
+        }
+    }
+}
+
+impl ColumnDefinition {
+    /// Column can be omitted or assigned by `DEFAULT` in insert query
+    pub fn omissible(&self) -> bool {
+        self.nullable || self.auto_increase || self.default.is_some()
+    }
+
+    /// Column is generated by database and can not be assigned
+    pub fn generated(&self) -> bool {
+        matches!(self.default, Some(ColumnDefault::Generated(_)))
+    }
+}
+
+impl TableDefinition {
+    /// Names of columns which must be assigned in insert query
+    pub fn required_columns(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .filter(|column| !column.omissible())
+            .map(|column| column.name.clone())
+            .collect()
+    }
 }
 
 /// Index definition. Corresponds to a index of table database
@@ -64,12 +125,41 @@ pub struct ColumnDefinition {
 pub struct IndexDefinition {
     /// index name
     pub name: String,
-    /// Index key part, list of column names
-    pub columns: Vec<String>,
+    /// Index key parts
+    pub columns: Vec<IndexColumn>,
     /// Index method
     pub method: IndexMethod,
     /// is unique index
     pub unique: bool,
+    /// non-key columns covered by index
+    pub include: Vec<String>,
+    /// condition of partial index, boolean expression on columns in Yukino query syntax
+    pub condition: Option<String>,
+}
+
+/// Index key part
+#[derive(ToTokens, Clone)]
+#[Iroha(mod_path = "yukino::definitions")]
+pub enum IndexColumn {
+    /// Column name and order
+    Column(String, IndexOrder),
+    /// Expression on columns in Yukino query syntax and order
+    Expression(String, IndexOrder),
+}
+
+/// Order of index key part
+#[derive(ToTokens, Copy, Clone, Debug, Eq, PartialEq)]
+#[Iroha(mod_path = "yukino::definitions")]
+pub enum IndexOrder {
+    Asc,
+    Desc,
+}
+
+impl IndexColumn {
+    /// Key part of column in ascending order
+    pub fn column(name: String) -> Self {
+        IndexColumn::Column(name, IndexOrder::Asc)
+    }
 }
 
 /// Foreign key definition. Corresponds to a foreign key of table database
@@ -82,6 +172,20 @@ pub struct ForeignKeyDefinition {
     pub referenced_table: String,
     /// list of (column_name, referenced_columns)
     pub column_map: Vec<(String, String)>,
+    /// action when referenced row is deleted
+    pub on_delete: ReferentialAction,
+    /// action when referenced columns are updated
+    pub on_update: ReferentialAction,
+}
+
+/// Check constraint definition. Corresponds to a check constraint of table database
+#[derive(ToTokens, Clone)]
+#[Iroha(mod_path = "yukino::definitions")]
+pub struct CheckDefinition {
+    /// constraint name
+    pub name: String,
+    /// boolean expression on columns, in Yukino query syntax
+    pub expr: String,
 }
 
 #[derive(ToTokens, Clone)]
@@ -89,7 +193,12 @@ pub struct ForeignKeyDefinition {
 pub struct AssociationDefinition {
     pub referenced_entity: String,
     pub is_list: bool,
+    /// list of (column_name, referenced_column), referenced columns are in membership table if
+    /// `membership` is not empty
     pub column_map: Vec<(String, String)>,
+    /// Membership table name and list of (membership_column, referenced_column) of many to many
+    /// association
+    pub membership: Option<(String, Vec<(String, String)>)>,
 }
 
 #[derive(ToTokens, Clone)]
@@ -100,6 +209,9 @@ pub struct FieldDefinition {
     pub type_resolver_name: String,
     pub field_type: String,
     pub nullable: bool,
+    /// Max length of string field, None if not a string field or unbounded
+    pub length: Option<u32>,
+    pub default: Option<ColumnDefault>,
     pub columns: Vec<String>,
     pub tables: Vec<String>,
     pub association: Option<AssociationDefinition>,
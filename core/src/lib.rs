@@ -1,6 +1,5 @@
 #![feature(associated_type_defaults)]
 #![feature(unsafe_cell_raw_get)]
-#![feature(in_band_lifetimes)]
 
 #[macro_use]
 extern crate pest_derive;
@@ -8,6 +7,7 @@ extern crate pest;
 
 pub mod annotations;
 mod association;
+pub mod ddl;
 pub mod definitions;
 mod interface;
 pub mod query;
@@ -20,5 +20,5 @@ pub use interface::*;
 pub use transaction::*;
 
 pub mod collection {
-    pub use super::association::AssociatedEntity;
+    pub use super::association::{AssociatedCollection, AssociatedEntity, InversedEntities};
 }
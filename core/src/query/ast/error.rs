@@ -53,4 +53,24 @@ pub enum SyntaxError {
     CannotBeWrappedInto(String),
     #[error("Unable to infer a suitable type")]
     TypeInferError,
+    #[error("Parse error: {0}")]
+    ParseError(String),
+    #[error("Cannot assign nullable \"{1}\" to non-null column \"{0}\"")]
+    NullableAssignment(String, String),
+    #[error("Unknown function \"{0}\"")]
+    UnknownFunction(String),
+    #[error("Wrong number of arguments for function \"{0}\"")]
+    WrongArgumentCount(String),
+    #[error("Unknown type \"{0}\"")]
+    UnknownType(String),
+    #[error("Invalid literal \"{0}\" for \"{1}\"")]
+    InvalidLiteral(String, String),
+    #[error("Cannot assign value to generated column \"{0}\"")]
+    GeneratedColumnAssignment(String),
+    #[error("Missing value of required column \"{0}\"")]
+    MissingRequiredColumn(String),
+    #[error("Cannot assign DEFAULT to column \"{0}\" without default value")]
+    NonOmissibleColumn(String),
+    #[error("Unknown entity \"{0}\"")]
+    UnknownEntity(String),
 }
@@ -1,6 +1,6 @@
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{
-    ColumnIdent, FromPair, FunctionCall, Literal, Locatable, Location, QueryPair,
+    ColumnIdent, FromPair, FunctionCall, JsonAccess, Literal, Locatable, Location, QueryPair,
 };
 use crate::query::grammar::Rule;
 
@@ -13,6 +13,8 @@ pub enum Expr {
     ColumnIdent(ColumnIdent),
     Unary(Unary),
     Binary(Binary),
+    Cast(Cast),
+    JsonAccess(JsonAccess),
 }
 
 impl FromPair for Expr {
@@ -34,6 +36,8 @@ impl Locatable for Expr {
             Expr::ColumnIdent(ident) => ident.location(),
             Expr::Unary(e) => e.location(),
             Expr::Binary(e) => e.location(),
+            Expr::Cast(e) => e.location(),
+            Expr::JsonAccess(e) => e.location(),
         }
     }
 }
@@ -50,7 +54,9 @@ impl Expr {
 
                 match inner.as_rule() {
                     Rule::literal => Literal::from_pair(inner).map(Expr::Literal),
+                    Rule::cast_expr => Cast::from_pair(inner).map(Expr::Cast),
                     Rule::function_call => FunctionCall::from_pair(inner).map(Expr::FunctionCall),
+                    Rule::json_access => JsonAccess::from_pair(inner).map(Expr::JsonAccess),
                     Rule::column_ident => ColumnIdent::from_pair(inner).map(Expr::ColumnIdent),
                     Rule::expr => Self::from_pair(inner),
                     _ => {
@@ -105,7 +111,7 @@ impl BinaryOperator {
     pub fn from_rule(rule: Rule) -> Option<Self> {
         match rule {
             Rule::plus => Some(BinaryOperator::Plus),
-            Rule::minus => Some(BinaryOperator::Plus),
+            Rule::minus => Some(BinaryOperator::Minus),
             Rule::multi => Some(BinaryOperator::Multi),
             Rule::div => Some(BinaryOperator::Div),
             Rule::modulo => Some(BinaryOperator::Mod),
@@ -341,6 +347,62 @@ impl Locatable for Unary {
     }
 }
 
+/// `CAST(expr AS type)`, `ty` is the name of target type, e.g. `i64`
+#[derive(Clone, Debug)]
+pub struct Cast {
+    pub expr: BoxedExpr,
+    pub ty: String,
+    pub location: Location,
+}
+
+impl PartialEq for Cast {
+    fn eq(&self, other: &Self) -> bool {
+        self.expr == other.expr && self.ty == other.ty
+    }
+}
+
+impl Eq for Cast {}
+
+impl FromPair for Cast {
+    fn from_pair(pair: QueryPair) -> Result<Self, SyntaxErrorWithPos> {
+        let location: Location = (&pair).into();
+
+        match pair.as_rule() {
+            Rule::cast_expr => {
+                let mut inner = pair.into_inner();
+
+                let expr = Expr::from_pair(
+                    inner
+                        .next()
+                        .ok_or_else(|| location.error(SyntaxError::UnexpectedPair("expr")))?,
+                )?;
+
+                let ty_pair = inner
+                    .next()
+                    .ok_or_else(|| location.error(SyntaxError::UnexpectedPair("type_name")))?;
+
+                let ty = match ty_pair.as_rule() {
+                    Rule::type_name => Ok(ty_pair.as_str().to_string()),
+                    _ => Err(location.error(SyntaxError::UnexpectedPair("type_name"))),
+                }?;
+
+                Ok(Cast {
+                    expr: Box::new(expr),
+                    ty,
+                    location,
+                })
+            }
+            _ => Err(location.error(SyntaxError::UnexpectedPair("cast_expr"))),
+        }
+    }
+}
+
+impl Locatable for Cast {
+    fn location(&self) -> Location {
+        self.location
+    }
+}
+
 #[test]
 fn test_expr() {
     use crate::query::ast::helper::assert_parse_result;
@@ -464,5 +526,26 @@ fn test_expr() {
             location,
         }),
         Rule::expr,
+    );
+
+    assert_parse_result(
+        "CAST(column.a AS f64) * 1.5",
+        Expr::Binary(Binary {
+            operator: BinaryOperator::Multi,
+            left: Box::new(Expr::Cast(Cast {
+                expr: Box::new(Expr::ColumnIdent(ColumnIdent {
+                    segments: vec!["column".to_string(), "a".to_string()],
+                    location,
+                })),
+                ty: "f64".to_string(),
+                location,
+            })),
+            right: Box::new(Expr::Literal(Literal::Float(Float {
+                value: "1.5".to_string(),
+                location,
+            }))),
+            location,
+        }),
+        Rule::expr,
     )
 }
@@ -1,12 +1,13 @@
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
-use crate::query::ast::{Expr, FromPair, Locatable, Location, QueryPair};
+use crate::query::ast::{FromPair, Locatable, Location, QueryPair, ValueItem};
 use crate::query::grammar::Rule;
 
 #[derive(Clone, Debug)]
 pub struct InsertQuery {
     pub table: String,
     pub columns: Option<Vec<String>>,
-    pub values: Vec<Expr>,
+    /// Values of columns, empty for `DEFAULT VALUES`
+    pub values: Vec<ValueItem>,
     pub location: Location,
 }
 
@@ -55,9 +56,7 @@ impl FromPair for InsertQuery {
                                     let ident_location = Location::from(&ident_pair);
 
                                     match ident_pair.as_rule() {
-                                        Rule::ident | Rule::any_ident => {
-                                            Ok(ident_pair.as_str().to_string())
-                                        }
+                                        Rule::column_ident => Ok(ident_pair.as_str().to_string()),
                                         _ => Err(ident_location
                                             .error(SyntaxError::UnexpectedPair("ident"))),
                                     }
@@ -71,10 +70,16 @@ impl FromPair for InsertQuery {
                     others => (None, others),
                 };
 
-                let values = values_pair
-                    .into_inner()
-                    .map(Expr::from_pair)
-                    .collect::<Result<Vec<_>, _>>()?;
+                let values = match values_pair.into_inner().next_back() {
+                    Some(value_list) if value_list.as_rule() == Rule::value_list => value_list
+                        .into_inner()
+                        .map(ValueItem::from_pair)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    Some(default_value) if default_value.as_rule() == Rule::default_value => {
+                        vec![]
+                    }
+                    _ => return Err(location.error(SyntaxError::UnexpectedPair("insert_value"))),
+                };
 
                 Ok(InsertQuery {
                     table,
@@ -87,3 +92,48 @@ impl FromPair for InsertQuery {
         }
     }
 }
+
+#[test]
+fn test_insert() {
+    use crate::query::ast::{Expr, Integer, Literal};
+    use crate::query::grammar::Grammar;
+    use pest::Parser;
+
+    let parse = |input: &str| {
+        InsertQuery::from_pair(
+            Grammar::parse(Rule::insert_query, input)
+                .unwrap()
+                .next()
+                .unwrap(),
+        )
+        .unwrap()
+    };
+
+    let location = Location::pos(0);
+
+    assert_eq!(
+        parse("INSERT INTO test (a, b) VALUES (1, DEFAULT)"),
+        InsertQuery {
+            table: "test".to_string(),
+            columns: Some(vec!["a".to_string(), "b".to_string()]),
+            values: vec![
+                ValueItem::Expr(Expr::Literal(Literal::Integer(Integer {
+                    value: "1".to_string(),
+                    location
+                }))),
+                ValueItem::Default
+            ],
+            location
+        }
+    );
+
+    assert_eq!(
+        parse("INSERT INTO test DEFAULT VALUES"),
+        InsertQuery {
+            table: "test".to_string(),
+            columns: None,
+            values: vec![],
+            location
+        }
+    );
+}
@@ -0,0 +1,128 @@
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{ColumnIdent, FromPair, Locatable, Location, QueryPair, Str};
+use crate::query::grammar::Rule;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Segment of JSON path, e.g. `->"key"` or `[0]`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl Display for JsonPathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            JsonPathSegment::Key(key) => write!(f, "->\"{}\"", key),
+            JsonPathSegment::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+impl FromPair for JsonPathSegment {
+    fn from_pair(pair: QueryPair) -> Result<Self, SyntaxErrorWithPos> {
+        let location: Location = (&pair).into();
+
+        match pair.as_rule() {
+            Rule::json_key => {
+                let inner = pair
+                    .into_inner()
+                    .next()
+                    .ok_or_else(|| location.error(SyntaxError::UnexpectedPair("string")))?;
+
+                Ok(JsonPathSegment::Key(Str::from_pair(inner)?.value))
+            }
+            Rule::json_index => {
+                let inner = pair
+                    .into_inner()
+                    .next()
+                    .ok_or_else(|| location.error(SyntaxError::UnexpectedPair("unsigned_int")))?;
+
+                inner
+                    .as_str()
+                    .parse()
+                    .map(JsonPathSegment::Index)
+                    .map_err(|_| {
+                        location.error(SyntaxError::CannotParseIntoInteger(
+                            inner.as_str().to_string(),
+                        ))
+                    })
+            }
+            _ => Err(location.error(SyntaxError::UnexpectedPair("json_path"))),
+        }
+    }
+}
+
+/// Access value inside a JSON column, e.g. `f.map->"key"` or `f.list[0]`
+#[derive(Clone, Debug)]
+pub struct JsonAccess {
+    pub ident: ColumnIdent,
+    pub path: Vec<JsonPathSegment>,
+    pub location: Location,
+}
+
+impl PartialEq for JsonAccess {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident && self.path == other.path
+    }
+}
+
+impl Eq for JsonAccess {}
+
+impl FromPair for JsonAccess {
+    fn from_pair(pair: QueryPair) -> Result<Self, SyntaxErrorWithPos> {
+        let location: Location = (&pair).into();
+
+        match pair.as_rule() {
+            Rule::json_access => {
+                let mut inner = pair.into_inner();
+
+                let ident =
+                    ColumnIdent::from_pair(inner.next().ok_or_else(|| {
+                        location.error(SyntaxError::UnexpectedPair("column_ident"))
+                    })?)?;
+
+                let mut path = vec![];
+                for segment in inner {
+                    path.push(JsonPathSegment::from_pair(segment)?);
+                }
+
+                Ok(JsonAccess {
+                    ident,
+                    path,
+                    location,
+                })
+            }
+            _ => Err(location.error(SyntaxError::UnexpectedPair("json_access"))),
+        }
+    }
+}
+
+impl Locatable for JsonAccess {
+    fn location(&self) -> Location {
+        self.location
+    }
+}
+
+#[test]
+fn test_json_access() {
+    use crate::query::ast::helper::assert_parse_result;
+
+    let location = Location::pos(0);
+
+    assert_parse_result(
+        "f.map->\"key\"[0]",
+        JsonAccess {
+            ident: ColumnIdent {
+                segments: vec!["f".to_string(), "map".to_string()],
+                location,
+            },
+            path: vec![
+                JsonPathSegment::Key("key".to_string()),
+                JsonPathSegment::Index(0),
+            ],
+            location,
+        },
+        Rule::json_access,
+    );
+}
@@ -1,6 +1,7 @@
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{FromPair, Locatable, Location, QueryPair};
 use crate::query::grammar::Rule;
+use crate::types::DatabaseValue;
 use std::cmp::PartialEq;
 
 #[derive(Debug, Clone)]
@@ -234,6 +235,60 @@ impl Locatable for Null {
     }
 }
 
+/// String literal with a type prefix, e.g. `date"2021-01-01"`
+#[derive(Debug, Clone)]
+pub struct TypedLiteral {
+    pub prefix: String,
+    pub value: String,
+    pub location: Location,
+}
+
+impl PartialEq for TypedLiteral {
+    fn eq(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && self.value == other.value
+    }
+}
+
+impl Eq for TypedLiteral {}
+
+impl FromPair for TypedLiteral {
+    fn from_pair(pair: QueryPair) -> Result<Self, SyntaxErrorWithPos> {
+        let location: Location = (&pair).into();
+        match pair.as_rule() {
+            Rule::typed_string => {
+                let mut inner = pair.into_inner();
+
+                let prefix = inner
+                    .next()
+                    .filter(|inner_pair| inner_pair.as_rule() == Rule::typed_prefix)
+                    .ok_or_else(|| location.error(SyntaxError::UnexpectedPair("typed_prefix")))?
+                    .as_str()
+                    .to_lowercase();
+
+                let value = Str::from_pair(
+                    inner
+                        .next()
+                        .ok_or_else(|| location.error(SyntaxError::UnexpectedPair("string")))?,
+                )?
+                .value;
+
+                Ok(TypedLiteral {
+                    prefix,
+                    value,
+                    location,
+                })
+            }
+            _ => Err(location.error(SyntaxError::UnexpectedPair("typed_string"))),
+        }
+    }
+}
+
+impl Locatable for TypedLiteral {
+    fn location(&self) -> Location {
+        self.location
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum Literal {
     Boolean(Boolean),
@@ -242,6 +297,7 @@ pub enum Literal {
     String(Str),
     External(ExternalValue),
     Null(Null),
+    Typed(TypedLiteral),
 }
 
 impl FromPair for Literal {
@@ -261,6 +317,7 @@ impl FromPair for Literal {
                     Rule::string => Literal::String(Str::from_pair(inner)?),
                     Rule::external_ident => Literal::External(ExternalValue::from_pair(inner)?),
                     Rule::null => Literal::Null(Null::from_pair(inner)?),
+                    Rule::typed_string => Literal::Typed(TypedLiteral::from_pair(inner)?),
                     _ => return Err(location.error(SyntaxError::UnexpectedPair("literal"))),
                 })
             }
@@ -269,6 +326,35 @@ impl FromPair for Literal {
     }
 }
 
+impl Literal {
+    /// Literal of database value, `None` if the value can not be written as literal
+    pub fn from_database_value(value: &DatabaseValue, location: Location) -> Option<Literal> {
+        let integer = |value: String| Some(Literal::Integer(Integer { value, location }));
+        let float = |value: String| Some(Literal::Float(Float { value, location }));
+        let string = |value: String| Some(Literal::String(Str { value, location }));
+
+        match value {
+            DatabaseValue::Bool(value) => Some(Literal::Boolean(Boolean {
+                value: *value,
+                location,
+            })),
+            DatabaseValue::SmallInteger(value) => integer(value.to_string()),
+            DatabaseValue::UnsignedSmallInteger(value) => integer(value.to_string()),
+            DatabaseValue::Integer(value) => integer(value.to_string()),
+            DatabaseValue::UnsignedInteger(value) => integer(value.to_string()),
+            DatabaseValue::BigInteger(value) => integer(value.to_string()),
+            DatabaseValue::UnsignedBigInteger(value) => integer(value.to_string()),
+            DatabaseValue::Timestamp(value) => integer(value.to_string()),
+            DatabaseValue::Float(value) => float(value.to_string()),
+            DatabaseValue::Double(value) => float(value.to_string()),
+            DatabaseValue::Character(value) => string(value.to_string()),
+            DatabaseValue::String(value) | DatabaseValue::Text(value) => string(value.clone()),
+            DatabaseValue::Null(_) => Some(Literal::Null(Null { location })),
+            _ => None,
+        }
+    }
+}
+
 impl Locatable for Literal {
     fn location(&self) -> Location {
         match self {
@@ -278,6 +364,7 @@ impl Locatable for Literal {
             Literal::String(lit) => lit.location(),
             Literal::External(lit) => lit.location(),
             Literal::Null(lit) => lit.location(),
+            Literal::Typed(lit) => lit.location(),
         }
     }
 }
@@ -367,4 +454,14 @@ fn test_literal() {
 
     assert_parse_result("Null", Literal::Null(Null { location }), Rule::literal);
     assert_parse_result("null", Literal::Null(Null { location }), Rule::literal);
+
+    assert_parse_result(
+        "date\"2021-01-01\"",
+        Literal::Typed(TypedLiteral {
+            prefix: "date".to_string(),
+            value: "2021-01-01".to_string(),
+            location,
+        }),
+        Rule::literal,
+    );
 }
@@ -39,6 +39,13 @@ impl Location {
             Location::Span(_, e) => *e,
         }
     }
+
+    /// Line and column(both start from 1) of the start in `input`
+    pub fn line_col(&self, input: &str) -> (usize, usize) {
+        Position::new(input, self.start().min(input.len()))
+            .map(|pos| pos.line_col())
+            .unwrap_or((1, 1))
+    }
 }
 
 impl From<InputLocation> for Location {
@@ -76,3 +83,12 @@ impl Display for Location {
         }
     }
 }
+
+#[test]
+fn test_line_col() {
+    let input = "SELECT t.a AS a\nFROM test t\nWHERE t.b = 1";
+
+    assert_eq!(Location::pos(0).line_col(input), (1, 1));
+    assert_eq!(Location::span(21, 22).line_col(input), (2, 6));
+    assert_eq!(Location::pos(34).line_col(input), (3, 7));
+}
@@ -6,6 +6,7 @@ mod func;
 mod helper;
 mod ident;
 mod insert;
+mod json;
 mod literal;
 mod location;
 mod query;
@@ -19,6 +20,7 @@ pub use expr::*;
 pub use func::*;
 pub use ident::*;
 pub use insert::*;
+pub use json::*;
 pub use literal::*;
 pub use location::*;
 pub use query::*;
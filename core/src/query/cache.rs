@@ -0,0 +1,220 @@
+use crate::definitions::{FieldDefinition, TableDefinition};
+use crate::query::ast::error::SyntaxErrorWithPos;
+use crate::query::{check_query, parse_query, TypeCheckedQuery};
+use crate::types::TypeResolver;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+/// Bounded cache of type checked queries, keyed by query string and schema version.
+/// It is thread safe, wrap it with `Arc` to share it between transactions.
+pub struct QueryCache {
+    capacity: usize,
+    inner: Mutex<CacheInner>,
+}
+
+struct CacheEntry {
+    query: String,
+    schema_version: String,
+    last_used: u64,
+    checked: Arc<TypeCheckedQuery>,
+}
+
+#[derive(Default)]
+struct CacheInner {
+    tick: u64,
+    /// Entries mapped by hash of query and schema version
+    entries: HashMap<u64, CacheEntry>,
+    /// Hash of entries ordered by last used tick, the first one is the least recently used
+    recently_used: BTreeMap<u64, u64>,
+}
+
+impl CacheInner {
+    fn touch(&mut self, hash: u64) {
+        self.tick += 1;
+        let tick = self.tick;
+
+        if let Some(entry) = self.entries.get_mut(&hash) {
+            self.recently_used.remove(&entry.last_used);
+            self.recently_used.insert(tick, hash);
+            entry.last_used = tick;
+        }
+    }
+
+    fn remove(&mut self, hash: u64) {
+        if let Some(entry) = self.entries.remove(&hash) {
+            self.recently_used.remove(&entry.last_used);
+        }
+    }
+}
+
+fn cache_hash(query: &str, schema_version: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    query.hash(&mut hasher);
+    schema_version.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+impl QueryCache {
+    pub fn new(capacity: usize) -> Self {
+        QueryCache {
+            capacity: capacity.max(1),
+            inner: Mutex::new(Default::default()),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.clear();
+        inner.recently_used.clear();
+    }
+
+    pub fn get(&self, query: &str, schema_version: &str) -> Option<Arc<TypeCheckedQuery>> {
+        let hash = cache_hash(query, schema_version);
+        let mut inner = self.inner.lock().unwrap();
+        let checked = inner
+            .entries
+            .get(&hash)
+            .filter(|entry| entry.query == query && entry.schema_version == schema_version)
+            .map(|entry| entry.checked.clone())?;
+
+        inner.touch(hash);
+
+        Some(checked)
+    }
+
+    /// Insert a checked query, the least recently used one will be evicted if cache is full
+    pub fn insert(
+        &self,
+        query: &str,
+        schema_version: &str,
+        checked: TypeCheckedQuery,
+    ) -> Arc<TypeCheckedQuery> {
+        let checked = Arc::new(checked);
+        let hash = cache_hash(query, schema_version);
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.remove(hash);
+
+        if inner.entries.len() >= self.capacity {
+            let evicted = inner.recently_used.values().next().copied();
+
+            if let Some(evicted) = evicted {
+                inner.remove(evicted);
+            }
+        }
+
+        inner.entries.insert(
+            hash,
+            CacheEntry {
+                query: query.to_string(),
+                schema_version: schema_version.to_string(),
+                last_used: 0,
+                checked: checked.clone(),
+            },
+        );
+        inner.touch(hash);
+
+        checked
+    }
+
+    /// Get the cached query, or parse and type check it then put it into cache. `resolvers` is
+    /// only called if the query is not cached.
+    pub fn get_or_check<R, F>(
+        &self,
+        query: &str,
+        schema_version: &str,
+        resolvers: R,
+        definition_getter: F,
+        table_definitions: Vec<TableDefinition>,
+    ) -> Result<Arc<TypeCheckedQuery>, SyntaxErrorWithPos>
+    where
+        R: FnOnce() -> Vec<Box<dyn TypeResolver>>,
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        if let Some(checked) = self.get(query, schema_version) {
+            return Ok(checked);
+        }
+
+        let checked = check_query(
+            parse_query(query)?,
+            resolvers(),
+            definition_getter,
+            table_definitions,
+        )?;
+
+        Ok(self.insert(query, schema_version, checked))
+    }
+}
+
+#[test]
+fn test_query_cache() {
+    use crate::query::default_type_resolvers;
+
+    let definition_getter = |entity: &str, field: &str| match (entity, field) {
+        ("test", "a") => Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "numeric".to_string(),
+            field_type: "u32".to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        }),
+        _ => None,
+    };
+
+    let cache = QueryCache::new(2);
+    let query1 = "SELECT t.a AS a FROM test t";
+    let query2 = "SELECT t.a AS b FROM test t";
+    let query3 = "SELECT t.a AS c FROM test t";
+    let check = |query: &str| {
+        cache.get_or_check(
+            query,
+            "1",
+            default_type_resolvers,
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check(query1).unwrap();
+    let cached = check(query1).unwrap();
+
+    assert!(Arc::ptr_eq(&checked, &cached));
+    assert!(cache
+        .get_or_check(
+            query1,
+            "1",
+            || unreachable!("resolvers are not needed on cache hit"),
+            definition_getter,
+            vec![],
+        )
+        .is_ok());
+    assert!(cache.get(query1, "2").is_none());
+
+    check(query2).unwrap();
+    cache.get(query1, "1");
+    check(query3).unwrap();
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(query1, "1").is_some());
+    assert!(cache.get(query2, "1").is_none());
+}
@@ -0,0 +1,171 @@
+use crate::query::ast::{BinaryOperator, Expr};
+use crate::query::{binary, boolean, column, float, integer, null, string};
+use std::marker::PhantomData;
+
+/// Typed handle of a field in entity `E` with value type `T`.
+/// Generated by `EntityFieldHandleResolverPass`, e.g. `FooInner::fields("f").integer()`.
+pub struct Field<E, T> {
+    segments: Vec<String>,
+    _marker: PhantomData<(E, T)>,
+}
+
+impl<E, T> Clone for Field<E, T> {
+    fn clone(&self) -> Self {
+        Field::new(self.segments.clone())
+    }
+}
+
+impl<E, T> Field<E, T> {
+    pub fn new(segments: Vec<String>) -> Self {
+        Field {
+            segments,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    pub fn expr(&self) -> Expr {
+        column(&self.segments.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+    }
+
+    fn binary(&self, operator: BinaryOperator, other: impl AsExpr<T>) -> Expr {
+        binary(self.expr(), operator, other.as_expr())
+    }
+
+    pub fn eq(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Eq, other)
+    }
+
+    pub fn neq(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Neq, other)
+    }
+
+    pub fn lt(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Lt, other)
+    }
+
+    pub fn lte(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Lte, other)
+    }
+
+    pub fn gt(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Bt, other)
+    }
+
+    pub fn gte(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Bte, other)
+    }
+
+    pub fn add(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Plus, other)
+    }
+
+    pub fn sub(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Minus, other)
+    }
+
+    pub fn mul(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Multi, other)
+    }
+
+    pub fn div(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Div, other)
+    }
+
+    pub fn rem(&self, other: impl AsExpr<T>) -> Expr {
+        self.binary(BinaryOperator::Mod, other)
+    }
+}
+
+/// Value which can be used as operand of field with type `T`
+pub trait AsExpr<T> {
+    fn as_expr(&self) -> Expr;
+}
+
+impl<E, T> AsExpr<T> for Field<E, T> {
+    fn as_expr(&self) -> Expr {
+        self.expr()
+    }
+}
+
+impl<T> AsExpr<T> for Expr {
+    fn as_expr(&self) -> Expr {
+        self.clone()
+    }
+}
+
+macro_rules! impl_as_expr {
+    ($ty: ty, $value: ident => $expr: expr) => {
+        impl AsExpr<$ty> for $ty {
+            fn as_expr(&self) -> Expr {
+                let $value = self;
+                $expr
+            }
+        }
+
+        impl AsExpr<Option<$ty>> for $ty {
+            fn as_expr(&self) -> Expr {
+                let $value = self;
+                $expr
+            }
+        }
+
+        impl AsExpr<Option<$ty>> for Option<$ty> {
+            fn as_expr(&self) -> Expr {
+                match self {
+                    Some($value) => $expr,
+                    None => null(),
+                }
+            }
+        }
+    };
+}
+
+impl_as_expr!(i16, value => integer(*value as i128));
+impl_as_expr!(i32, value => integer(*value as i128));
+impl_as_expr!(i64, value => integer(*value as i128));
+impl_as_expr!(u16, value => integer(*value as i128));
+impl_as_expr!(u32, value => integer(*value as i128));
+impl_as_expr!(u64, value => integer(*value as i128));
+impl_as_expr!(f32, value => float(*value as f64));
+impl_as_expr!(f64, value => float(*value));
+impl_as_expr!(bool, value => boolean(*value));
+impl_as_expr!(String, value => string(value));
+
+impl AsExpr<String> for &str {
+    fn as_expr(&self) -> Expr {
+        string(self)
+    }
+}
+
+impl AsExpr<Option<String>> for &str {
+    fn as_expr(&self) -> Expr {
+        string(self)
+    }
+}
+
+#[test]
+fn test_field_handle() {
+    use crate::query::ast::{ColumnIdent, Integer, Literal, Location};
+
+    let location = Location::pos(0);
+    let field: Field<(), u32> = Field::new(vec!["f".to_string(), "integer".to_string()]);
+
+    assert_eq!(
+        field.eq(3),
+        binary(
+            Expr::ColumnIdent(ColumnIdent {
+                segments: vec!["f".to_string(), "integer".to_string()],
+                location,
+            }),
+            BinaryOperator::Eq,
+            Expr::Literal(Literal::Integer(Integer {
+                value: "3".to_string(),
+                location,
+            })),
+        )
+    );
+}
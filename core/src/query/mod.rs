@@ -1,7 +1,17 @@
+pub use cache::*;
+pub use field::*;
+pub use params::*;
+pub use parser::*;
 pub use query_builder::*;
+pub use typed_query::*;
 
 pub mod ast;
+mod cache;
+mod field;
 mod grammar;
+mod params;
+mod parser;
 mod pretty;
 mod query_builder;
 pub mod type_check;
+mod typed_query;
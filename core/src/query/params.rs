@@ -0,0 +1,348 @@
+use crate::query::TypeCheckedQuery;
+#[cfg(feature = "decimal")]
+use crate::resolver::field_resolver_seeds::DECIMAL_MAX_PRECISION;
+use crate::resolver::field_resolver_seeds::STRING_DEFAULT_LENGTH;
+use crate::types::{DatabaseType, DatabaseValue};
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ParamError {
+    #[error("MissingParam: no value bound for external value `${0}`")]
+    MissingParam(String),
+    #[error("UnusedParam: `${0}` is not used in query")]
+    UnusedParam(String),
+    #[error("MismatchedParamType: `${0}` expects \"{1}\", but \"{2}\" is bound")]
+    MismatchedParamType(String, String, String),
+}
+
+/// Rust value which can be bound to an external value
+pub trait ToParam {
+    /// Type name, which should be the same as the asserted type of type resolvers
+    fn type_name() -> String;
+
+    fn database_type() -> DatabaseType;
+
+    fn to_database_value(&self) -> DatabaseValue;
+}
+
+impl<T: ToParam> ToParam for Option<T> {
+    fn type_name() -> String {
+        format!("Option<{}>", T::type_name())
+    }
+
+    fn database_type() -> DatabaseType {
+        T::database_type()
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        match self {
+            Some(value) => value.to_database_value(),
+            None => DatabaseValue::Null(T::database_type()),
+        }
+    }
+}
+
+impl ToParam for &str {
+    fn type_name() -> String {
+        String::type_name()
+    }
+
+    fn database_type() -> DatabaseType {
+        String::database_type()
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        DatabaseValue::String(self.to_string())
+    }
+}
+
+impl ToParam for Vec<u8> {
+    fn type_name() -> String {
+        "Vec<u8>".to_string()
+    }
+
+    fn database_type() -> DatabaseType {
+        DatabaseType::Binary
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        DatabaseValue::Binary(self.clone())
+    }
+}
+
+impl<const N: usize> ToParam for [u8; N] {
+    fn type_name() -> String {
+        format!("[u8; {}]", N)
+    }
+
+    fn database_type() -> DatabaseType {
+        DatabaseType::Binary
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        DatabaseValue::Binary(self.to_vec())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl ToParam for bytes::Bytes {
+    fn type_name() -> String {
+        "Bytes".to_string()
+    }
+
+    fn database_type() -> DatabaseType {
+        DatabaseType::Binary
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        DatabaseValue::Binary(self.to_vec())
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl ToParam for rust_decimal::Decimal {
+    fn type_name() -> String {
+        "Decimal".to_string()
+    }
+
+    fn database_type() -> DatabaseType {
+        DatabaseType::Decimal(DECIMAL_MAX_PRECISION, 0)
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        DatabaseValue::Decimal(*self)
+    }
+}
+
+impl ToParam for String {
+    fn type_name() -> String {
+        "String".to_string()
+    }
+
+    fn database_type() -> DatabaseType {
+        DatabaseType::String(STRING_DEFAULT_LENGTH)
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        DatabaseValue::String(self.clone())
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToParam for serde_json::Value {
+    fn type_name() -> String {
+        "Value".to_string()
+    }
+
+    fn database_type() -> DatabaseType {
+        DatabaseType::Json
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        DatabaseValue::Json(self.clone())
+    }
+}
+
+macro_rules! impl_to_param {
+    ($ty: ty, $name: literal, $database_type: ident) => {
+        impl ToParam for $ty {
+            fn type_name() -> String {
+                $name.to_string()
+            }
+
+            fn database_type() -> DatabaseType {
+                DatabaseType::$database_type
+            }
+
+            fn to_database_value(&self) -> DatabaseValue {
+                DatabaseValue::$database_type(self.to_owned())
+            }
+        }
+    };
+}
+
+impl_to_param!(bool, "bool", Bool);
+impl_to_param!(i16, "i16", SmallInteger);
+impl_to_param!(u16, "u16", UnsignedSmallInteger);
+impl_to_param!(i32, "i32", Integer);
+impl_to_param!(u32, "u32", UnsignedInteger);
+impl_to_param!(i64, "i64", BigInteger);
+impl_to_param!(u64, "u64", UnsignedBigInteger);
+impl_to_param!(f32, "f32", Float);
+impl_to_param!(f64, "f64", Double);
+#[cfg(feature = "data-time")]
+impl_to_param!(time::Date, "Date", Date);
+#[cfg(feature = "data-time")]
+impl_to_param!(time::Time, "Time", Time);
+#[cfg(feature = "data-time")]
+impl_to_param!(time::PrimitiveDateTime, "PrimitiveDateTime", DateTime);
+
+/// Values of external values in query, e.g. `$name`
+#[derive(Default)]
+pub struct Params {
+    values: HashMap<String, (String, DatabaseValue)>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn bind<T: ToParam>(mut self, ident: &str, value: T) -> Self {
+        self.values.insert(
+            ident.to_string(),
+            (T::type_name(), value.to_database_value()),
+        );
+        self
+    }
+
+    /// Check bound values against the type assertions of query, and get the positional parameters.
+    /// External value used several times in query is bound to each of its positions.
+    pub fn resolve(self, query: &TypeCheckedQuery) -> Result<Vec<DatabaseValue>, ParamError> {
+        if let Some(ident) = self
+            .values
+            .keys()
+            .find(|ident| !query.external_values.contains(ident))
+        {
+            return Err(ParamError::UnusedParam(ident.clone()));
+        }
+
+        let mut result = vec![];
+
+        for ident in query.external_values.iter() {
+            let (ty, value) = self
+                .values
+                .get(ident)
+                .ok_or_else(|| ParamError::MissingParam(ident.clone()))?;
+
+            if let Some(expected) = query.external_value_assertion.get(ident) {
+                if expected != ty && expected != &format!("Option<{}>", ty) {
+                    return Err(ParamError::MismatchedParamType(
+                        ident.clone(),
+                        expected.clone(),
+                        ty.clone(),
+                    ));
+                }
+            }
+
+            result.push(value.clone());
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+enum Status {
+    Active,
+}
+
+#[cfg(test)]
+impl ToParam for Status {
+    fn type_name() -> String {
+        "Status".to_string()
+    }
+
+    fn database_type() -> DatabaseType {
+        DatabaseType::Integer
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        match self {
+            Status::Active => DatabaseValue::Integer(1),
+        }
+    }
+}
+
+/// Same as the implement generated for newtype declared in schema
+#[cfg(test)]
+struct UserId(u64);
+
+#[cfg(test)]
+impl ToParam for UserId {
+    fn type_name() -> String {
+        u64::type_name()
+    }
+
+    fn database_type() -> DatabaseType {
+        u64::database_type()
+    }
+
+    fn to_database_value(&self) -> DatabaseValue {
+        self.0.to_database_value()
+    }
+}
+
+#[test]
+fn test_params() {
+    use crate::definitions::FieldDefinition;
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let query = check_query(
+        parse_query(
+            "SELECT t.a AS a FROM test t WHERE (t.a = $a OR t.a > $a) \
+            AND t.b = $b AND t.status = $status AND t.user = $user",
+        )
+        .unwrap(),
+        default_type_resolvers(),
+        |entity: &str, field: &str| {
+            let (type_resolver_name, field_type, nullable) = match (entity, field) {
+                ("test", "a") => ("numeric", "u32", false),
+                ("test", "b") => ("string", "String", true),
+                ("test", "status") => ("enum", "Status(integer: Active = 1)", false),
+                // Newtype field is checked as its inner type
+                ("test", "user") => ("numeric", "u64", false),
+                _ => return None,
+            };
+
+            Some(FieldDefinition {
+                entity: "test".to_string(),
+                name: field.to_string(),
+                type_resolver_name: type_resolver_name.to_string(),
+                field_type: field_type.to_string(),
+                nullable,
+                length: None,
+                default: None,
+                columns: vec![field.to_string()],
+                tables: vec![],
+                association: None,
+            })
+        },
+        vec![],
+    )
+    .unwrap();
+
+    let values = Params::new()
+        .bind("a", 3u32)
+        .bind("b", "name")
+        .bind("status", Status::Active)
+        .bind("user", UserId(7))
+        .resolve(&query)
+        .unwrap();
+
+    assert_eq!(values.len(), 5);
+    assert!(matches!(values[0], DatabaseValue::UnsignedInteger(3)));
+    assert!(matches!(values[1], DatabaseValue::UnsignedInteger(3)));
+    assert!(matches!(&values[2], DatabaseValue::String(s) if s == "name"));
+    assert!(matches!(values[3], DatabaseValue::Integer(1)));
+    assert!(matches!(values[4], DatabaseValue::UnsignedBigInteger(7)));
+
+    assert!(matches!(
+        Params::new().bind("a", 3u32).resolve(&query),
+        Err(ParamError::MissingParam(_))
+    ));
+    assert!(matches!(
+        Params::new()
+            .bind("a", 3i64)
+            .bind("b", None::<String>)
+            .bind("status", Status::Active)
+            .bind("user", 7u64)
+            .resolve(&query),
+        Err(ParamError::MismatchedParamType(_, _, _))
+    ));
+    assert!(matches!(
+        Params::new().bind("c", true).resolve(&query),
+        Err(ParamError::UnusedParam(_))
+    ));
+}
@@ -0,0 +1,507 @@
+use crate::definitions::{FieldDefinition, TableDefinition};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{Expr, FromPair, Literal, Location, Query};
+use crate::query::grammar::{Grammar, Rule};
+use crate::query::pretty::alias::{CollectTableAlias, ReplaceIdent};
+use crate::query::pretty::Calc;
+use crate::query::type_check::{TypeCheck, TypeChecker, TypeKind};
+#[cfg(feature = "data-time")]
+use crate::resolver::field_resolver_seeds::DateTimeTypeResolver;
+#[cfg(feature = "decimal")]
+use crate::resolver::field_resolver_seeds::DecimalTypeResolver;
+#[cfg(feature = "json")]
+use crate::resolver::field_resolver_seeds::JsonTypeResolver;
+use crate::resolver::field_resolver_seeds::{
+    AssociatedEntityTypeResolver, BinaryTypeResolver, BoolTypeResolver, EmbeddedTypeResolver,
+    EnumTypeResolver, NumericTypeResolver, StringTypeResolver,
+};
+use crate::types::{TypeResolver, ValuePack};
+use pest::Parser;
+use std::collections::{HashMap, HashSet};
+
+/// Query which passed the type check.
+#[derive(Clone, Debug)]
+pub struct TypeCheckedQuery {
+    /// Query with all literals and idents wrapped by type resolvers
+    pub query: Query,
+    /// Type of each result column, mapped by result alias
+    pub result_types: HashMap<String, String>,
+    /// Asserted type of each external value, mapped by external value ident
+    pub external_value_assertion: HashMap<String, String>,
+    /// Idents of external values, in the order of positional parameters
+    pub external_values: Vec<String>,
+}
+
+pub fn default_type_resolvers() -> Vec<Box<dyn TypeResolver>> {
+    vec![
+        NumericTypeResolver::seed(),
+        StringTypeResolver::seed(),
+        BoolTypeResolver::seed(),
+        BinaryTypeResolver::seed(),
+        EnumTypeResolver::seed(),
+        #[cfg(feature = "data-time")]
+        DateTimeTypeResolver::seed(),
+        #[cfg(feature = "decimal")]
+        DecimalTypeResolver::seed(),
+        #[cfg(feature = "json")]
+        JsonTypeResolver::seed(),
+        EmbeddedTypeResolver::seed(),
+        AssociatedEntityTypeResolver::seed(),
+    ]
+}
+
+pub fn parse_query(input: &str) -> Result<Query, SyntaxErrorWithPos> {
+    let pair = Grammar::parse(Rule::query, input)
+        .map_err(|e| {
+            Location::from(e.location.clone())
+                .error(SyntaxError::ParseError(e.variant.message().to_string()))
+        })?
+        .next()
+        .ok_or_else(|| Location::pos(0).error(SyntaxError::UnexpectedPair("query")))?;
+
+    Query::from_pair(pair)
+}
+
+pub fn parse_expr(input: &str) -> Result<Expr, SyntaxErrorWithPos> {
+    let pair = Grammar::parse(Rule::expr, input)
+        .map_err(|e| {
+            Location::from(e.location.clone())
+                .error(SyntaxError::ParseError(e.variant.message().to_string()))
+        })?
+        .next()
+        .ok_or_else(|| Location::pos(0).error(SyntaxError::UnexpectedPair("expr")))?;
+
+    Expr::from_pair(pair)
+}
+
+/// Check expression assigned to a field of entity, e.g. default value of column. Bare idents in
+/// expression are resolved as fields of the same entity.
+pub fn check_field_expr<F>(
+    mut expr: Expr,
+    entity: &str,
+    field: &str,
+    fields: HashSet<String>,
+    resolvers: Vec<Box<dyn TypeResolver>>,
+    definition_getter: F,
+) -> Result<Expr, SyntaxErrorWithPos>
+where
+    F: Fn(&str, &str) -> Option<FieldDefinition>,
+{
+    let alias = qualify_entity_expr(&mut expr, entity, fields)?;
+    let mut type_checker = TypeChecker::new(resolvers, alias, definition_getter, vec![]);
+
+    expr.check_field_assignment(&mut type_checker, entity, field)?;
+
+    Ok(expr)
+}
+
+/// Check expression on entity, e.g. check constraint or index expression. Bare idents in
+/// expression are resolved as fields of the entity.
+pub fn check_entity_expr<F>(
+    mut expr: Expr,
+    entity: &str,
+    fields: HashSet<String>,
+    type_kind: Option<TypeKind>,
+    resolvers: Vec<Box<dyn TypeResolver>>,
+    definition_getter: F,
+) -> Result<Expr, SyntaxErrorWithPos>
+where
+    F: Fn(&str, &str) -> Option<FieldDefinition>,
+{
+    let alias = qualify_entity_expr(&mut expr, entity, fields)?;
+    let mut type_checker = TypeChecker::new(resolvers, alias, definition_getter, vec![]);
+
+    expr.check_type(&mut type_checker, type_kind)?;
+
+    Ok(expr)
+}
+
+/// Prefix bare idents with entity name, returns alias map of entity
+fn qualify_entity_expr(
+    expr: &mut Expr,
+    entity: &str,
+    fields: HashSet<String>,
+) -> Result<HashMap<String, String>, SyntaxErrorWithPos> {
+    let alias: HashMap<_, _> = vec![(entity.to_string(), entity.to_string())]
+        .into_iter()
+        .collect();
+    let field_map = vec![(entity.to_string(), fields)].into_iter().collect();
+
+    expr.replace(&alias, &HashMap::new(), &field_map)?;
+
+    Ok(alias)
+}
+
+/// Rewrite bare idents in source of expression, e.g. from field names into column names. Idents
+/// not in `names` are kept as written.
+pub fn rename_idents(
+    input: &str,
+    names: &HashMap<String, String>,
+) -> Result<String, SyntaxErrorWithPos> {
+    let mut expr = parse_expr(input)?;
+    let mut replacements = vec![];
+
+    visit_bare_idents(&mut expr, &mut |ident_expr: &mut Expr| {
+        if let Expr::ColumnIdent(ident) = ident_expr {
+            if let Some(name) = names.get(&ident.segments[0]) {
+                replacements.push((ident.location, name));
+            }
+        }
+    });
+
+    replacements.sort_by_key(|(location, _)| location.start());
+
+    let mut result = String::new();
+    let mut last = 0;
+
+    for (location, name) in replacements {
+        // span of ident may contain the whitespace following it
+        let ident = input[location.start()..location.end()].trim_end();
+
+        result.push_str(&input[last..location.start()]);
+        result.push_str(name);
+        last = location.start() + ident.len();
+    }
+
+    result.push_str(&input[last..]);
+
+    Ok(result)
+}
+
+/// Evaluate check constraint with values of columns. `None` is returned if the result can only be
+/// decided by database, e.g. function calls, unsupported value types or null result.
+pub fn eval_check(input: &str, values: &ValuePack) -> Result<Option<bool>, SyntaxErrorWithPos> {
+    let mut expr = parse_expr(input)?;
+    let mut bound = true;
+
+    visit_bare_idents(&mut expr, &mut |ident_expr: &mut Expr| {
+        let literal = match ident_expr {
+            Expr::ColumnIdent(ident) => values
+                .get(&ident.segments[0])
+                .and_then(|value| Literal::from_database_value(value, ident.location)),
+            _ => None,
+        };
+
+        match literal {
+            Some(literal) => *ident_expr = Expr::Literal(literal),
+            None => bound = false,
+        }
+    });
+
+    if !bound {
+        return Ok(None);
+    }
+
+    match expr.calc() {
+        Ok(Some(Literal::Boolean(result))) => Ok(Some(result.value)),
+        _ => Ok(None),
+    }
+}
+
+/// Visit idents without table alias in expression
+fn visit_bare_idents<V>(expr: &mut Expr, visitor: &mut V)
+where
+    V: FnMut(&mut Expr),
+{
+    if matches!(expr, Expr::ColumnIdent(ident) if ident.segments.len() == 1) {
+        visitor(expr);
+        return;
+    }
+
+    match expr {
+        Expr::FunctionCall(function) => {
+            for parameter in function.parameters.iter_mut() {
+                visit_bare_idents(parameter, visitor);
+            }
+        }
+        Expr::Unary(unary) => visit_bare_idents(&mut unary.right, visitor),
+        Expr::Binary(binary) => {
+            visit_bare_idents(&mut binary.left, visitor);
+            visit_bare_idents(&mut binary.right, visitor);
+        }
+        Expr::Cast(cast) => visit_bare_idents(&mut cast.expr, visitor),
+        _ => {}
+    }
+}
+
+pub fn check_query<F>(
+    mut query: Query,
+    resolvers: Vec<Box<dyn TypeResolver>>,
+    definition_getter: F,
+    table_definitions: Vec<TableDefinition>,
+) -> Result<TypeCheckedQuery, SyntaxErrorWithPos>
+where
+    F: Fn(&str, &str) -> Option<FieldDefinition>,
+{
+    let (mut alias, generated_alias) = query.collect_table_alias()?;
+    alias.extend(generated_alias);
+
+    let mut type_checker = TypeChecker::new(resolvers, alias, definition_getter, table_definitions);
+
+    query.check_type(&mut type_checker)?;
+
+    Ok(TypeCheckedQuery {
+        query,
+        result_types: type_checker.result_type().clone(),
+        external_value_assertion: type_checker.external_value_assertion().clone(),
+        external_values: type_checker.external_values(),
+    })
+}
+
+#[test]
+fn test_check_query() {
+    let checked = check_query(
+        parse_query("SELECT t.a * 10 AS result FROM test t WHERE t.b = $value").unwrap(),
+        default_type_resolvers(),
+        |entity: &str, field: &str| match (entity, field) {
+            ("test", "a") | ("test", "b") => Some(FieldDefinition {
+                entity: "test".to_string(),
+                name: field.to_string(),
+                type_resolver_name: "numeric".to_string(),
+                field_type: "u32".to_string(),
+                nullable: false,
+                length: None,
+                default: None,
+                columns: vec![field.to_string()],
+                tables: vec![],
+                association: None,
+            }),
+            _ => None,
+        },
+        vec![],
+    )
+    .unwrap();
+
+    assert_eq!(
+        checked.result_types.get("result").map(|s| s.as_str()),
+        Some("u32")
+    );
+
+    assert_eq!(checked.external_values, vec!["value".to_string()]);
+
+    assert!(parse_query("SELECT FROM").is_err());
+}
+
+#[test]
+fn test_nullable_check() {
+    let definition_getter = |entity: &str, field: &str| match (entity, field) {
+        ("test", "a") | ("test", "b") => Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "numeric".to_string(),
+            field_type: "u32".to_string(),
+            nullable: field == "b",
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        }),
+        _ => None,
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check("SELECT t.b + 1 AS result FROM test t WHERE t.b > t.a").unwrap();
+    assert_eq!(
+        checked.result_types.get("result").map(|s| s.as_str()),
+        Some("Option<u32>")
+    );
+
+    let checked = check("SELECT COALESCE(t.b, 0) AS result FROM test t").unwrap();
+    assert_eq!(
+        checked.result_types.get("result").map(|s| s.as_str()),
+        Some("u32")
+    );
+
+    assert!(check("UPDATE test t SET t.a = COALESCE(t.b, t.a)").is_ok());
+    assert!(matches!(
+        check("UPDATE test t SET t.a = t.b + 1").map_err(|e| e.error),
+        Err(SyntaxError::NullableAssignment(_, _))
+    ));
+}
+
+#[test]
+fn test_generated_column() {
+    use crate::definitions::{
+        ColumnDefault, ColumnDefinition, ColumnType, TableDefinition, TableType,
+    };
+    use crate::types::DatabaseType;
+
+    let definition_getter = |entity: &str, field: &str| match (entity, field) {
+        ("test", "a") => Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "numeric".to_string(),
+            field_type: "u32".to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        }),
+        ("test", "b") => Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "numeric".to_string(),
+            field_type: "u32".to_string(),
+            nullable: false,
+            length: None,
+            default: Some(ColumnDefault::Generated("a * 2".to_string())),
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        }),
+        _ => None,
+    };
+
+    let column = |name: &str| {
+        let field = definition_getter("test", name).unwrap();
+
+        ColumnDefinition {
+            name: name.to_string(),
+            ty: ColumnType::NormalColumn(name.to_string()),
+            data_type: DatabaseType::UnsignedInteger,
+            unique: false,
+            auto_increase: false,
+            primary_key: false,
+            nullable: field.nullable,
+            default: field.default,
+            charset: None,
+            collation: None,
+            enum_variants: vec![],
+        }
+    };
+
+    let table = TableDefinition {
+        name: "test".to_string(),
+        ty: TableType::NormalEntityTable("test".to_string()),
+        columns: vec![column("a"), column("b")],
+        indexes: vec![],
+        foreign_keys: vec![],
+        checks: vec![],
+        version: None,
+        created_at: None,
+        updated_at: None,
+    };
+
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![table.clone()],
+        )
+    };
+
+    assert!(check("INSERT INTO test (a, b) VALUES (1, DEFAULT)").is_ok());
+    assert!(check("INSERT INTO test (a) VALUES (1)").is_ok());
+    assert!(matches!(
+        check("INSERT INTO test DEFAULT VALUES").map_err(|e| e.error),
+        Err(SyntaxError::MissingRequiredColumn(column)) if column == "a"
+    ));
+    assert!(matches!(
+        check("INSERT INTO test (b) VALUES (DEFAULT)").map_err(|e| e.error),
+        Err(SyntaxError::MissingRequiredColumn(column)) if column == "a"
+    ));
+    assert!(matches!(
+        check("INSERT INTO test (a, b) VALUES (DEFAULT, DEFAULT)").map_err(|e| e.error),
+        Err(SyntaxError::NonOmissibleColumn(column)) if column == "a"
+    ));
+    assert!(matches!(
+        check("INSERT INTO unknown DEFAULT VALUES").map_err(|e| e.error),
+        Err(SyntaxError::UnknownEntity(_))
+    ));
+    assert!(matches!(
+        check("INSERT INTO test (a, b) VALUES (1, 2)").map_err(|e| e.error),
+        Err(SyntaxError::GeneratedColumnAssignment(_))
+    ));
+    assert!(matches!(
+        check("UPDATE test t SET t.b = 2").map_err(|e| e.error),
+        Err(SyntaxError::GeneratedColumnAssignment(_))
+    ));
+
+    let checked = check_field_expr(
+        parse_expr("a * 2").unwrap(),
+        "test",
+        "b",
+        vec!["a".to_string(), "b".to_string()].into_iter().collect(),
+        default_type_resolvers(),
+        definition_getter,
+    );
+    assert!(checked.is_ok());
+}
+
+#[test]
+fn test_check_constraint() {
+    use crate::types::DatabaseValue;
+
+    let definition_getter = |entity: &str, field: &str| match (entity, field) {
+        ("test", "price") | ("test", "discount") => Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "numeric".to_string(),
+            field_type: "u32".to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        }),
+        _ => None,
+    };
+    let fields: HashSet<_> = vec!["price".to_string(), "discount".to_string()]
+        .into_iter()
+        .collect();
+    let check = |input: &str| {
+        check_entity_expr(
+            parse_expr(input).unwrap(),
+            "test",
+            fields.clone(),
+            Some(TypeKind::Boolean),
+            default_type_resolvers(),
+            definition_getter,
+        )
+    };
+
+    assert!(check("discount < price").is_ok());
+    assert!(matches!(
+        check("price + 1").map_err(|e| e.error),
+        Err(SyntaxError::TypeError(_, _))
+    ));
+
+    let names = vec![("price".to_string(), "unit_price".to_string())]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        rename_idents("discount < price AND price > 0", &names).unwrap(),
+        "discount < unit_price AND unit_price > 0"
+    );
+    assert_eq!(
+        rename_idents("(price)>0 AND price  = discount", &names).unwrap(),
+        "(unit_price)>0 AND unit_price  = discount"
+    );
+
+    let mut values: ValuePack = vec![
+        ("price".to_string(), DatabaseValue::UnsignedInteger(10)),
+        ("discount".to_string(), DatabaseValue::UnsignedInteger(20)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        eval_check("discount < price", &values).unwrap(),
+        Some(false)
+    );
+
+    values.insert("discount".to_string(), DatabaseValue::UnsignedInteger(5));
+    assert_eq!(eval_check("discount < price", &values).unwrap(), Some(true));
+    assert_eq!(eval_check("discount < abs(price)", &values).unwrap(), None);
+}
@@ -217,6 +217,13 @@ impl ReplaceIdent for Expr {
     ) -> Result<(), SyntaxErrorWithPos> {
         match self {
             Expr::ColumnIdent(ident) => ident.replace(generated_alias, table_alias, field_map),
+            Expr::FunctionCall(function) => {
+                for parameter in function.parameters.iter_mut() {
+                    parameter.replace(generated_alias, table_alias, field_map)?;
+                }
+
+                Ok(())
+            }
             Expr::Binary(binary) => {
                 binary
                     .left
@@ -226,6 +233,12 @@ impl ReplaceIdent for Expr {
                     .replace(generated_alias, table_alias, field_map)
             }
             Expr::Unary(unary) => unary.right.replace(generated_alias, table_alias, field_map),
+            Expr::Cast(cast) => cast.expr.replace(generated_alias, table_alias, field_map),
+            Expr::JsonAccess(access) => {
+                access
+                    .ident
+                    .replace(generated_alias, table_alias, field_map)
+            }
             _ => Ok(()),
         }
     }
@@ -1,7 +1,7 @@
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{
-    Binary, BinaryOperator, Boolean, ColumnIdent, DeleteQuery, Expr, Float, FromClause,
-    GroupByClause, InsertQuery, Integer, JoinClause, JoinOn, Literal, Locatable, Null,
+    Binary, BinaryOperator, Boolean, Cast, ColumnIdent, DeleteQuery, Expr, Float, FromClause,
+    GroupByClause, InsertQuery, Integer, JoinClause, JoinOn, JsonAccess, Literal, Locatable, Null,
     OrderByClause, Query, SelectClause, SelectQuery, SetClause, Unary, UpdateQuery, ValueItem,
 };
 use crate::query::type_check::TypeKind;
@@ -20,7 +20,10 @@ impl Calc for Expr {
             Expr::ColumnIdent(ident) => ident.calc(),
             Expr::Binary(binary) => binary.calc(),
             Expr::Unary(unary) => unary.calc(),
-            _ => unimplemented!(),
+            Expr::Cast(cast) => cast.calc(),
+            Expr::JsonAccess(access) => access.calc(),
+            // Functions are evaluated by database
+            Expr::FunctionCall(_) => Ok(None),
         }
     }
 }
@@ -37,6 +40,18 @@ impl Calc for Literal {
 
 impl Calc for ColumnIdent {}
 
+impl Calc for JsonAccess {}
+
+impl Calc for Cast {
+    fn calc(&mut self) -> Result<Option<Literal>, SyntaxErrorWithPos> {
+        if let Some(lit) = self.expr.calc()? {
+            self.expr = Box::new(Expr::Literal(lit));
+        }
+
+        Ok(None)
+    }
+}
+
 impl Calc for Binary {
     fn calc(&mut self) -> Result<Option<Literal>, SyntaxErrorWithPos> {
         let location = self.location();
@@ -391,10 +406,8 @@ impl CalcExpr for ValueItem {
 
 impl CalcExpr for InsertQuery {
     fn calc_expr(&mut self) -> Result<(), SyntaxErrorWithPos> {
-        for expr in self.values.iter_mut() {
-            if let Some(new_expr) = expr.calc()? {
-                *expr = Expr::Literal(new_expr)
-            }
+        for value in self.values.iter_mut() {
+            value.calc_expr()?;
         }
 
         Ok(())
@@ -1,4 +1,18 @@
-use crate::Transaction;
+use crate::definitions::{FieldDefinition, TableDefinition, TableType};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{
+    Binary, BinaryOperator, Boolean, ColumnIdent, DeleteQuery, Expr, ExternalValue, Float,
+    FromClause, GroupByClause, Integer, JoinClause, JoinOn, JoinType, Literal, Location, Null,
+    Order, OrderByClause, Query, SelectClause, SelectQuery, SetClause, Str, TableReference,
+    UpdateQuery, ValueItem,
+};
+use crate::query::{check_query, default_type_resolvers, TypeCheckedQuery};
+use crate::{Entity, Transaction};
+use std::any::type_name;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+type DefinitionGetter = fn(&str) -> Option<FieldDefinition>;
 
 #[allow(dead_code)]
 pub struct QueryBuilderFactory<'t> {
@@ -9,4 +23,525 @@ impl<'t> QueryBuilderFactory<'t> {
     pub fn create(transaction: &'t Transaction) -> QueryBuilderFactory<'t> {
         QueryBuilderFactory { transaction }
     }
+
+    /// Select from entity `E`. Without any `column`, the whole entity will be selected.
+    pub fn select<E: Entity>(&self, alias: &str) -> SelectQueryBuilder<'t> {
+        let mut entities = EntityCollection::default();
+
+        SelectQueryBuilder {
+            transaction: self.transaction,
+            query: SelectQuery {
+                select_clause: SelectClause {
+                    items: vec![],
+                    location: builder_location(),
+                },
+                from: FromClause {
+                    table: entities.table_reference::<E>(alias),
+                    join: vec![],
+                    location: builder_location(),
+                },
+                where_clause: None,
+                group_by_clause: None,
+                order_by_clause: None,
+                limit: None,
+                offset: None,
+                location: builder_location(),
+            },
+            entities,
+        }
+    }
+
+    pub fn update<E: Entity>(&self, alias: &str) -> UpdateQueryBuilder<'t> {
+        let mut entities = EntityCollection::default();
+
+        UpdateQueryBuilder {
+            transaction: self.transaction,
+            query: UpdateQuery {
+                update_table: entities.table_reference::<E>(alias),
+                set_clause: SetClause {
+                    items: vec![],
+                    location: builder_location(),
+                },
+                from_table: None,
+                where_clause: None,
+                location: builder_location(),
+            },
+            alias: alias.to_string(),
+            entities,
+        }
+    }
+
+    pub fn delete<E: Entity>(&self, alias: &str) -> DeleteQueryBuilder<'t> {
+        let mut entities = EntityCollection::default();
+
+        DeleteQueryBuilder {
+            transaction: self.transaction,
+            query: DeleteQuery {
+                from: FromClause {
+                    table: entities.table_reference::<E>(alias),
+                    join: vec![],
+                    location: builder_location(),
+                },
+                where_clause: None,
+                location: builder_location(),
+            },
+            entities,
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct SelectQueryBuilder<'t> {
+    transaction: &'t Transaction,
+    query: SelectQuery,
+    entities: EntityCollection,
+}
+
+impl<'t> SelectQueryBuilder<'t> {
+    pub fn column(mut self, expr: Expr, alias: Option<&str>) -> Self {
+        self.query
+            .select_clause
+            .items
+            .push((expr, alias.map(|s| s.to_string())));
+        self
+    }
+
+    pub fn join<E: Entity>(mut self, ty: JoinType, alias: &str, on: Expr) -> Self {
+        self.query.from.join.push(JoinClause::JoinOn(JoinOn {
+            ty,
+            table: self.entities.table_reference::<E>(alias),
+            on,
+            location: builder_location(),
+        }));
+        self
+    }
+
+    pub fn filter(mut self, expr: Expr) -> Self {
+        self.query.where_clause = Some(and_where(self.query.where_clause.take(), expr));
+        self
+    }
+
+    pub fn group_by(mut self, by: Expr, having: Option<Expr>) -> Self {
+        self.query.group_by_clause = Some(GroupByClause {
+            by,
+            having,
+            location: builder_location(),
+        });
+        self
+    }
+
+    pub fn order_by(mut self, expr: Expr, order: Order) -> Self {
+        self.query
+            .order_by_clause
+            .get_or_insert_with(|| OrderByClause {
+                items: vec![],
+                location: builder_location(),
+            })
+            .items
+            .push((expr, order));
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.query.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.query.offset = Some(offset);
+        self
+    }
+
+    pub fn build(self) -> Result<TypeCheckedQuery, SyntaxErrorWithPos> {
+        self.entities.check(Query::Select(Box::new(self.query)))
+    }
+}
+
+#[allow(dead_code)]
+pub struct UpdateQueryBuilder<'t> {
+    transaction: &'t Transaction,
+    query: UpdateQuery,
+    alias: String,
+    entities: EntityCollection,
+}
+
+impl<'t> UpdateQueryBuilder<'t> {
+    pub fn set(mut self, field: &str, value: Expr) -> Self {
+        let ident = column_ident(&[self.alias.as_str(), field]);
+        self.query
+            .set_clause
+            .items
+            .push((ident, ValueItem::Expr(value)));
+        self
+    }
+
+    pub fn set_default(mut self, field: &str) -> Self {
+        let ident = column_ident(&[self.alias.as_str(), field]);
+        self.query
+            .set_clause
+            .items
+            .push((ident, ValueItem::Default));
+        self
+    }
+
+    pub fn filter(mut self, expr: Expr) -> Self {
+        self.query.where_clause = Some(and_where(self.query.where_clause.take(), expr));
+        self
+    }
+
+    pub fn build(self) -> Result<TypeCheckedQuery, SyntaxErrorWithPos> {
+        self.entities.check(Query::Update(Box::new(self.query)))
+    }
+}
+
+#[allow(dead_code)]
+pub struct DeleteQueryBuilder<'t> {
+    transaction: &'t Transaction,
+    query: DeleteQuery,
+    entities: EntityCollection,
+}
+
+impl<'t> DeleteQueryBuilder<'t> {
+    pub fn filter(mut self, expr: Expr) -> Self {
+        self.query.where_clause = Some(and_where(self.query.where_clause.take(), expr));
+        self
+    }
+
+    pub fn build(self) -> Result<TypeCheckedQuery, SyntaxErrorWithPos> {
+        self.entities.check(Query::Delete(Box::new(self.query)))
+    }
+}
+
+/// Field definition getters of entities used in query, mapped by entity name, and table
+/// definitions of them
+#[derive(Default)]
+struct EntityCollection {
+    getters: HashMap<String, DefinitionGetter>,
+    definitions: Vec<TableDefinition>,
+    /// Error of referenced entity, it is returned when query is built
+    error: Option<SyntaxError>,
+}
+
+impl EntityCollection {
+    fn table_reference<E: Entity>(&mut self, alias: &str) -> TableReference {
+        let name = match entity_name::<E>() {
+            Some(name) => name,
+            None => {
+                let name = type_name::<E>().to_string();
+                self.error
+                    .get_or_insert_with(|| SyntaxError::UnknownEntity(name.clone()));
+
+                name
+            }
+        };
+        if !self.getters.contains_key(&name) {
+            self.definitions.extend(E::get_definitions());
+        }
+        self.getters
+            .insert(name.clone(), E::get_field_definition as DefinitionGetter);
+
+        TableReference {
+            name,
+            alias: Some(alias.to_string()),
+            location: builder_location(),
+        }
+    }
+
+    fn check(self, query: Query) -> Result<TypeCheckedQuery, SyntaxErrorWithPos> {
+        if let Some(error) = self.error {
+            return Err(builder_location().error(error));
+        }
+
+        let getters = self.getters;
+
+        check_query(
+            query,
+            default_type_resolvers(),
+            move |entity, field| getters.get(entity).and_then(|getter| getter(field)),
+            self.definitions,
+        )
+    }
+}
+
+fn entity_name<E: Entity>() -> Option<String> {
+    E::get_definitions()
+        .into_iter()
+        .find_map(|definition| match definition.ty {
+            TableType::NormalEntityTable(name) => Some(name),
+            _ => None,
+        })
+}
+
+fn and_where(current: Option<Expr>, expr: Expr) -> Expr {
+    match current {
+        Some(current) => binary(current, BinaryOperator::And, expr),
+        None => expr,
+    }
+}
+
+/// Nodes created by builders get increasing positions, external values of built query are ordered
+/// as they are created
+fn builder_location() -> Location {
+    static NEXT_POSITION: AtomicUsize = AtomicUsize::new(0);
+
+    Location::pos(NEXT_POSITION.fetch_add(1, Ordering::Relaxed))
+}
+
+fn column_ident(segments: &[&str]) -> ColumnIdent {
+    ColumnIdent {
+        segments: segments.iter().map(|s| s.to_string()).collect(),
+        location: builder_location(),
+    }
+}
+
+pub fn column(segments: &[&str]) -> Expr {
+    Expr::ColumnIdent(column_ident(segments))
+}
+
+pub fn binary(left: Expr, operator: BinaryOperator, right: Expr) -> Expr {
+    Expr::Binary(Binary {
+        operator,
+        left: Box::new(left),
+        right: Box::new(right),
+        location: builder_location(),
+    })
+}
+
+pub fn external(ident: &str) -> Expr {
+    Expr::Literal(Literal::External(ExternalValue {
+        ident: ident.to_string(),
+        location: builder_location(),
+    }))
+}
+
+pub fn integer(value: i128) -> Expr {
+    Expr::Literal(Literal::Integer(Integer {
+        value: value.to_string(),
+        location: builder_location(),
+    }))
+}
+
+/// Float literal, `value` must be finite. Debug format keeps the decimal point or exponent of
+/// integral values, e.g. `1.0` and `1e20`
+pub fn float(value: f64) -> Expr {
+    Expr::Literal(Literal::Float(Float {
+        value: format!("{:?}", value),
+        location: builder_location(),
+    }))
+}
+
+pub fn string(value: &str) -> Expr {
+    Expr::Literal(Literal::String(Str {
+        value: value.to_string(),
+        location: builder_location(),
+    }))
+}
+
+pub fn boolean(value: bool) -> Expr {
+    Expr::Literal(Literal::Boolean(Boolean {
+        value,
+        location: builder_location(),
+    }))
+}
+
+pub fn null() -> Expr {
+    Expr::Literal(Literal::Null(Null {
+        location: builder_location(),
+    }))
+}
+
+#[cfg(test)]
+#[derive(Clone)]
+struct Product;
+
+#[cfg(test)]
+impl Entity for Product {
+    fn from_database_value(
+        _result: &crate::types::ValuePack,
+    ) -> Result<Self, crate::resolver::error::DataConvertError> {
+        Ok(Product)
+    }
+
+    fn to_database_values(
+        &self,
+    ) -> Result<crate::types::ValuePack, crate::resolver::error::DataConvertError> {
+        Ok(HashMap::new())
+    }
+
+    fn get_definitions() -> Vec<TableDefinition> {
+        vec![TableDefinition {
+            name: "product".to_string(),
+            ty: TableType::NormalEntityTable("Product".to_string()),
+            columns: vec![],
+            indexes: vec![],
+            foreign_keys: vec![],
+            checks: vec![],
+            version: None,
+            created_at: None,
+            updated_at: None,
+        }]
+    }
+
+    fn get_field_definition(field_name: &str) -> Option<FieldDefinition> {
+        let field_type = match field_name {
+            "id" => "u32",
+            "price" => "f64",
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "Product".to_string(),
+            name: field_name.to_string(),
+            type_resolver_name: "numeric".to_string(),
+            field_type: field_type.to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: vec![field_name.to_string()],
+            tables: vec![],
+            association: None,
+        })
+    }
+
+    fn primary_key_values(
+        &self,
+    ) -> Result<crate::types::ValuePack, crate::resolver::error::DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+#[cfg(test)]
+fn check_product_query(input: &str) -> TypeCheckedQuery {
+    use crate::query::parse_query;
+
+    check_query(
+        parse_query(input).unwrap(),
+        default_type_resolvers(),
+        |entity, field| match entity {
+            "Product" => Product::get_field_definition(field),
+            _ => None,
+        },
+        Product::get_definitions(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_select_builder() {
+    let transaction = Transaction::default();
+    let built = transaction
+        .create_query_builder()
+        .select::<Product>("p")
+        .column(column(&["p", "price"]), Some("price"))
+        .filter(binary(
+            column(&["p", "price"]),
+            BinaryOperator::Bt,
+            float(1.0),
+        ))
+        .filter(binary(
+            column(&["p", "id"]),
+            BinaryOperator::Eq,
+            external("id"),
+        ))
+        .filter(binary(
+            column(&["p", "price"]),
+            BinaryOperator::Lt,
+            external("max_price"),
+        ))
+        .order_by(column(&["p", "id"]), Order::Desc)
+        .limit(10)
+        .build()
+        .unwrap();
+    let expected = check_product_query(
+        "SELECT p.price AS price FROM Product p WHERE p.price > 1.0 AND p.id = $id \
+        AND p.price < $max_price ORDER BY p.id DESC LIMIT 10",
+    );
+
+    assert_eq!(built.query, expected.query);
+    assert_eq!(built.result_types, expected.result_types);
+    assert_eq!(built.external_values, expected.external_values);
+
+    assert!(transaction
+        .create_query_builder()
+        .select::<Product>("p")
+        .filter(binary(
+            column(&["p", "price"]),
+            BinaryOperator::Eq,
+            string("a")
+        ))
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_update_and_delete_builder() {
+    let transaction = Transaction::default();
+    let built = transaction
+        .create_query_builder()
+        .update::<Product>("p")
+        .set("price", float(1e20))
+        .filter(binary(column(&["p", "id"]), BinaryOperator::Eq, integer(1)))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        built.query,
+        check_product_query("UPDATE Product p SET p.price = 1e20 WHERE p.id = 1").query
+    );
+
+    let built = transaction
+        .create_query_builder()
+        .delete::<Product>("p")
+        .filter(binary(column(&["p", "id"]), BinaryOperator::Eq, integer(1)))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        built.query,
+        check_product_query("DELETE FROM Product p WHERE p.id = 1").query
+    );
+}
+
+#[test]
+fn test_unknown_entity() {
+    #[derive(Clone)]
+    struct Unknown;
+
+    impl Entity for Unknown {
+        fn from_database_value(
+            _result: &crate::types::ValuePack,
+        ) -> Result<Self, crate::resolver::error::DataConvertError> {
+            Ok(Unknown)
+        }
+
+        fn to_database_values(
+            &self,
+        ) -> Result<crate::types::ValuePack, crate::resolver::error::DataConvertError> {
+            Ok(HashMap::new())
+        }
+
+        fn get_definitions() -> Vec<TableDefinition> {
+            vec![]
+        }
+
+        fn get_field_definition(_field_name: &str) -> Option<FieldDefinition> {
+            None
+        }
+
+        fn primary_key_values(
+            &self,
+        ) -> Result<crate::types::ValuePack, crate::resolver::error::DataConvertError> {
+            Ok(HashMap::new())
+        }
+    }
+
+    let transaction = Transaction::default();
+    assert!(matches!(
+        transaction
+            .create_query_builder()
+            .select::<Unknown>("u")
+            .build()
+            .map_err(|e| e.error),
+        Err(SyntaxError::UnknownEntity(_))
+    ));
 }
@@ -1,6 +1,8 @@
 use crate::definitions::FieldDefinition;
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
-use crate::query::ast::{Binary, ColumnIdent, Expr, Literal, Locatable, Unary};
+use crate::query::ast::{
+    Binary, Cast, ColumnIdent, Expr, FunctionCall, JsonAccess, Literal, Locatable, Unary,
+};
 use crate::query::type_check::{TypeChecker, TypeInfer};
 use crate::types::{ExprWrapper, IdentResolveStatus, TypeInfo};
 
@@ -17,7 +19,9 @@ impl TypeInfer for Expr {
             Expr::Binary(binary) => binary.try_wrap(ty_checker),
             Expr::Unary(unary) => unary.try_wrap(ty_checker),
             Expr::Literal(lit) => lit.try_wrap(ty_checker),
-            _ => unimplemented!(),
+            Expr::FunctionCall(call) => call.try_wrap(ty_checker),
+            Expr::Cast(cast) => cast.try_wrap(ty_checker),
+            Expr::JsonAccess(access) => access.try_wrap(ty_checker),
         }
     }
 
@@ -34,7 +38,9 @@ impl TypeInfer for Expr {
             Expr::Binary(binary) => binary.wrap_with_ty(ty_checker, type_info),
             Expr::Unary(unary) => unary.wrap_with_ty(ty_checker, type_info),
             Expr::Literal(lit) => lit.wrap_with_ty(ty_checker, type_info),
-            _ => unimplemented!(),
+            Expr::FunctionCall(call) => call.wrap_with_ty(ty_checker, type_info),
+            Expr::Cast(cast) => cast.wrap_with_ty(ty_checker, type_info),
+            Expr::JsonAccess(access) => access.wrap_with_ty(ty_checker, type_info),
         }
     }
 }
@@ -148,6 +154,253 @@ impl TypeInfer for Unary {
     }
 }
 
+impl TypeInfer for Cast {
+    fn try_wrap<F>(
+        &self,
+        ty_checker: &mut TypeChecker<F>,
+    ) -> Result<Option<ExprWrapper>, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        let target = ty_checker.get_cast_type_info(&self.ty).ok_or_else(|| {
+            self.location()
+                .error(SyntaxError::UnknownType(self.ty.clone()))
+        })?;
+
+        let item = match self.expr.try_wrap(ty_checker)? {
+            Some(item) => item,
+            None => self.expr.wrap_with_ty(ty_checker, target.clone())?,
+        };
+
+        let item_type_info = item.type_info.clone();
+        let item_location = item.location();
+        let item_expr = ty_checker
+            .get_resolver(&item_type_info.resolver_name)
+            .ok_or_else(|| {
+                item_location.error(SyntaxError::UnknownResolverName(
+                    item_type_info.resolver_name.clone(),
+                ))
+            })?
+            .unwrap_expr(item)?;
+
+        let resolver = ty_checker
+            .get_resolver(&target.resolver_name)
+            .ok_or_else(|| {
+                self.location().error(SyntaxError::UnknownResolverName(
+                    target.resolver_name.clone(),
+                ))
+            })?;
+
+        resolver
+            .handle_cast(
+                ExprWrapper {
+                    exprs: vec![item_expr],
+                    type_info: item_type_info.clone(),
+                    location: item_location,
+                },
+                TypeInfo {
+                    nullable: item_type_info.nullable,
+                    ..target
+                },
+                self.location,
+            )
+            .map(Some)
+    }
+}
+
+impl TypeInfer for FunctionCall {
+    fn try_wrap<F>(
+        &self,
+        ty_checker: &mut TypeChecker<F>,
+    ) -> Result<Option<ExprWrapper>, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        self.wrap_function(ty_checker, None)
+    }
+
+    fn wrap_with_ty<F>(
+        &self,
+        ty_checker: &mut TypeChecker<F>,
+        type_info: TypeInfo,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        self.wrap_function(ty_checker, Some(type_info.clone()))?
+            .ok_or_else(|| {
+                self.location()
+                    .error(SyntaxError::CannotBeWrappedInto(type_info.field_type))
+            })
+    }
+}
+
+impl FunctionCall {
+    fn wrap_function<F>(
+        &self,
+        ty_checker: &mut TypeChecker<F>,
+        type_info: Option<TypeInfo>,
+    ) -> Result<Option<ExprWrapper>, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        match self.ident.to_lowercase().as_str() {
+            "coalesce" | "ifnull" => self.wrap_coalesce(ty_checker, type_info),
+            name => {
+                if ty_checker.get_function_resolver(name, &[]).is_none() {
+                    return Err(self
+                        .location()
+                        .error(SyntaxError::UnknownFunction(self.ident.clone())));
+                }
+
+                let mut arguments = vec![];
+                for parameter in self.parameters.iter() {
+                    arguments.push(parameter.try_wrap(ty_checker)?);
+                }
+
+                for (index, parameter) in self.parameters.iter().enumerate() {
+                    if arguments[index].is_some() {
+                        continue;
+                    }
+
+                    let expected =
+                        ty_checker
+                            .get_function_resolver(name, &arguments)
+                            .and_then(|resolver| {
+                                resolver.function_argument_type_info(name, &arguments, index)
+                            });
+
+                    if let Some(expected) = expected {
+                        arguments[index] = Some(parameter.wrap_with_ty(ty_checker, expected)?);
+                    }
+                }
+
+                ty_checker
+                    .get_function_resolver(name, &arguments)
+                    .unwrap()
+                    .handle_function(self, arguments)
+                    .map(Some)
+            }
+        }
+    }
+
+    fn check_argument_count(&self, valid: bool) -> Result<(), SyntaxErrorWithPos> {
+        if valid {
+            Ok(())
+        } else {
+            Err(self
+                .location()
+                .error(SyntaxError::WrongArgumentCount(self.ident.clone())))
+        }
+    }
+
+    /// `COALESCE`/`IFNULL` is nullable only if all of the arguments are nullable
+    fn wrap_coalesce<F>(
+        &self,
+        ty_checker: &mut TypeChecker<F>,
+        type_info: Option<TypeInfo>,
+    ) -> Result<Option<ExprWrapper>, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        let count = self.parameters.len();
+        self.check_argument_count(if self.ident.eq_ignore_ascii_case("ifnull") {
+            count == 2
+        } else {
+            count > 0
+        })?;
+
+        let mut wrappers = vec![];
+        for parameter in self.parameters.iter() {
+            wrappers.push(parameter.try_wrap(ty_checker)?);
+        }
+
+        let (type_info, target_nullable) = match wrappers
+            .iter()
+            .flatten()
+            .next()
+            .map(|wrapper| (wrapper.type_info.clone(), false))
+            .or_else(|| type_info.map(|type_info| (type_info.clone(), type_info.nullable)))
+        {
+            Some(result) => result,
+            None => return Ok(None),
+        };
+
+        let mut parameters = vec![];
+        let mut nullable = true;
+
+        for (index, (parameter, wrapper)) in self.parameters.iter().zip(wrappers).enumerate() {
+            let expected = TypeInfo {
+                nullable: match parameter {
+                    Expr::Literal(Literal::Null(_)) => true,
+                    Expr::Literal(Literal::External(_)) => target_nullable || index + 1 < count,
+                    _ => false,
+                },
+                ..type_info.clone()
+            };
+
+            let wrapper = match wrapper {
+                Some(wrapper) => wrapper,
+                None => parameter.wrap_with_ty(ty_checker, expected.clone())?,
+            };
+
+            let resolver = ty_checker
+                .get_resolver(&wrapper.type_info.resolver_name)
+                .ok_or_else(|| {
+                    wrapper.location().error(SyntaxError::UnknownResolverName(
+                        wrapper.type_info.resolver_name.clone(),
+                    ))
+                })?;
+
+            if !resolver.cmp_type_info(&expected, &wrapper.type_info) {
+                return Err(wrapper.location().error(SyntaxError::TypeError(
+                    expected.field_type,
+                    wrapper.type_info.field_type,
+                )));
+            }
+
+            nullable = nullable && wrapper.type_info.nullable;
+            parameters.push(resolver.unwrap_expr(wrapper)?);
+        }
+
+        Ok(Some(ExprWrapper {
+            exprs: vec![Expr::FunctionCall(FunctionCall {
+                ident: self.ident.clone(),
+                parameters,
+                location: self.location,
+            })],
+            type_info: TypeInfo {
+                nullable,
+                ..type_info
+            },
+            location: self.location,
+        }))
+    }
+}
+
+impl TypeInfer for JsonAccess {
+    fn try_wrap<F>(
+        &self,
+        ty_checker: &mut TypeChecker<F>,
+    ) -> Result<Option<ExprWrapper>, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        let item = self
+            .ident
+            .try_wrap(ty_checker)?
+            .ok_or_else(|| self.location().error(SyntaxError::TypeInferError))?;
+
+        let resolver_name = item.type_info.resolver_name.clone();
+        let resolver = ty_checker.get_resolver(&resolver_name).ok_or_else(|| {
+            self.location()
+                .error(SyntaxError::UnknownResolverName(resolver_name))
+        })?;
+
+        resolver.handle_json_access(item, self).map(Some)
+    }
+}
+
 impl TypeInfer for ColumnIdent {
     fn try_wrap<F>(
         &self,
@@ -162,12 +415,10 @@ impl TypeInfer for ColumnIdent {
             let table_alias = ident.segments.first().unwrap();
             let field_name = ident.segments.get(1).unwrap();
 
-            let entity_name = ty_checker
-                .get_table_name(self.segments.first().unwrap())
-                .ok_or_else(|| {
-                    self.location()
-                        .error(SyntaxError::UnknownAlias(table_alias.to_string()))
-                })?;
+            let entity_name = ty_checker.get_table_name(table_alias).ok_or_else(|| {
+                self.location()
+                    .error(SyntaxError::UnknownAlias(table_alias.to_string()))
+            })?;
 
             let definition = ty_checker
                 .get_field_definition(entity_name, field_name)
@@ -186,7 +437,7 @@ impl TypeInfer for ColumnIdent {
                     ))
                 })?;
 
-            let (new_status, join_clauses) = resolver.wrap_ident(self, &definition)?;
+            let (new_status, join_clauses) = resolver.wrap_ident(&ident, &definition)?;
 
             for join in join_clauses {
                 ty_checker
@@ -205,12 +456,25 @@ impl TypeInfer for ColumnIdent {
 impl TypeInfer for Literal {
     fn try_wrap<F>(
         &self,
-        _ty_checker: &mut TypeChecker<F>,
+        ty_checker: &mut TypeChecker<F>,
     ) -> Result<Option<ExprWrapper>, SyntaxErrorWithPos>
     where
         F: Fn(&str, &str) -> Option<FieldDefinition>,
     {
-        Ok(None)
+        match self {
+            Literal::Typed(typed) => {
+                let type_info =
+                    ty_checker
+                        .get_literal_type_info(&typed.prefix)
+                        .ok_or_else(|| {
+                            self.location()
+                                .error(SyntaxError::UnknownType(typed.prefix.clone()))
+                        })?;
+
+                self.wrap_with_ty(ty_checker, type_info).map(Some)
+            }
+            _ => Ok(None),
+        }
     }
 
     fn wrap_with_ty<F>(
@@ -233,7 +497,7 @@ impl TypeInfer for Literal {
 
         for (ident, ty) in assertions {
             ty_checker
-                .add_external_value_assertion(ident, ty)
+                .add_external_value_assertion(ident, ty, self.location())
                 .map_err(|e| self.location().error(e))?;
         }
 
@@ -264,28 +528,21 @@ fn test_expr_type_check() {
             .into_iter()
             .collect(),
         |entity: &str, field: &str| match (entity, field) {
-            ("test", "a") => Some(FieldDefinition {
-                entity: "test".to_string(),
-                name: "a".to_string(),
-                type_resolver_name: "numeric".to_string(),
-                field_type: "u64".to_string(),
-                nullable: false,
-                columns: vec![],
-                tables: vec![],
-                association: None,
-            }),
-            ("test", "b") => Some(FieldDefinition {
+            ("test", "a") | ("test", "b") => Some(FieldDefinition {
                 entity: "test".to_string(),
-                name: "b".to_string(),
+                name: field.to_string(),
                 type_resolver_name: "numeric".to_string(),
                 field_type: "u64".to_string(),
                 nullable: false,
-                columns: vec![],
+                length: None,
+                default: None,
+                columns: vec![field.to_string()],
                 tables: vec![],
                 association: None,
             }),
             _ => None,
         },
+        vec![],
     );
 
     assert_eq!(
@@ -298,6 +555,7 @@ fn test_expr_type_check() {
             resolver_name: "numeric".to_string(),
             field_type: "u64".to_string(),
             nullable: false,
+            length: None,
             type_kind: TypeKind::Numeric
         }
     )
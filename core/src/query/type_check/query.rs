@@ -1,11 +1,13 @@
-use crate::definitions::FieldDefinition;
+use crate::definitions::{ColumnDefault, FieldDefinition, TableDefinition};
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{
-    DeleteQuery, Expr, FromClause, GroupByClause, InsertQuery, JoinClause, Locatable,
-    OrderByClause, Query, SelectClause, SelectQuery, UpdateQuery, ValueItem,
+    ColumnIdent, DeleteQuery, Expr, FromClause, GroupByClause, InsertQuery, JoinClause, Locatable,
+    Location, OrderByClause, Query, SelectClause, SelectQuery, UpdateQuery, ValueItem,
 };
 use crate::query::type_check::{TypeCheck, TypeChecker, TypeInfer, TypeKind};
-use crate::types::TypeInfo;
+use crate::resolver::HiddenPrimaryKey;
+use crate::types::{IdentResolveStatus, TypeInfo};
+use std::collections::HashSet;
 
 impl TypeCheck for Query {
     fn check_type<F>(&mut self, ty_checker: &mut TypeChecker<F>) -> Result<(), SyntaxErrorWithPos>
@@ -65,9 +67,33 @@ impl TypeCheck for UpdateQuery {
     where
         F: Fn(&str, &str) -> Option<FieldDefinition>,
     {
-        for (_, value_item) in self.set_clause.items.iter_mut() {
+        for (ident, value_item) in self.set_clause.items.iter_mut() {
             if let ValueItem::Expr(expr) = value_item {
-                expr.check_type(ty_checker, None)?;
+                if let [alias, field] = ident.segments.as_slice() {
+                    let table = ty_checker
+                        .get_table_name(alias)
+                        .unwrap_or(alias.as_str())
+                        .to_string();
+
+                    if is_generated_field(ty_checker, &table, field) {
+                        return Err(ident.location().error(
+                            SyntaxError::GeneratedColumnAssignment(ident.segments.join(".")),
+                        ));
+                    }
+                }
+
+                match ident.try_wrap(ty_checker)? {
+                    Some(column) => {
+                        expr.check_assignment(
+                            ty_checker,
+                            column.type_info,
+                            &ident.segments.join("."),
+                        )?;
+                    }
+                    None => {
+                        expr.check_type(ty_checker, None)?;
+                    }
+                }
             }
         }
 
@@ -84,11 +110,71 @@ impl TypeCheck for InsertQuery {
     where
         F: Fn(&str, &str) -> Option<FieldDefinition>,
     {
-        for value in self.values.iter_mut() {
-            value.check_type(ty_checker, None)?;
+        let location = self.location();
+        let table = self.table.clone();
+        let definition = ty_checker
+            .get_table_definition(&table)
+            .cloned()
+            .ok_or_else(|| location.error(SyntaxError::UnknownEntity(table.clone())))?;
+        let columns = self.columns.clone().unwrap_or_default();
+
+        for (index, value) in self.values.iter_mut().enumerate() {
+            let expr = match value {
+                ValueItem::Expr(expr) => expr,
+                ValueItem::Default => {
+                    if let Some(column) = columns.get(index) {
+                        if let Some(name) =
+                            non_omissible_column(ty_checker, &definition, &table, column)
+                        {
+                            return Err(location.error(SyntaxError::NonOmissibleColumn(name)));
+                        }
+                    }
+
+                    continue;
+                }
+            };
+
+            if let Some(column) = columns.get(index) {
+                if is_generated_field(ty_checker, &table, column) {
+                    return Err(expr
+                        .location()
+                        .error(SyntaxError::GeneratedColumnAssignment(column.clone())));
+                }
+            }
+
+            match columns
+                .get(index)
+                .and_then(|column| column_type_info(ty_checker, &table, column))
+            {
+                Some(type_info) => {
+                    expr.check_assignment(ty_checker, type_info, &columns[index])?;
+                }
+                None => {
+                    expr.check_type(ty_checker, None)?;
+                }
+            }
         }
 
-        Ok(())
+        // values without column list are assigned by position, which can not be mapped to fields
+        if self.columns.is_none() && !self.values.is_empty() {
+            return Ok(());
+        }
+
+        let assigned: HashSet<_> = columns
+            .iter()
+            .filter_map(|column| ty_checker.get_field_definition(&table, column))
+            .flat_map(|field| field.columns.into_iter())
+            .collect();
+        let hidden_primary_key =
+            HiddenPrimaryKey::from_definitions(&table, ty_checker.table_definitions())
+                .map(|primary_key| primary_key.column_name().to_string());
+
+        match definition.required_columns().into_iter().find(|column| {
+            !assigned.contains(column) && hidden_primary_key.as_ref() != Some(column)
+        }) {
+            Some(column) => Err(location.error(SyntaxError::MissingRequiredColumn(column))),
+            None => Ok(()),
+        }
     }
 }
 
@@ -108,7 +194,7 @@ impl TypeCheck for SelectClause {
             };
 
             ty_checker
-                .add_result_ty(alias.as_ref().cloned().unwrap(), type_info.field_type)
+                .add_result_ty(alias.as_ref().cloned().unwrap(), type_info.to_string())
                 .map_err(|e| location.error(e))?;
         }
 
@@ -160,7 +246,7 @@ impl TypeCheck for OrderByClause {
 }
 
 impl Expr {
-    fn check_type<F>(
+    pub(crate) fn check_type<F>(
         &mut self,
         ty_checker: &mut TypeChecker<F>,
         type_kind: Option<TypeKind>,
@@ -195,3 +281,125 @@ impl Expr {
         Ok(type_info)
     }
 }
+
+impl Expr {
+    /// Check expression assigned to field of entity outside of query, e.g. default value of column
+    pub(crate) fn check_field_assignment<F>(
+        &mut self,
+        ty_checker: &mut TypeChecker<F>,
+        entity: &str,
+        field: &str,
+    ) -> Result<TypeInfo, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        let column_type = column_type_info(ty_checker, entity, field).ok_or_else(|| {
+            self.location().error(SyntaxError::UnknownField(
+                entity.to_string(),
+                field.to_string(),
+            ))
+        })?;
+
+        self.check_assignment(ty_checker, column_type, field)
+    }
+
+    /// Check the type of value assigned to a column, nullable value cannot be assigned to
+    /// non-null column
+    fn check_assignment<F>(
+        &mut self,
+        ty_checker: &mut TypeChecker<F>,
+        column_type: TypeInfo,
+        column: &str,
+    ) -> Result<TypeInfo, SyntaxErrorWithPos>
+    where
+        F: Fn(&str, &str) -> Option<FieldDefinition>,
+    {
+        let wrapper = match self.try_wrap(ty_checker)? {
+            Some(wrapper) => wrapper,
+            None => self.wrap_with_ty(ty_checker, column_type.clone())?,
+        };
+
+        let column_resolver = ty_checker
+            .get_resolver(&column_type.resolver_name)
+            .ok_or_else(|| {
+                wrapper.location().error(SyntaxError::UnknownResolverName(
+                    column_type.resolver_name.clone(),
+                ))
+            })?;
+
+        if !column_resolver.is_assignable(&column_type, &wrapper.type_info) {
+            return Err(wrapper.location().error(SyntaxError::TypeError(
+                column_type.to_string(),
+                wrapper.type_info.to_string(),
+            )));
+        }
+
+        if wrapper.type_info.nullable && !column_type.nullable {
+            return Err(wrapper.location().error(SyntaxError::NullableAssignment(
+                column.to_string(),
+                wrapper.type_info.to_string(),
+            )));
+        }
+
+        let type_info = wrapper.type_info.clone();
+        *self = ty_checker
+            .get_resolver(&wrapper.type_info.resolver_name)
+            .ok_or_else(|| {
+                wrapper.location().error(SyntaxError::UnknownResolverName(
+                    wrapper.type_info.resolver_name.clone(),
+                ))
+            })?
+            .unwrap_expr(wrapper)?;
+
+        Ok(type_info)
+    }
+}
+
+fn column_type_info<F>(ty_checker: &TypeChecker<F>, entity: &str, field: &str) -> Option<TypeInfo>
+where
+    F: Fn(&str, &str) -> Option<FieldDefinition>,
+{
+    let definition = ty_checker.get_field_definition(entity, field)?;
+    let resolver = ty_checker.get_resolver(&definition.type_resolver_name)?;
+    let ident = ColumnIdent {
+        segments: vec![entity.to_string(), field.to_string()],
+        location: Location::pos(0),
+    };
+
+    match resolver.wrap_ident(&ident, &definition).ok()?.0 {
+        IdentResolveStatus::Resolved(wrapper) => Some(wrapper.type_info),
+        IdentResolveStatus::Unresolved(_) => None,
+    }
+}
+
+/// Find the column of field which can be neither omitted nor assigned by `DEFAULT`
+fn non_omissible_column<F>(
+    ty_checker: &TypeChecker<F>,
+    definition: &TableDefinition,
+    entity: &str,
+    field: &str,
+) -> Option<String>
+where
+    F: Fn(&str, &str) -> Option<FieldDefinition>,
+{
+    let field = ty_checker.get_field_definition(entity, field)?;
+
+    definition
+        .columns
+        .iter()
+        .filter(|column| field.columns.contains(&column.name))
+        .find(|column| !column.omissible())
+        .map(|column| column.name.clone())
+}
+
+fn is_generated_field<F>(ty_checker: &TypeChecker<F>, entity: &str, field: &str) -> bool
+where
+    F: Fn(&str, &str) -> Option<FieldDefinition>,
+{
+    matches!(
+        ty_checker
+            .get_field_definition(entity, field)
+            .and_then(|definition| definition.default),
+        Some(ColumnDefault::Generated(_))
+    )
+}
@@ -1,6 +1,6 @@
-use crate::definitions::FieldDefinition;
+use crate::definitions::{FieldDefinition, TableDefinition, TableType};
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
-use crate::query::ast::{JoinClause, Locatable};
+use crate::query::ast::{JoinClause, Locatable, Location};
 use crate::types::{ExprWrapper, TypeInfo, TypeResolver};
 use std::collections::HashMap;
 
@@ -9,11 +9,13 @@ where
     F: Fn(&str, &str) -> Option<FieldDefinition>,
 {
     external_value_assertion: HashMap<String, String>,
+    external_value_location: Vec<(Location, String)>,
     result_type: HashMap<String, String>,
     generated_join: HashMap<String, JoinClause>,
     resolvers: HashMap<String, Box<dyn TypeResolver>>,
     alias: HashMap<String, String>,
     definition_getter: F,
+    table_definitions: Vec<TableDefinition>,
 }
 
 #[allow(clippy::map_entry)]
@@ -25,9 +27,11 @@ where
         resolvers: Vec<Box<dyn TypeResolver>>,
         alias: HashMap<String, String>,
         definition_getter: F,
+        table_definitions: Vec<TableDefinition>,
     ) -> Self {
         TypeChecker {
             external_value_assertion: Default::default(),
+            external_value_location: vec![],
             result_type: Default::default(),
             generated_join: Default::default(),
             resolvers: resolvers
@@ -36,32 +40,50 @@ where
                 .collect(),
             alias,
             definition_getter,
+            table_definitions,
         }
     }
 
+    /// External value can be used several times, but always in the same type
     pub fn add_external_value_assertion(
         &mut self,
         ident: String,
         ty: String,
+        location: Location,
     ) -> Result<(), SyntaxError> {
-        if self.external_value_assertion.contains_key(&ident) {
-            Err(SyntaxError::ConflictValueAssertion(ident))
-        } else {
-            self.external_value_assertion.insert(ident, ty);
-            Ok(())
+        match self.external_value_assertion.get(&ident) {
+            Some(asserted) if asserted != &ty => Err(SyntaxError::ConflictValueAssertion(ident)),
+            _ => {
+                self.external_value_location.push((location, ident.clone()));
+                self.external_value_assertion.insert(ident, ty);
+                Ok(())
+            }
         }
     }
 
+    /// Add join clause generated by type resolver, aliased table is registered so that it can be
+    /// referenced by following idents, joining the same aliased table again is ignored
     pub fn add_join_clause(&mut self, join: JoinClause) -> Result<(), SyntaxError> {
-        let alias = match &join {
-            JoinClause::JoinOn(join_on) => join_on.table.name.clone(),
-            JoinClause::NaturalJoin(natural_join) => natural_join.table.name.clone(),
-            JoinClause::CrossJoin(cross_join) => cross_join.table.name.clone(),
+        let table = match &join {
+            JoinClause::JoinOn(join_on) => &join_on.table,
+            JoinClause::NaturalJoin(natural_join) => &natural_join.table,
+            JoinClause::CrossJoin(cross_join) => &cross_join.table,
         };
+        let name = table.name.clone();
+        let alias = table.alias.clone().unwrap_or_else(|| name.clone());
 
         if self.generated_join.contains_key(&alias) {
+            if self.alias.get(&alias) == Some(&name) {
+                Ok(())
+            } else {
+                Err(SyntaxError::ConflictAlias(alias))
+            }
+        } else if table.alias.is_some() && self.alias.contains_key(&alias) {
             Err(SyntaxError::ConflictAlias(alias))
         } else {
+            if table.alias.is_some() {
+                self.alias.insert(alias.clone(), name);
+            }
             self.generated_join.insert(alias, join);
 
             Ok(())
@@ -82,6 +104,37 @@ where
         self.resolvers.get(name).map(|boxed| boxed.as_ref())
     }
 
+    pub fn get_cast_type_info(&self, ty: &str) -> Option<TypeInfo> {
+        self.resolvers
+            .values()
+            .find_map(|resolver| resolver.cast_type_info(ty))
+    }
+
+    pub fn get_literal_type_info(&self, prefix: &str) -> Option<TypeInfo> {
+        self.resolvers
+            .values()
+            .find_map(|resolver| resolver.literal_type_info(prefix))
+    }
+
+    /// Get resolver of function, resolvers of arguments will be preferred
+    pub fn get_function_resolver(
+        &self,
+        name: &str,
+        arguments: &[Option<ExprWrapper>],
+    ) -> Option<&dyn TypeResolver> {
+        arguments
+            .iter()
+            .flatten()
+            .filter_map(|argument| self.get_resolver(&argument.type_info.resolver_name))
+            .find(|resolver| resolver.has_function(name))
+            .or_else(|| {
+                self.resolvers
+                    .values()
+                    .find(|resolver| resolver.has_function(name))
+                    .map(|boxed| boxed.as_ref())
+            })
+    }
+
     pub fn get_table_name(&self, alias: &str) -> Option<&str> {
         self.alias.get(alias).map(|string| string.as_str())
     }
@@ -89,6 +142,33 @@ where
     pub fn get_field_definition(&self, entity: &str, field: &str) -> Option<FieldDefinition> {
         (self.definition_getter)(entity, field)
     }
+
+    /// Get definition of the table generated by entity
+    pub fn get_table_definition(&self, entity: &str) -> Option<&TableDefinition> {
+        self.table_definitions.iter().find(
+            |definition| matches!(&definition.ty, TableType::NormalEntityTable(name) if name == entity),
+        )
+    }
+
+    pub fn table_definitions(&self) -> &[TableDefinition] {
+        &self.table_definitions
+    }
+
+    pub fn external_value_assertion(&self) -> &HashMap<String, String> {
+        &self.external_value_assertion
+    }
+
+    /// Idents of external values, ordered by their position in query
+    pub fn external_values(&self) -> Vec<String> {
+        let mut locations = self.external_value_location.clone();
+        locations.sort_by_key(|(location, _)| location.start());
+
+        locations.into_iter().map(|(_, ident)| ident).collect()
+    }
+
+    pub fn result_type(&self) -> &HashMap<String, String> {
+        &self.result_type
+    }
 }
 
 pub trait TypeInfer: Locatable {
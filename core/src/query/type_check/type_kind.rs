@@ -8,6 +8,10 @@ pub enum TypeKind {
     Numeric,
     String,
     Boolean,
+    DateTime,
+    Binary,
+    Json,
+    Enum,
     Object(String),
     Others(String),
 }
@@ -21,6 +25,10 @@ impl Display for TypeKind {
                 TypeKind::Numeric => "Numeric",
                 TypeKind::String => "String",
                 TypeKind::Boolean => "Boolean",
+                TypeKind::DateTime => "DateTime",
+                TypeKind::Binary => "Binary",
+                TypeKind::Json => "Json",
+                TypeKind::Enum => "Enum",
                 TypeKind::Object(name) => return write!(f, "Object({})", name),
                 TypeKind::Others(s) => s,
             }
@@ -36,6 +44,7 @@ impl From<&Literal> for TypeKind {
             Literal::String(_) => TypeKind::String,
             Literal::External(e) => TypeKind::Others(format!("External Value: {}", e.ident)),
             Literal::Null(_) => TypeKind::Others("Null".to_string()),
+            Literal::Typed(typed) => TypeKind::Others(format!("{} literal", typed.prefix)),
         }
     }
 }
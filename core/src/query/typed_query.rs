@@ -0,0 +1,44 @@
+use crate::query::ast::error::SyntaxErrorWithPos;
+use crate::query::ast::Query;
+use crate::query::parse_query;
+
+/// Query generated by `query!` macro.
+/// The query string has been parsed and type checked at compile time, `P` is the generated struct
+/// holding a typed field for every external value in the query.
+pub struct TypedQuery<P> {
+    query: &'static str,
+    result_types: Vec<(&'static str, &'static str)>,
+    params: P,
+}
+
+impl<P> TypedQuery<P> {
+    pub fn new(
+        query: &'static str,
+        result_types: Vec<(&'static str, &'static str)>,
+        params: P,
+    ) -> Self {
+        TypedQuery {
+            query,
+            result_types,
+            params,
+        }
+    }
+
+    pub fn query_string(&self) -> &'static str {
+        self.query
+    }
+
+    /// List of (result alias, result type), types are rendered by type checker for display and
+    /// are not checked by compiler
+    pub fn result_types(&self) -> &[(&'static str, &'static str)] {
+        &self.result_types
+    }
+
+    pub fn params(&self) -> &P {
+        &self.params
+    }
+
+    pub fn parse(&self) -> Result<Query, SyntaxErrorWithPos> {
+        parse_query(self.query)
+    }
+}
@@ -1,5 +1,10 @@
+use crate::annotations::ReferentialAction;
+use crate::definitions::{TableDefinition, TableType};
+use crate::error::TransactionError;
+use crate::query::eval_check;
 use crate::resolver::error::DataConvertError;
-use crate::types::{DatabaseValue, ValuePack};
+use crate::resolver::{generate_uuid, HiddenPrimaryKey};
+use crate::types::{DatabaseType, DatabaseValue, ValuePack};
 use crate::{Entity, EntityProxy, EntityUniqueID};
 use rand::random;
 use std::cell::RefCell;
@@ -11,21 +16,38 @@ where
     E: Entity + Clone,
 {
     pool: RefCell<HashMap<EntityUniqueID, E>>,
+    /// Rows of membership tables referencing entities of this repository, mapped by table name
+    memberships: RefCell<HashMap<String, Vec<ValuePack>>>,
 }
 
 impl<E: Entity + Clone> Repository<E> {
     fn insert_entity(&self, entity: E) -> EntityUniqueID {
-        let mut pool = self.pool.borrow_mut();
         let id = self.generate_unique_id(&entity);
 
-        pool.insert(id, entity);
+        self.pool.borrow_mut().insert(id, entity);
 
         id
     }
 
-    fn generate_unique_id(&self, _entity: &E) -> EntityUniqueID {
-        // todo: generate_by_primary_key
+    /// Entity with the same primary key as a pooled one shares its id, others get a random one
+    fn generate_unique_id(&self, entity: &E) -> EntityUniqueID {
         let pool = self.pool.borrow();
+        let pooled_id = entity
+            .primary_key_values()
+            .ok()
+            .filter(|values| !values.is_empty())
+            .and_then(|primary_key_values| {
+                pool.iter()
+                    .find_map(|(id, pooled)| match pooled.primary_key_values() {
+                        Ok(values) if values == primary_key_values => Some(*id),
+                        _ => None,
+                    })
+            });
+
+        if let Some(id) = pooled_id {
+            return id;
+        }
+
         loop {
             let id = random();
 
@@ -46,20 +68,390 @@ impl<E: Entity + Clone> Repository<E> {
         pool.remove(id)
     }
 
-    pub fn commit<'t, P: EntityProxy<'t, E>>(&mut self, entity_proxy: P) {
+    pub fn commit<'t, P: EntityProxy<'t, E>>(
+        &mut self,
+        entity_proxy: P,
+    ) -> Result<(), TransactionError> {
         let id = entity_proxy.unique_id();
 
         let entity = entity_proxy.inner();
         // todo: compare_value
 
-        let _id = id.unwrap_or_else(|| self.generate_unique_id(&entity));
+        let mut values = entity.to_database_values()?;
+        // Values of row loaded from database, compared with values to be committed
+        let row = match id {
+            Some(id) => self
+                .get_entity(&id)
+                .map(|entity| entity.to_database_values())
+                .transpose()?,
+            None => None,
+        };
+
+        if id.is_none() {
+            Self::fill_hidden_primary_key(&mut values);
+        }
+        Self::fill_timestamps(id.is_none(), row.as_ref(), &mut values);
+        Self::check_constraints(&values)?;
 
-        let _value = entity.to_database_values();
+        let id = match id {
+            Some(id) => {
+                Self::lock_version(row.as_ref(), &mut values)?;
+                id
+            }
+            None => self.generate_unique_id(&E::from_database_value(&values)?),
+        };
         // todo: commit to db
+
+        let mut pool = self.pool.borrow_mut();
+        pool.insert(id, E::from_database_value(&values)?);
+
+        Ok(())
+    }
+
+    fn entity_table() -> Option<TableDefinition> {
+        E::get_definitions()
+            .into_iter()
+            .find(|definition| matches!(definition.ty, TableType::NormalEntityTable(_)))
+    }
+
+    /// Generate uuid of hidden primary key for entity to be inserted
+    fn fill_hidden_primary_key(values: &mut ValuePack) {
+        let primary_key = Self::entity_table().and_then(|table| match &table.ty {
+            TableType::NormalEntityTable(entity_name) => {
+                HiddenPrimaryKey::from_definitions(entity_name, std::slice::from_ref(&table))
+            }
+            _ => None,
+        });
+
+        if let Some(primary_key) = primary_key {
+            values.insert(
+                primary_key.column_name().to_string(),
+                DatabaseValue::String(generate_uuid()),
+            );
+        }
+    }
+
+    /// Fill timestamp columns by current time. Both are filled on insert, and `updated_at` is
+    /// refreshed on update if values of other columns differ from the loaded row
+    fn fill_timestamps(inserting: bool, row: Option<&ValuePack>, values: &mut ValuePack) {
+        let table = match Self::entity_table() {
+            Some(table) => table,
+            None => return,
+        };
+
+        let changed = match row {
+            Some(row) => values.iter().any(|(name, value)| {
+                Some(name) != table.updated_at.as_ref()
+                    && Some(name) != table.version.as_ref()
+                    && !row.get(name).map_or(false, |old| old.same_value(value))
+            }),
+            None => true,
+        };
+
+        let columns = if inserting {
+            vec![&table.created_at, &table.updated_at]
+        } else if changed {
+            vec![&table.updated_at]
+        } else {
+            vec![]
+        };
+
+        for column in columns.into_iter().flatten() {
+            if let Some(now) = values
+                .get(column)
+                .and_then(|value| DatabaseValue::now(DatabaseType::from(value)))
+            {
+                values.insert(column.clone(), now);
+            }
+        }
     }
 
-    pub fn find(&self, _primary_key_values: &ValuePack) -> Option<E> {
-        unimplemented!()
+    /// Optimistic locking of entity with version column. Update is only applied on the row whose
+    /// version is still the loaded one, and version in `values` is increased.
+    /// The check is made against the row in pool only, `version = old` has to be added to the
+    /// update statement once entities are committed to database
+    fn lock_version(
+        row: Option<&ValuePack>,
+        values: &mut ValuePack,
+    ) -> Result<(), TransactionError> {
+        let table = match Self::entity_table() {
+            Some(table) => table,
+            None => return Ok(()),
+        };
+
+        let column = match &table.version {
+            Some(column) => column,
+            None => return Ok(()),
+        };
+
+        // WHERE of update: primary keys and `version = old`
+        let conditions: ValuePack = table
+            .columns
+            .iter()
+            .filter(|definition| definition.primary_key || &definition.name == column)
+            .filter_map(|definition| {
+                values
+                    .get(&definition.name)
+                    .map(|value| (definition.name.clone(), value.clone()))
+            })
+            .collect();
+
+        let affected = match row {
+            Some(row) => conditions
+                .iter()
+                .all(|(name, value)| row.get(name) == Some(value)),
+            None => false,
+        };
+
+        if !affected {
+            return Err(TransactionError::StaleEntity(table.name));
+        }
+
+        if let Some(next) = values.get(column).and_then(DatabaseValue::next_version) {
+            values.insert(column.clone(), next);
+        }
+
+        Ok(())
+    }
+
+    /// Check constraints of entity table which can be decided without database, others are left to
+    /// database
+    fn check_constraints(values: &ValuePack) -> Result<(), TransactionError> {
+        for definition in E::get_definitions() {
+            if !matches!(definition.ty, TableType::NormalEntityTable(_)) {
+                continue;
+            }
+
+            for check in definition.checks.iter() {
+                if let Ok(Some(false)) = eval_check(&check.expr, values) {
+                    return Err(TransactionError::CheckViolation(
+                        definition.name.clone(),
+                        check.name.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn find(&self, primary_key_values: &ValuePack) -> Option<E> {
+        self.find_all(primary_key_values).into_iter().next()
+    }
+
+    /// Find entities whose columns are equal to `column_values`, null is equal to nothing as it is
+    /// in database
+    pub fn find_all(&self, column_values: &ValuePack) -> Vec<E> {
+        let pool = self.pool.borrow();
+
+        pool.values()
+            .filter(|entity| match entity.to_database_values() {
+                Ok(values) => Self::match_values(&values, column_values),
+                Err(_) => false,
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn match_values(values: &ValuePack, conditions: &ValuePack) -> bool {
+        conditions
+            .iter()
+            .all(|(column, expected)| match (values.get(column), expected) {
+                (None, _) | (Some(DatabaseValue::Null(_)), _) | (_, DatabaseValue::Null(_)) => {
+                    false
+                }
+                (Some(value), expected) => value == expected,
+            })
+    }
+
+    /// Find descendants of tree structured entity recursively, `column_map` is list of
+    /// (parent_column, referenced_column)
+    pub fn find_descendants(
+        &self,
+        column_map: &[(String, String)],
+        root_values: &ValuePack,
+    ) -> Vec<E> {
+        let mut result = vec![];
+        // Primary keys of found entities, entities in a cycle are only visited once
+        let mut visited: Vec<ValuePack> = vec![];
+        let mut queue = vec![root_values.clone()];
+
+        while let Some(values) = queue.pop() {
+            let conditions: ValuePack = column_map
+                .iter()
+                .filter_map(|(parent, referenced)| {
+                    values
+                        .get(referenced)
+                        .map(|value| (parent.clone(), value.clone()))
+                })
+                .collect();
+
+            if conditions.is_empty() || conditions.len() != column_map.len() {
+                continue;
+            }
+
+            for child in self.find_all(&conditions) {
+                let (primary_key, child_values) =
+                    match (child.primary_key_values(), child.to_database_values()) {
+                        (Ok(primary_key), Ok(child_values)) => (primary_key, child_values),
+                        _ => continue,
+                    };
+
+                if visited.contains(&primary_key) {
+                    continue;
+                }
+
+                visited.push(primary_key);
+                queue.push(child_values);
+                result.push(child);
+            }
+        }
+
+        result
+    }
+
+    /// Find entities associated by membership table, `column_map` is list of
+    /// (membership_column, referenced_column)
+    pub fn find_by_membership(
+        &self,
+        membership_table: &str,
+        conditions: &ValuePack,
+        column_map: &[(String, String)],
+    ) -> Vec<E> {
+        let memberships = self.memberships.borrow();
+        let rows = match memberships.get(membership_table) {
+            Some(rows) => rows,
+            None => return vec![],
+        };
+
+        rows.iter()
+            .filter(|row| Self::match_values(row, conditions))
+            .flat_map(|row| {
+                let referenced_values: ValuePack = column_map
+                    .iter()
+                    .filter_map(|(column, referenced)| {
+                        row.get(column)
+                            .map(|value| (referenced.clone(), value.clone()))
+                    })
+                    .collect();
+
+                self.find_all(&referenced_values)
+            })
+            .collect()
+    }
+
+    /// Add a row of membership table referencing entity of this repository
+    pub fn add_membership(&self, membership_table: &str, row: ValuePack) {
+        let mut memberships = self.memberships.borrow_mut();
+        let rows = memberships.entry(membership_table.to_string()).or_default();
+
+        if !rows.contains(&row) {
+            rows.push(row);
+        }
+    }
+
+    /// Remove rows of membership table matching `conditions`
+    pub fn remove_membership(&self, membership_table: &str, conditions: &ValuePack) {
+        let mut memberships = self.memberships.borrow_mut();
+
+        if let Some(rows) = memberships.get_mut(membership_table) {
+            rows.retain(|row| !Self::match_values(row, conditions));
+        }
+    }
+}
+
+/// Change of pooled entity caused by referential action
+pub(crate) enum ReferentialChange {
+    /// Cascaded deletion, unique id, table name and values of deleted entity
+    Drop(EntityUniqueID, String, ValuePack),
+    /// Associated columns are set to null, unique id and new values of entity
+    Replace(EntityUniqueID, ValuePack),
+}
+
+impl<E: Entity + Clone> Repository<E> {
+    /// Changes of pooled entities referencing the deleted row of `table`
+    pub(crate) fn plan_delete(
+        &self,
+        table: &str,
+        values: &ValuePack,
+    ) -> Result<Vec<ReferentialChange>, TransactionError> {
+        let definitions = E::get_definitions();
+        let entity_table = match definitions
+            .iter()
+            .find(|definition| matches!(definition.ty, TableType::NormalEntityTable(_)))
+        {
+            Some(entity_table) => entity_table,
+            None => return Ok(vec![]),
+        };
+
+        let pool = self.pool.borrow();
+        let mut changes = vec![];
+
+        for foreign_key in entity_table
+            .foreign_keys
+            .iter()
+            .filter(|foreign_key| foreign_key.referenced_table == table)
+        {
+            for (id, entity) in pool.iter() {
+                let mut entity_values = entity.to_database_values()?;
+                let referencing =
+                    foreign_key.column_map.iter().all(|(column, referenced)| {
+                        match (entity_values.get(column), values.get(referenced)) {
+                            (Some(DatabaseValue::Null(_)), _) | (None, _) | (_, None) => false,
+                            (Some(value), Some(referenced_value)) => value == referenced_value,
+                        }
+                    });
+
+                if !referencing {
+                    continue;
+                }
+
+                match foreign_key.on_delete {
+                    ReferentialAction::NoAction | ReferentialAction::Restrict => {
+                        return Err(TransactionError::RestrictedByForeignKey(
+                            entity_table.name.clone(),
+                            foreign_key.name.clone(),
+                        ))
+                    }
+                    ReferentialAction::Cascade => changes.push(ReferentialChange::Drop(
+                        *id,
+                        entity_table.name.clone(),
+                        entity_values,
+                    )),
+                    ReferentialAction::SetNull => {
+                        for (column, _) in foreign_key.column_map.iter() {
+                            if let Some(value) = entity_values.get_mut(column) {
+                                *value = DatabaseValue::Null(DatabaseType::from(&*value));
+                            }
+                        }
+
+                        changes.push(ReferentialChange::Replace(*id, entity_values))
+                    }
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+
+    pub(crate) fn apply_changes(
+        &self,
+        changes: Vec<ReferentialChange>,
+    ) -> Result<(), TransactionError> {
+        let mut pool = self.pool.borrow_mut();
+
+        for change in changes {
+            match change {
+                ReferentialChange::Drop(id, _, _) => {
+                    pool.remove(&id);
+                }
+                ReferentialChange::Replace(id, values) => {
+                    pool.insert(id, E::from_database_value(&values)?);
+                }
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -92,6 +484,370 @@ where
     fn create() -> Repository<E> where {
         Repository {
             pool: RefCell::new(Default::default()),
+            memberships: RefCell::new(Default::default()),
         }
     }
 }
+
+#[cfg(test)]
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    id: u32,
+    parent: Option<u32>,
+}
+
+#[cfg(test)]
+impl Entity for Node {
+    fn from_database_value(result: &ValuePack) -> Result<Self, DataConvertError> {
+        let id = match result.get("id") {
+            Some(DatabaseValue::UnsignedInteger(id)) => *id,
+            _ => {
+                return Err(DataConvertError::UnexpectedDatabaseValueType(
+                    "Node".to_string(),
+                    "id".to_string(),
+                ))
+            }
+        };
+        let parent = match result.get("parent_id") {
+            Some(DatabaseValue::UnsignedInteger(parent)) => Some(*parent),
+            _ => None,
+        };
+
+        Ok(Node { id, parent })
+    }
+
+    fn to_database_values(&self) -> Result<ValuePack, DataConvertError> {
+        Ok(vec![
+            ("id".to_string(), DatabaseValue::UnsignedInteger(self.id)),
+            (
+                "parent_id".to_string(),
+                match self.parent {
+                    Some(parent) => DatabaseValue::UnsignedInteger(parent),
+                    None => DatabaseValue::Null(DatabaseType::UnsignedInteger),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect())
+    }
+
+    fn get_definitions() -> Vec<TableDefinition> {
+        vec![]
+    }
+
+    fn get_field_definition(_field_name: &str) -> Option<crate::definitions::FieldDefinition> {
+        None
+    }
+
+    fn primary_key_values(&self) -> Result<ValuePack, DataConvertError> {
+        Ok(
+            vec![("id".to_string(), DatabaseValue::UnsignedInteger(self.id))]
+                .into_iter()
+                .collect(),
+        )
+    }
+}
+
+/// Entity without `ID` field, identified by the hidden primary key `__label_id` and locked by
+/// `version`
+#[cfg(test)]
+#[derive(Clone, Debug, PartialEq)]
+struct Label {
+    __label_id: String,
+    name: String,
+    version: u32,
+}
+
+#[cfg(test)]
+impl Entity for Label {
+    fn from_database_value(result: &ValuePack) -> Result<Self, DataConvertError> {
+        match (
+            result.get("__label_id"),
+            result.get("name"),
+            result.get("version"),
+        ) {
+            (
+                Some(DatabaseValue::String(id)),
+                Some(DatabaseValue::String(name)),
+                Some(DatabaseValue::UnsignedInteger(version)),
+            ) => Ok(Label {
+                __label_id: id.clone(),
+                name: name.clone(),
+                version: *version,
+            }),
+            _ => Err(DataConvertError::UnexpectedDatabaseValueType(
+                "Label".to_string(),
+                "name".to_string(),
+            )),
+        }
+    }
+
+    fn to_database_values(&self) -> Result<ValuePack, DataConvertError> {
+        let mut values = self.primary_key_values()?;
+        values.insert("name".to_string(), DatabaseValue::String(self.name.clone()));
+        values.insert(
+            "version".to_string(),
+            DatabaseValue::UnsignedInteger(self.version),
+        );
+
+        Ok(values)
+    }
+
+    fn get_definitions() -> Vec<TableDefinition> {
+        use crate::definitions::{ColumnDefinition, ColumnType};
+
+        let column = |name: &str, ty: ColumnType, data_type: DatabaseType| ColumnDefinition {
+            name: name.to_string(),
+            primary_key: matches!(ty, ColumnType::VisualColumn),
+            unique: matches!(ty, ColumnType::VisualColumn),
+            ty,
+            data_type,
+            auto_increase: false,
+            nullable: false,
+            default: None,
+            charset: None,
+            collation: None,
+            enum_variants: vec![],
+        };
+
+        let field = ColumnType::NormalColumn("Label".to_string());
+
+        vec![TableDefinition {
+            name: "label".to_string(),
+            ty: TableType::NormalEntityTable("Label".to_string()),
+            columns: vec![
+                column(
+                    "__label_id",
+                    ColumnType::VisualColumn,
+                    DatabaseType::String(255),
+                ),
+                column("name", field.clone(), DatabaseType::String(255)),
+                column("version", field, DatabaseType::UnsignedInteger),
+            ],
+            indexes: vec![],
+            foreign_keys: vec![],
+            checks: vec![],
+            version: Some("version".to_string()),
+            created_at: None,
+            updated_at: None,
+        }]
+    }
+
+    fn get_field_definition(_field_name: &str) -> Option<crate::definitions::FieldDefinition> {
+        None
+    }
+
+    fn primary_key_values(&self) -> Result<ValuePack, DataConvertError> {
+        Ok(vec![(
+            "__label_id".to_string(),
+            DatabaseValue::String(self.__label_id.clone()),
+        )]
+        .into_iter()
+        .collect())
+    }
+}
+
+#[cfg(test)]
+fn node_repository(nodes: Vec<(u32, Option<u32>)>) -> Repository<Node> {
+    let repository = Repository::create();
+
+    for (id, parent) in nodes {
+        repository.insert_entity(Node { id, parent });
+    }
+
+    repository
+}
+
+#[test]
+fn test_find_all() {
+    let repository = node_repository(vec![(1, None), (2, Some(1)), (3, Some(1)), (4, Some(2))]);
+    let value = |column: &str, id: u32| {
+        vec![(column.to_string(), DatabaseValue::UnsignedInteger(id))]
+            .into_iter()
+            .collect::<ValuePack>()
+    };
+
+    let mut children = repository.find_all(&value("parent_id", 1));
+    children.sort_by_key(|node| node.id);
+
+    assert_eq!(
+        children,
+        vec![
+            Node {
+                id: 2,
+                parent: Some(1)
+            },
+            Node {
+                id: 3,
+                parent: Some(1)
+            }
+        ]
+    );
+    assert!(repository.find_all(&value("parent_id", 4)).is_empty());
+    assert_eq!(
+        repository.find(&value("id", 4)),
+        Some(Node {
+            id: 4,
+            parent: Some(2)
+        })
+    );
+
+    let roots = vec![(
+        "parent_id".to_string(),
+        DatabaseValue::Null(DatabaseType::UnsignedInteger),
+    )]
+    .into_iter()
+    .collect();
+    assert!(repository.find_all(&roots).is_empty());
+}
+
+#[test]
+fn test_find_by_membership() {
+    let repository = node_repository(vec![(1, None), (2, Some(1)), (3, Some(1))]);
+    let row = |owner: u32, node: u32| {
+        vec![
+            (
+                "owner_id".to_string(),
+                DatabaseValue::UnsignedInteger(owner),
+            ),
+            ("node_id".to_string(), DatabaseValue::UnsignedInteger(node)),
+        ]
+        .into_iter()
+        .collect::<ValuePack>()
+    };
+    let owner = |owner: u32| {
+        vec![(
+            "owner_id".to_string(),
+            DatabaseValue::UnsignedInteger(owner),
+        )]
+        .into_iter()
+        .collect::<ValuePack>()
+    };
+    let column_map = vec![("node_id".to_string(), "id".to_string())];
+    let find = |owner_id: u32| {
+        let mut ids: Vec<_> = repository
+            .find_by_membership("owner_node", &owner(owner_id), &column_map)
+            .into_iter()
+            .map(|node| node.id)
+            .collect();
+        ids.sort_unstable();
+
+        ids
+    };
+
+    assert!(find(10).is_empty());
+
+    repository.add_membership("owner_node", row(10, 1));
+    repository.add_membership("owner_node", row(10, 3));
+    repository.add_membership("owner_node", row(10, 3));
+    repository.add_membership("owner_node", row(11, 2));
+
+    assert_eq!(find(10), vec![1, 3]);
+    assert_eq!(find(11), vec![2]);
+
+    repository.remove_membership("owner_node", &row(10, 1));
+
+    assert_eq!(find(10), vec![3]);
+    assert!(repository
+        .find_by_membership("other_table", &owner(11), &column_map)
+        .is_empty());
+}
+
+#[test]
+fn test_find_descendants() {
+    let repository = node_repository(vec![
+        (1, None),
+        (2, Some(1)),
+        (3, Some(1)),
+        (4, Some(2)),
+        (5, Some(4)),
+        (6, None),
+        (7, Some(8)),
+        (8, Some(7)),
+    ]);
+    let column_map = vec![("parent_id".to_string(), "id".to_string())];
+    let descendants = |id: u32| {
+        let root = Node { id, parent: None }.to_database_values().unwrap();
+        let mut ids: Vec<_> = repository
+            .find_descendants(&column_map, &root)
+            .into_iter()
+            .map(|node| node.id)
+            .collect();
+        ids.sort_unstable();
+
+        ids
+    };
+
+    assert_eq!(descendants(1), vec![2, 3, 4, 5]);
+    assert_eq!(descendants(4), vec![5]);
+    assert!(descendants(3).is_empty());
+    assert!(descendants(6).is_empty());
+    assert_eq!(descendants(7), vec![7, 8]);
+}
+
+#[test]
+fn test_hidden_primary_key() {
+    let mut values = Label {
+        __label_id: String::new(),
+        name: "rust".to_string(),
+        version: 1,
+    }
+    .to_database_values()
+    .unwrap();
+    Repository::<Label>::fill_hidden_primary_key(&mut values);
+
+    let label = Label::from_database_value(&values).unwrap();
+    assert_eq!(label.__label_id.len(), 36);
+
+    let repository: Repository<Label> = Repository::create();
+    let id = repository.insert_entity(label.clone());
+    let renamed = Label {
+        name: "go".to_string(),
+        ..label.clone()
+    };
+
+    assert_eq!(repository.generate_unique_id(&renamed), id);
+    assert_eq!(
+        repository.find(&label.primary_key_values().unwrap()),
+        Some(label)
+    );
+
+    let nodes = node_repository(vec![(1, None)]);
+    let node = |id: u32| Node { id, parent: None };
+    assert_ne!(
+        nodes.generate_unique_id(&node(2)),
+        nodes.generate_unique_id(&node(1))
+    );
+}
+
+#[test]
+fn test_lock_version() {
+    let label = Label {
+        __label_id: generate_uuid(),
+        name: "rust".to_string(),
+        version: 1,
+    };
+    let row = label.to_database_values().unwrap();
+    let lock = |label: &Label| {
+        let mut values = label.to_database_values().unwrap();
+        Repository::<Label>::lock_version(Some(&row), &mut values).map(|_| values)
+    };
+
+    let values = lock(&label).unwrap();
+    assert_eq!(
+        values.get("version"),
+        Some(&DatabaseValue::UnsignedInteger(2))
+    );
+
+    let stale = Label {
+        version: 2,
+        ..label.clone()
+    };
+    assert!(matches!(
+        lock(&stale),
+        Err(TransactionError::StaleEntity(table)) if table == "label"
+    ));
+
+    let mut values = label.to_database_values().unwrap();
+    assert!(Repository::<Label>::lock_version(None, &mut values).is_err());
+}
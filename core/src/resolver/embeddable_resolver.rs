@@ -0,0 +1,97 @@
+use crate::resolver::error::DataConvertError;
+use crate::resolver::AchievedFieldResolver;
+use crate::types::ValuePack;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ItemStruct, Type};
+
+/// Struct which can be flattened into columns of entity, implemented for embeddable structs
+/// declared in schema file
+pub trait Embeddable: Sized {
+    fn from_database_values(values: &ValuePack) -> Result<Self, DataConvertError>;
+
+    fn to_database_values(&self) -> Result<ValuePack, DataConvertError>;
+}
+
+/// Embeddable struct declared in schema file, column names of fields are not prefixed
+pub struct EmbeddableDefinition {
+    pub ident: Ident,
+    pub fields: Vec<AchievedFieldResolver>,
+    item: ItemStruct,
+}
+
+impl EmbeddableDefinition {
+    pub fn new(item: ItemStruct, fields: Vec<AchievedFieldResolver>) -> Self {
+        EmbeddableDefinition {
+            ident: item.ident.clone(),
+            fields,
+            item,
+        }
+    }
+
+    pub fn get_implement(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        let mut item = self.item.clone();
+        item.attrs = vec![];
+
+        for (field, resolver) in item.fields.iter_mut().zip(self.fields.iter()) {
+            field.attrs = vec![];
+
+            if let Type::Path(_) = &resolver.field_type {
+                field.ty = resolver.field_type.clone();
+            }
+        }
+
+        let converters = self
+            .fields
+            .iter()
+            .map(|resolver| &resolver.data_converter_token_stream);
+
+        let field_idents: Vec<_> = self
+            .fields
+            .iter()
+            .map(|resolver| format_ident!("{}", &resolver.field_path.1))
+            .collect();
+
+        let methods: Vec<_> = self
+            .fields
+            .iter()
+            .map(|resolver| &resolver.converter_getter_ident)
+            .collect();
+
+        quote! {
+            #[derive(Clone)]
+            #item
+
+            impl #ident {
+                #(#converters)*
+            }
+
+            impl yukino::resolver::Embeddable for #ident {
+                fn from_database_values(
+                    values: &std::collections::HashMap<String, yukino::types::DatabaseValue>
+                ) -> Result<Self, yukino::resolver::error::DataConvertError> {
+                    use yukino::resolver::ValueConverter;
+
+                    Ok(#ident {
+                        #(#field_idents: Self::#methods().to_field_value(values)?),*
+                    })
+                }
+
+                fn to_database_values(&self)
+                    -> Result<
+                        std::collections::HashMap<String, yukino::types::DatabaseValue>,
+                        yukino::resolver::error::DataConvertError
+                    > {
+                    use yukino::resolver::ValueConverter;
+
+                    let mut map = std::collections::HashMap::new();
+                    #(map.extend(Self::#methods().to_database_values_by_ref(&self.#field_idents)?);)*
+
+                    Ok(map)
+                }
+            }
+        }
+    }
+}
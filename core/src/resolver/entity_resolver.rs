@@ -1,10 +1,17 @@
 use crate::annotations::{Entity, IndexMethod};
 use crate::definitions::{
-    ColumnDefinition, ColumnType, IndexDefinition, TableDefinition, TableType,
+    CheckDefinition, ColumnDefault, ColumnDefinition, ColumnType, FieldDefinition, IndexColumn,
+    IndexDefinition, IndexOrder, TableDefinition, TableType,
+};
+use crate::query::ast::error::SyntaxErrorWithPos;
+use crate::query::type_check::TypeKind;
+use crate::query::{
+    check_entity_expr, check_field_expr, default_type_resolvers, parse_expr, rename_idents,
 };
 use crate::resolver::error::ResolveError;
+use crate::resolver::field_resolver_seeds::{StringTypeResolver, STRING_DEFAULT_LENGTH};
 use crate::resolver::{AchievedFieldResolver, EntityName, FieldName, TypePathResolver};
-use crate::types::DatabaseType;
+use crate::types::{DatabaseType, TypeResolver};
 use heck::SnakeCase;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
@@ -45,6 +52,20 @@ pub struct EntityResolver {
     field_count: usize,
     annotation: Entity,
     field_resolvers: HashMap<FieldName, AchievedFieldResolver>,
+    /// Fields which are not required for entity to be finished
+    deferred_fields: HashSet<FieldName>,
+    /// Column defaults declared by Field annotation, checked when entity is achieved
+    field_defaults: HashMap<FieldName, ColumnDefault>,
+    /// Check constraints declared by Field annotation, in which `value` is the field itself
+    field_checks: HashMap<FieldName, String>,
+    /// Fields declaring string options(length, text, charset or collation) by Field annotation
+    string_option_fields: HashSet<FieldName>,
+    /// Field annotated by `Version`
+    version_field: Option<FieldName>,
+    /// Field annotated by `CreatedAt`
+    created_at_field: Option<FieldName>,
+    /// Field annotated by `UpdatedAt`
+    updated_at_field: Option<FieldName>,
     primary_keys: Vec<String>,
     resolver_passes: Vec<Box<dyn EntityResolverPass>>,
     input: ItemStruct,
@@ -61,6 +82,7 @@ impl EntityResolver {
         let mut resolved_annotation = annotation.unwrap_or(Entity {
             name: None,
             indexes: None,
+            checks: None,
         });
 
         if resolved_annotation.name.is_none() {
@@ -76,6 +98,13 @@ impl EntityResolver {
             field_count,
             annotation: resolved_annotation,
             field_resolvers: HashMap::new(),
+            deferred_fields: HashSet::new(),
+            field_defaults: HashMap::new(),
+            field_checks: HashMap::new(),
+            string_option_fields: HashSet::new(),
+            version_field: None,
+            created_at_field: None,
+            updated_at_field: None,
             primary_keys: vec![],
             resolver_passes,
             input,
@@ -94,6 +123,59 @@ impl EntityResolver {
         self.status
     }
 
+    /// Deferred field is not required for entity to be finished, see `FieldResolver::is_deferred`
+    pub fn defer_field(&mut self, field_name: FieldName) {
+        self.deferred_fields.insert(field_name);
+    }
+
+    pub fn set_field_default(&mut self, field_name: FieldName, default: ColumnDefault) {
+        self.field_defaults.insert(field_name, default);
+    }
+
+    pub fn add_field_check(&mut self, field_name: FieldName, check: String) {
+        self.field_checks.insert(field_name, check);
+    }
+
+    pub fn add_string_option_field(&mut self, field_name: FieldName) {
+        self.string_option_fields.insert(field_name);
+    }
+
+    pub fn set_version_field(&mut self, field_name: FieldName) -> Result<(), ResolveError> {
+        if self.version_field.is_some() {
+            return Err(ResolveError::ConflictVersionField(self.entity_name()));
+        }
+
+        self.version_field = Some(field_name);
+
+        Ok(())
+    }
+
+    pub fn set_created_at_field(&mut self, field_name: FieldName) -> Result<(), ResolveError> {
+        if self.created_at_field.is_some() {
+            return Err(ResolveError::ConflictTimestampField(
+                self.entity_name(),
+                "CreatedAt".to_string(),
+            ));
+        }
+
+        self.created_at_field = Some(field_name);
+
+        Ok(())
+    }
+
+    pub fn set_updated_at_field(&mut self, field_name: FieldName) -> Result<(), ResolveError> {
+        if self.updated_at_field.is_some() {
+            return Err(ResolveError::ConflictTimestampField(
+                self.entity_name(),
+                "UpdatedAt".to_string(),
+            ));
+        }
+
+        self.updated_at_field = Some(field_name);
+
+        Ok(())
+    }
+
     pub fn get_field_resolver(&self, field: &str) -> Result<&AchievedFieldResolver, ResolveError> {
         self.field_resolvers.get(field).ok_or_else(|| {
             ResolveError::FieldResolverNotFound(self.entity_name(), field.to_string())
@@ -162,7 +244,13 @@ impl EntityResolver {
         self.field_resolvers
             .insert(field.field_path.1.clone(), field);
 
-        self.status = if self.field_resolvers.len() == self.field_count {
+        let required_count = self
+            .field_resolvers
+            .keys()
+            .filter(|name| !self.deferred_fields.contains(*name))
+            .count();
+
+        self.status = if required_count == self.field_count - self.deferred_fields.len() {
             EntityResolveStatus::Finished
         } else {
             EntityResolveStatus::Assemble
@@ -181,26 +269,14 @@ impl EntityResolver {
         let mut tables = vec![];
         let mut foreign_keys = vec![];
 
-        let mut indexes = self
-            .annotation
-            .indexes
-            .as_ref()
-            .unwrap()
-            .iter()
-            .map(|(name, index)| {
-                let mut columns = vec![];
-                for field_name in index.fields.iter() {
-                    let mut column_names = self.get_field_resolver(field_name)?.column_names();
-                    columns.append(&mut column_names)
-                }
-                Ok(IndexDefinition {
-                    name: name.clone(),
-                    columns,
-                    method: index.method,
-                    unique: index.unique,
-                })
-            })
-            .collect::<Result<Vec<_>, ResolveError>>()?;
+        let mut indexes = self.resolve_indexes()?;
+
+        self.resolve_string_options()?;
+        self.resolve_field_defaults()?;
+        let checks = self.resolve_checks()?;
+        let version = self.resolve_version()?;
+        let created_at = self.resolve_timestamp(&self.created_at_field)?;
+        let updated_at = self.resolve_timestamp(&self.updated_at_field)?;
 
         for resolver in self.field_resolvers.values() {
             let mut field_columns = resolver.columns.clone();
@@ -227,11 +303,15 @@ impl EntityResolver {
             let auto_primary_keys = ColumnDefinition {
                 name: format!("__{}_id", self.ident.to_string().to_snake_case()),
                 ty: ColumnType::VisualColumn,
-                data_type: DatabaseType::String,
+                data_type: DatabaseType::String(STRING_DEFAULT_LENGTH),
                 unique: true,
                 auto_increase: false,
                 primary_key: true,
                 nullable: false,
+                default: None,
+                charset: None,
+                collation: None,
+                enum_variants: vec![],
             };
             self.primary_keys.push(auto_primary_keys.name.clone());
             columns.push(auto_primary_keys);
@@ -247,10 +327,12 @@ impl EntityResolver {
                 columns: columns
                     .iter()
                     .filter(|item| item.primary_key)
-                    .map(|item| item.name.clone())
+                    .map(|item| IndexColumn::column(item.name.clone()))
                     .collect(),
                 method: IndexMethod::BTree,
                 unique: true,
+                include: vec![],
+                condition: None,
             });
         }
 
@@ -260,6 +342,10 @@ impl EntityResolver {
             columns,
             indexes,
             foreign_keys,
+            checks,
+            version,
+            created_at,
+            updated_at,
         });
 
         let implements = self
@@ -290,14 +376,343 @@ impl EntityResolver {
 
         Ok(AchievedEntityResolver {
             definitions: tables,
+            field_definitions: self.field_definitions(),
             implement: quote! {
                 #implements
             },
         })
     }
+
+    fn field_definitions(&self) -> HashMap<FieldName, FieldDefinition> {
+        self.field_resolvers
+            .iter()
+            .map(|(name, resolver)| (name.clone(), resolver.field_definition.clone()))
+            .chain(
+                self.field_resolvers
+                    .values()
+                    .flat_map(|resolver| resolver.nested_field_definitions.iter())
+                    .map(|definition| (definition.name.clone(), definition.clone())),
+            )
+            .collect()
+    }
+
+    /// String options of Field annotation are only meaningful for string fields
+    fn resolve_string_options(&self) -> Result<(), ResolveError> {
+        let string_resolver_name = StringTypeResolver::seed().name();
+
+        match self.string_option_fields.iter().find(|field_name| {
+            self.field_resolvers
+                .get(*field_name)
+                .map_or(true, |resolver| {
+                    resolver.field_definition.type_resolver_name != string_resolver_name
+                })
+        }) {
+            Some(field_name) => Err(ResolveError::UnsupportedStringOption(
+                self.entity_name(),
+                field_name.clone(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Check default expressions against fields of entity, and attach them to the columns
+    fn resolve_field_defaults(&mut self) -> Result<(), ResolveError> {
+        let entity_name = self.entity_name();
+        let definitions = self.field_definitions();
+        let fields: HashSet<_> = definitions.keys().cloned().collect();
+        let column_names = self.column_names_by_field();
+
+        for (field_name, default) in self.field_defaults.iter() {
+            let invalid_expression = |e: SyntaxErrorWithPos| {
+                ResolveError::InvalidFieldExpression(
+                    entity_name.clone(),
+                    field_name.clone(),
+                    e.to_string(),
+                )
+            };
+
+            let expr = parse_expr(default.expr()).map_err(invalid_expression)?;
+            check_field_expr(
+                expr,
+                &entity_name,
+                field_name,
+                fields.clone(),
+                default_type_resolvers(),
+                |entity: &str, field: &str| {
+                    if entity == entity_name {
+                        definitions.get(field).cloned()
+                    } else {
+                        None
+                    }
+                },
+            )
+            .map_err(invalid_expression)?;
+
+            let column_default = match default {
+                ColumnDefault::Literal(literal) => ColumnDefault::Literal(literal.clone()),
+                ColumnDefault::Expression(expr) => ColumnDefault::Expression(
+                    rename_idents(expr, &column_names).map_err(invalid_expression)?,
+                ),
+                ColumnDefault::Generated(expr) => ColumnDefault::Generated(
+                    rename_idents(expr, &column_names).map_err(invalid_expression)?,
+                ),
+            };
+
+            let resolver = self.field_resolvers.get_mut(field_name).ok_or_else(|| {
+                ResolveError::FieldResolverNotFound(entity_name.clone(), field_name.clone())
+            })?;
+
+            match resolver.columns.as_mut_slice() {
+                [column]
+                    if !column.primary_key || !matches!(default, ColumnDefault::Generated(_)) =>
+                {
+                    column.default = Some(column_default)
+                }
+                _ => {
+                    return Err(ResolveError::UnsupportedFieldDefault(
+                        entity_name.clone(),
+                        field_name.clone(),
+                    ))
+                }
+            }
+
+            resolver.field_definition.default = Some(default.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Names of columns mapped by fields with single column
+    fn column_names_by_field(&self) -> HashMap<FieldName, String> {
+        self.field_resolvers
+            .iter()
+            .filter_map(|(name, resolver)| match resolver.columns.as_slice() {
+                [column] => Some((name.clone(), column.name.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Convert index annotations into indexes on columns, expressions of key parts and partial
+    /// index conditions are checked against fields of entity
+    fn resolve_indexes(&self) -> Result<Vec<IndexDefinition>, ResolveError> {
+        let entity_name = self.entity_name();
+        let definitions = self.field_definitions();
+        let fields: HashSet<_> = definitions.keys().cloned().collect();
+        let column_names = self.column_names_by_field();
+
+        let check_expr = |index_name: &str, input: &str, type_kind: Option<TypeKind>| {
+            parse_expr(input)
+                .and_then(|expr| {
+                    check_entity_expr(
+                        expr,
+                        &entity_name,
+                        fields.clone(),
+                        type_kind,
+                        default_type_resolvers(),
+                        |entity: &str, field: &str| {
+                            if entity == entity_name {
+                                definitions.get(field).cloned()
+                            } else {
+                                None
+                            }
+                        },
+                    )
+                })
+                .and_then(|_| rename_idents(input, &column_names))
+                .map_err(|e| {
+                    ResolveError::InvalidIndex(
+                        entity_name.clone(),
+                        index_name.to_string(),
+                        e.to_string(),
+                    )
+                })
+        };
+
+        self.annotation
+            .indexes
+            .iter()
+            .flatten()
+            .map(|(name, index)| {
+                let mut columns = vec![];
+                for key_part in index.fields.iter() {
+                    let (key, order) = split_index_order(key_part);
+
+                    if self.field_resolvers.contains_key(key) {
+                        let mut field_columns = self
+                            .get_field_resolver(key)?
+                            .column_names()
+                            .into_iter()
+                            .map(|column| IndexColumn::Column(column, order))
+                            .collect();
+                        columns.append(&mut field_columns);
+                    } else {
+                        columns.push(IndexColumn::Expression(check_expr(name, key, None)?, order));
+                    }
+                }
+
+                let mut include = vec![];
+                for field_name in index.include.iter().flatten() {
+                    let mut column_names = self.get_field_resolver(field_name)?.column_names();
+                    include.append(&mut column_names);
+                }
+
+                let condition = match &index.condition {
+                    Some(condition) => Some(check_expr(name, condition, Some(TypeKind::Boolean))?),
+                    None => None,
+                };
+
+                Ok(IndexDefinition {
+                    name: name.clone(),
+                    columns,
+                    method: index.method,
+                    unique: index.unique,
+                    include,
+                    condition,
+                })
+            })
+            .collect()
+    }
+
+    /// Column of version field, `None` if entity is not optimistic locked
+    fn resolve_version(&self) -> Result<Option<String>, ResolveError> {
+        let field_name = match &self.version_field {
+            Some(field_name) => field_name,
+            None => return Ok(None),
+        };
+
+        match self.get_field_resolver(field_name)?.columns.as_slice() {
+            [column]
+                if !column.nullable
+                    && !column.primary_key
+                    && column.data_type.suitable_for_version() =>
+            {
+                Ok(Some(column.name.clone()))
+            }
+            _ => Err(ResolveError::InvalidVersionField(
+                self.entity_name(),
+                field_name.clone(),
+            )),
+        }
+    }
+
+    /// Column of timestamp field declared by `CreatedAt` or `UpdatedAt`
+    fn resolve_timestamp(
+        &self,
+        field_name: &Option<FieldName>,
+    ) -> Result<Option<String>, ResolveError> {
+        let field_name = match field_name {
+            Some(field_name) => field_name,
+            None => return Ok(None),
+        };
+
+        match self.get_field_resolver(field_name)?.columns.as_slice() {
+            [column] if !column.primary_key && column.data_type.suitable_for_timestamp() => {
+                Ok(Some(column.name.clone()))
+            }
+            _ => Err(ResolveError::InvalidTimestampField(
+                self.entity_name(),
+                field_name.clone(),
+            )),
+        }
+    }
+
+    /// Check constraints of field and entity annotations against fields of entity, and convert
+    /// them into constraints on columns
+    fn resolve_checks(&self) -> Result<Vec<CheckDefinition>, ResolveError> {
+        let entity_name = self.entity_name();
+        let definitions = self.field_definitions();
+        let fields: HashSet<_> = definitions.keys().cloned().collect();
+        let column_names = self.column_names_by_field();
+
+        let mut checks = self
+            .field_checks
+            .iter()
+            .map(|(field_name, check)| {
+                let names = vec![("value".to_string(), field_name.clone())]
+                    .into_iter()
+                    .collect();
+
+                rename_idents(check, &names)
+                    .map(|check| (format!("{}_check", field_name), check))
+                    .map_err(|e| {
+                        ResolveError::InvalidFieldExpression(
+                            entity_name.clone(),
+                            field_name.clone(),
+                            e.to_string(),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (name, check) in self.annotation.checks.iter().flatten() {
+            if checks.iter().any(|(check_name, _)| check_name == name) {
+                return Err(ResolveError::ConflictCheckConstraint(
+                    entity_name,
+                    name.clone(),
+                ));
+            }
+
+            checks.push((name.clone(), check.clone()));
+        }
+
+        checks.sort();
+
+        checks
+            .into_iter()
+            .map(|(name, check)| {
+                let invalid_check = |e: SyntaxErrorWithPos| {
+                    ResolveError::InvalidCheckConstraint(
+                        entity_name.clone(),
+                        name.clone(),
+                        e.to_string(),
+                    )
+                };
+
+                check_entity_expr(
+                    parse_expr(&check).map_err(invalid_check)?,
+                    &entity_name,
+                    fields.clone(),
+                    Some(TypeKind::Boolean),
+                    default_type_resolvers(),
+                    |entity: &str, field: &str| {
+                        if entity == entity_name {
+                            definitions.get(field).cloned()
+                        } else {
+                            None
+                        }
+                    },
+                )
+                .map_err(invalid_check)?;
+
+                Ok(CheckDefinition {
+                    expr: rename_idents(&check, &column_names).map_err(invalid_check)?,
+                    name,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Split trailing `asc` or `desc` from key part of index
+fn split_index_order(key_part: &str) -> (&str, IndexOrder) {
+    let key_part = key_part.trim();
+    let (key, order) = match key_part.rfind(|c: char| c.is_ascii_whitespace()) {
+        Some(position) => (key_part[..position].trim_end(), &key_part[position + 1..]),
+        None => return (key_part, IndexOrder::Asc),
+    };
+
+    if order.eq_ignore_ascii_case("desc") {
+        (key, IndexOrder::Desc)
+    } else if order.eq_ignore_ascii_case("asc") {
+        (key, IndexOrder::Asc)
+    } else {
+        (key_part, IndexOrder::Asc)
+    }
 }
 
 pub struct AchievedEntityResolver {
     pub definitions: Vec<TableDefinition>,
+    pub field_definitions: HashMap<FieldName, FieldDefinition>,
     pub implement: TokenStream,
 }
@@ -0,0 +1,148 @@
+use crate::definitions::TableDefinition;
+use crate::resolver::error::ResolveError;
+use crate::resolver::{
+    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, TypePathResolver,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use syn::ItemStruct;
+
+pub struct EntityFieldHandleResolverPass;
+
+impl EntityResolverPass for EntityFieldHandleResolverPass {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        EntityFieldHandleResolverPass
+    }
+
+    fn boxed(&self) -> EntityResolverPassBox {
+        Box::new(EntityFieldHandleResolverPass)
+    }
+
+    fn get_implement_token_stream(
+        &self,
+        entity_name: String,
+        _definitions: &[TableDefinition],
+        field_resolvers: &HashMap<FieldName, AchievedFieldResolver>,
+        _input: &ItemStruct,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<TokenStream, ResolveError>> {
+        let inner_ident = format_ident!("{}Inner", &entity_name);
+        let fields_ident = format_ident!("{}Fields", &entity_name);
+
+        // Sorted by field name, so that generated code is stable between builds
+        let mut field_resolvers: Vec<_> = field_resolvers.iter().collect();
+        field_resolvers.sort_by_key(|(name, _)| name.as_str());
+
+        let handles: Vec<_> = field_resolvers
+            .into_iter()
+            .map(|(name, resolver)| {
+                let method_ident = format_ident!("{}", name);
+
+                match &resolver.field_definition.association {
+                    Some(association) => {
+                        let target_ident =
+                            format_ident!("{}Fields", &association.referenced_entity);
+
+                        quote! {
+                            pub fn #method_ident(&self) -> #target_ident {
+                                let mut segments = self.segments.clone();
+                                segments.push(#name.to_string());
+                                #target_ident::from_segments(segments)
+                            }
+                        }
+                    }
+                    None => {
+                        let field_type =
+                            type_path_resolver.get_full_type(resolver.field_type.clone());
+
+                        quote! {
+                            pub fn #method_ident(&self) -> yukino::query::Field<#inner_ident, #field_type> {
+                                let mut segments = self.segments.clone();
+                                segments.push(#name.to_string());
+                                yukino::query::Field::new(segments)
+                            }
+                        }
+                    }
+                }
+            })
+            .collect();
+
+        Some(Ok(quote! {
+            #[derive(Clone)]
+            pub struct #fields_ident {
+                segments: Vec<String>,
+            }
+
+            impl #fields_ident {
+                pub fn from_segments(segments: Vec<String>) -> Self {
+                    #fields_ident {
+                        segments,
+                    }
+                }
+
+                #(#handles)*
+            }
+
+            impl #inner_ident {
+                pub fn fields(alias: &str) -> #fields_ident {
+                    #fields_ident::from_segments(vec![alias.to_string()])
+                }
+            }
+        }))
+    }
+}
+
+#[test]
+fn test_association_field_handle() {
+    use crate::association::AssociatedEntityFieldResolverSeed;
+    use crate::resolver::field_resolver_seeds::NumericFieldResolverSeed;
+    use crate::resolver::{FieldResolverSeed, SchemaResolver};
+    use syn::parse_quote;
+
+    let mut schema_resolver = SchemaResolver::new(
+        vec![
+            Box::new(NumericFieldResolverSeed::new()),
+            Box::new(AssociatedEntityFieldResolverSeed::new()),
+        ],
+        vec![Box::new(EntityFieldHandleResolverPass::new())],
+    );
+    let type_path_resolver = TypePathResolver::default();
+    let inputs: Vec<ItemStruct> = vec![
+        parse_quote! {
+            pub struct Bar {
+                #[ID]
+                id: u32,
+            }
+        },
+        parse_quote! {
+            pub struct Foo {
+                #[ID]
+                id: u32,
+                bar: AssociatedEntity<Bar<'t>>,
+            }
+        },
+    ];
+
+    for input in inputs {
+        schema_resolver.parse(input, &type_path_resolver).unwrap();
+    }
+
+    let achieved = schema_resolver.achieve(&type_path_resolver).unwrap();
+    let association = achieved
+        .get_field_definition("Foo", "bar")
+        .and_then(|definition| definition.association)
+        .unwrap();
+
+    assert_eq!(association.referenced_entity, "Bar");
+
+    let implements = achieved.get_implements().to_string();
+    let foo_fields = &implements[implements.find("struct FooFields").unwrap()..];
+    let handle_position = |name: &str| foo_fields.find(&format!("pub fn {} (& self) ->", name));
+
+    assert!(implements.contains("-> BarFields"));
+    assert!(handle_position("bar").unwrap() < handle_position("id").unwrap());
+}
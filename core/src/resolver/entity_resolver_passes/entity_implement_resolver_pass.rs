@@ -1,7 +1,8 @@
 use crate::definitions::TableDefinition;
 use crate::resolver::error::ResolveError;
 use crate::resolver::{
-    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, TypePathResolver,
+    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, HiddenPrimaryKey,
+    TypePathResolver,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -31,14 +32,27 @@ impl EntityResolverPass for EntityImplementResolverPass {
         _type_path_resolver: &TypePathResolver,
     ) -> Option<Result<TokenStream, ResolveError>> {
         let ident = format_ident!("{}Inner", entity_name);
+        let hidden_primary_key = HiddenPrimaryKey::from_definitions(&entity_name, definitions);
+        let hidden_fields: Vec<_> = hidden_primary_key
+            .iter()
+            .map(|primary_key| {
+                (
+                    primary_key.converter_getter_ident(),
+                    primary_key.field_ident(),
+                )
+            })
+            .collect();
 
         let temp_values: Vec<_> = field_resolvers
             .values()
             .map(|resolver| {
-                let method = resolver.converter_getter_ident.clone();
-
-                let field_ident = format_ident!("{}", &resolver.field_path.1);
-
+                (
+                    resolver.converter_getter_ident.clone(),
+                    format_ident!("{}", &resolver.field_path.1),
+                )
+            })
+            .chain(hidden_fields.iter().cloned())
+            .map(|(method, field_ident)| {
                 quote::quote! {
                     let #field_ident = Self::#method().to_field_value(result)?
                 }
@@ -48,15 +62,23 @@ impl EntityResolverPass for EntityImplementResolverPass {
         let fields: Vec<_> = field_resolvers
             .values()
             .map(|resolver| format_ident!("{}", &resolver.field_path.1))
+            .chain(
+                hidden_fields
+                    .iter()
+                    .map(|(_, field_ident)| field_ident.clone()),
+            )
             .collect();
 
         let inserts: Vec<_> = field_resolvers
             .values()
             .map(|resolver| {
-                let method = resolver.converter_getter_ident.clone();
-
-                let field_ident = format_ident!("{}", &resolver.field_path.1);
-
+                (
+                    resolver.converter_getter_ident.clone(),
+                    format_ident!("{}", &resolver.field_path.1),
+                )
+            })
+            .chain(hidden_fields.iter().cloned())
+            .map(|(method, field_ident)| {
                 quote::quote! {
                     map.extend(Self::#method().to_database_values_by_ref(&self.#field_ident)?)
                 }
@@ -65,17 +87,17 @@ impl EntityResolverPass for EntityImplementResolverPass {
 
         let primary_key_inserts: Vec<_> = field_resolvers
             .values()
-            .filter_map(|resolver| {
-                if !resolver.primary_key_column_names().is_empty() {
-                    let method = resolver.converter_getter_ident.clone();
-
-                    let field_ident = format_ident!("{}", &resolver.field_path.1);
-
-                    Some(quote::quote! {
-                        map.extend(Self::#method().primary_column_values_by_ref(&self.#field_ident)?)
-                    })
-                } else {
-                    None
+            .filter(|resolver| !resolver.primary_key_column_names().is_empty())
+            .map(|resolver| {
+                (
+                    resolver.converter_getter_ident.clone(),
+                    format_ident!("{}", &resolver.field_path.1),
+                )
+            })
+            .chain(hidden_fields.iter().cloned())
+            .map(|(method, field_ident)| {
+                quote::quote! {
+                    map.extend(Self::#method().primary_column_values_by_ref(&self.#field_ident)?)
                 }
             })
             .collect();
@@ -91,8 +113,11 @@ impl EntityResolverPass for EntityImplementResolverPass {
 
         let field_definitions: Vec<_> = field_resolvers
             .iter()
-            .map(|(_, resolver)| {
-                let definition = resolver.field_definition.clone();
+            .flat_map(|(_, resolver)| {
+                std::iter::once(&resolver.field_definition)
+                    .chain(resolver.nested_field_definitions.iter())
+            })
+            .map(|definition| {
                 let name = definition.name.as_str();
 
                 quote::quote! {
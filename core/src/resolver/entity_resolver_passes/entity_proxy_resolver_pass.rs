@@ -1,7 +1,8 @@
 use crate::definitions::TableDefinition;
 use crate::resolver::error::ResolveError;
 use crate::resolver::{
-    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, TypePathResolver,
+    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, HiddenPrimaryKey,
+    TypePathResolver,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -26,7 +27,7 @@ impl EntityResolverPass for EntityProxyResolverPass {
     fn get_implement_token_stream(
         &self,
         entity_name: String,
-        _definitions: &[TableDefinition],
+        definitions: &[TableDefinition],
         field_resolvers: &HashMap<FieldName, AchievedFieldResolver>,
         _input: &ItemStruct,
         type_path_resolver: &TypePathResolver,
@@ -58,13 +59,25 @@ impl EntityResolverPass for EntityProxyResolverPass {
             .map(|(_, resolver)| format_ident!("{}", resolver.field_path.1))
             .collect();
 
+        let hidden_fields: Vec<_> = HiddenPrimaryKey::from_definitions(&entity_name, definitions)
+            .iter()
+            .map(|primary_key| {
+                let field_ident = primary_key.field_ident();
+
+                quote! {
+                    #field_ident: String::new()
+                }
+            })
+            .collect();
+
         let create = quote! {
             pub fn with_value(
                 #(#params,)*
             ) -> impl FnOnce() -> #inner_ident {
                 move || {
                     #inner_ident {
-                        #(#field_idents),*
+                        #(#field_idents,)*
+                        #(#hidden_fields,)*
                     }
                 }
             }
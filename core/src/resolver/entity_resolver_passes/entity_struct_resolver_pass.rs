@@ -1,12 +1,13 @@
 use crate::definitions::TableDefinition;
 use crate::resolver::error::ResolveError;
 use crate::resolver::{
-    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, TypePathResolver,
+    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, HiddenPrimaryKey,
+    TypePathResolver,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::collections::HashMap;
-use syn::{ItemStruct, Type, Visibility};
+use syn::{Fields, ItemStruct, Type, Visibility};
 
 pub struct EntityStructResolverPass;
 
@@ -25,17 +26,24 @@ impl EntityResolverPass for EntityStructResolverPass {
     fn get_implement_token_stream(
         &self,
         entity_name: String,
-        _definitions: &[TableDefinition],
+        definitions: &[TableDefinition],
         field_resolvers: &HashMap<FieldName, AchievedFieldResolver>,
         input: &ItemStruct,
         type_path_resolver: &TypePathResolver,
     ) -> Option<Result<TokenStream, ResolveError>> {
         let new_ident = format_ident!("{}Inner", entity_name);
 
+        let hidden_primary_key = HiddenPrimaryKey::from_definitions(&entity_name, definitions);
+
         let converters: Vec<_> = field_resolvers
             .values()
             .into_iter()
             .map(|resolver| resolver.data_converter_token_stream.clone())
+            .chain(
+                hidden_primary_key
+                    .iter()
+                    .map(|primary_key| primary_key.data_converter_token_stream()),
+            )
             .collect();
 
         let mut struct_item = input.clone();
@@ -75,6 +83,12 @@ impl EntityResolverPass for EntityStructResolverPass {
             }
         }
 
+        if let (Some(primary_key), Fields::Named(named_fields)) =
+            (&hidden_primary_key, &mut struct_item.fields)
+        {
+            named_fields.named.push(primary_key.field());
+        }
+
         Some(Ok(quote! {
         #[derive(Clone)]
             #struct_item
@@ -0,0 +1,111 @@
+use crate::annotations::Tree;
+use crate::definitions::TableDefinition;
+use crate::resolver::error::ResolveError;
+use crate::resolver::{
+    AchievedFieldResolver, EntityResolverPass, EntityResolverPassBox, FieldName, TypePathResolver,
+};
+use annotation_rs::AnnotationStructure;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use std::str::FromStr;
+use syn::ItemStruct;
+
+/// Generate ancestors and descendants helpers for entity annotated by `Tree`
+pub struct EntityTreeResolverPass;
+
+impl EntityResolverPass for EntityTreeResolverPass {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        EntityTreeResolverPass
+    }
+
+    fn boxed(&self) -> EntityResolverPassBox {
+        Box::new(EntityTreeResolverPass)
+    }
+
+    fn get_implement_token_stream(
+        &self,
+        entity_name: String,
+        _definitions: &[TableDefinition],
+        field_resolvers: &HashMap<FieldName, AchievedFieldResolver>,
+        input: &ItemStruct,
+        _type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<TokenStream, ResolveError>> {
+        let attr = input
+            .attrs
+            .iter()
+            .find(|attr| attr.path == Tree::get_path())?;
+
+        let tree = match attr.parse_meta().and_then(|meta| Tree::from_meta(&meta)) {
+            Ok(tree) => tree,
+            Err(e) => return Some(Err(ResolveError::ParseError(e.to_string()))),
+        };
+
+        let invalid_parent = || {
+            Some(Err(ResolveError::InvalidTreeParentField(
+                entity_name.clone(),
+                tree.parent.clone(),
+            )))
+        };
+
+        let parent = match field_resolvers.get(&tree.parent) {
+            Some(parent) => parent,
+            None => return invalid_parent(),
+        };
+
+        let column_map = match &parent.field_definition.association {
+            Some(association)
+                if parent.field_definition.nullable
+                    && !association.is_list
+                    && association.membership.is_none()
+                    && association.referenced_entity == entity_name =>
+            {
+                &association.column_map
+            }
+            _ => return invalid_parent(),
+        };
+
+        let ident = TokenStream::from_str(&entity_name).unwrap();
+        let inner_ident = format_ident!("{}Inner", &entity_name);
+        let parent_getter = &parent.field_getter_ident;
+        let (parent_columns, referenced_columns): (Vec<_>, Vec<_>) =
+            column_map.iter().cloned().unzip();
+
+        Some(Ok(quote! {
+            impl<'t> #ident<'t> {
+                /// Ancestors of entity, from parent to root
+                pub fn ancestors(&self) -> Vec<#ident<'t>> {
+                    let mut result = vec![];
+                    let mut current = self.#parent_getter();
+
+                    while let Some(parent) = current {
+                        current = parent.#parent_getter();
+                        result.push(parent);
+                    }
+
+                    result
+                }
+
+                /// Descendants of entity at any depth
+                pub fn descendants(&self) -> Vec<#ident<'t>> {
+                    use yukino::EntityProxy;
+                    let inner = self.get_inner();
+                    let values = yukino::Entity::to_database_values(inner).unwrap();
+
+                    self.get_transaction()
+                        .get_repository::<#inner_ident>()
+                        .find_descendants(
+                            &[#((#parent_columns.to_string(), #referenced_columns.to_string())),*],
+                            &values
+                        )
+                        .into_iter()
+                        .map(|entity| self.get_transaction().create_entity(move || entity))
+                        .collect()
+                }
+            }
+        }))
+    }
+}
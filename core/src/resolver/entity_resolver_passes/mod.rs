@@ -1,7 +1,11 @@
+mod entity_field_handle_resolver_pass;
 mod entity_implement_resolver_pass;
 mod entity_proxy_resolver_pass;
 mod entity_struct_resolver_pass;
+mod entity_tree_resolver_pass;
 
+pub use entity_field_handle_resolver_pass::*;
 pub use entity_implement_resolver_pass::*;
 pub use entity_proxy_resolver_pass::*;
 pub use entity_struct_resolver_pass::*;
+pub use entity_tree_resolver_pass::*;
@@ -0,0 +1,236 @@
+use crate::annotations::{EnumStorage, Enumeration};
+use crate::resolver::error::ResolveError;
+use crate::resolver::field_resolver_seeds::STRING_DEFAULT_LENGTH;
+use crate::types::DatabaseType;
+use annotation_rs::AnnotationStructure;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Error as SynError, Expr, Fields, ItemEnum, Lit, UnOp, Visibility};
+
+impl EnumStorage {
+    pub fn database_type(&self) -> DatabaseType {
+        match self {
+            EnumStorage::String => DatabaseType::String(STRING_DEFAULT_LENGTH),
+            EnumStorage::Integer => DatabaseType::Integer,
+            EnumStorage::Native => DatabaseType::Enum,
+        }
+    }
+}
+
+/// Rust enum which can be stored in a column, implemented for enums declared in schema file
+pub trait EnumLike: Sized {
+    fn from_variant(name: &str) -> Option<Self>;
+
+    fn variant(&self) -> &'static str;
+
+    fn from_discriminant(discriminant: i32) -> Option<Self>;
+
+    fn discriminant(&self) -> i32;
+}
+
+/// Unit-variant enum declared in schema file
+#[derive(Clone)]
+pub struct EnumDefinition {
+    pub ident: Ident,
+    pub storage: EnumStorage,
+    /// Variant idents and their discriminants
+    pub variants: Vec<(Ident, i32)>,
+    item: ItemEnum,
+}
+
+impl EnumDefinition {
+    pub fn from_item(item: &ItemEnum) -> Result<Self, SynError> {
+        let storage = item
+            .attrs
+            .iter()
+            .filter_map(|attr| {
+                if attr.path == Enumeration::get_path() {
+                    Some(
+                        attr.parse_meta()
+                            .and_then(|meta| Enumeration::from_meta(&meta)),
+                    )
+                } else {
+                    None
+                }
+            })
+            .next()
+            .map_or(Ok(None), |v| v.map(Some))?
+            .map(|annotation| annotation.storage)
+            .unwrap_or(EnumStorage::String);
+
+        let name = item.ident.to_string();
+
+        if !item.generics.params.is_empty() {
+            return Err(ResolveError::GenericIsNotSupported(name).into_syn_error(item));
+        } else if !matches!(&item.vis, Visibility::Public(_)) {
+            return Err(ResolveError::EnumVisibilityMustBePublic(name).into_syn_error(item));
+        } else if item.variants.is_empty() {
+            return Err(ResolveError::Others(format!(
+                "Enum({}) must have at least one variant",
+                name
+            ))
+            .into_syn_error(item));
+        }
+
+        let mut variants: Vec<(Ident, i32)> = vec![];
+        for variant in item.variants.iter() {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(
+                    ResolveError::UnsupportedEnumVariant(name, variant.ident.to_string())
+                        .into_syn_error(variant),
+                );
+            }
+
+            let discriminant = match &variant.discriminant {
+                Some((_, expr)) => Self::parse_discriminant(expr),
+                None => match variants.last() {
+                    Some((_, previous)) => previous.checked_add(1),
+                    None => Some(0),
+                },
+            }
+            .filter(|discriminant| variants.iter().all(|(_, d)| d != discriminant))
+            .ok_or_else(|| {
+                ResolveError::InvalidEnumDiscriminant(name.clone(), variant.ident.to_string())
+                    .into_syn_error(variant)
+            })?;
+
+            variants.push((variant.ident.clone(), discriminant));
+        }
+
+        Ok(EnumDefinition {
+            ident: item.ident.clone(),
+            storage,
+            variants,
+            item: item.clone(),
+        })
+    }
+
+    fn parse_discriminant(expr: &Expr) -> Option<i32> {
+        match expr {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(int) => int.base10_parse().ok(),
+                _ => None,
+            },
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+                Self::parse_discriminant(&unary.expr).and_then(i32::checked_neg)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_implement(&self) -> TokenStream {
+        let ident = &self.ident;
+        let name = ident.to_string();
+        let variant_idents: Vec<_> = self.variants.iter().map(|(ident, _)| ident).collect();
+        let variant_names: Vec<_> = variant_idents.iter().map(|i| i.to_string()).collect();
+        let discriminants: Vec<_> = self.variants.iter().map(|(_, d)| *d).collect();
+        let database_type = self.storage.database_type();
+        let database_value = match self.storage {
+            EnumStorage::Integer => quote! {
+                yukino::types::DatabaseValue::Integer(
+                    yukino::resolver::EnumLike::discriminant(self)
+                )
+            },
+            _ => quote! {
+                yukino::types::DatabaseValue::String(
+                    yukino::resolver::EnumLike::variant(self).to_string()
+                )
+            },
+        };
+
+        let mut item = self.item.clone();
+        item.attrs = vec![];
+
+        quote! {
+            #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+            #item
+
+            impl yukino::resolver::EnumLike for #ident {
+                fn from_variant(name: &str) -> Option<Self> {
+                    match name {
+                        #(#variant_names => Some(#ident::#variant_idents),)*
+                        _ => None,
+                    }
+                }
+
+                fn variant(&self) -> &'static str {
+                    match self {
+                        #(#ident::#variant_idents => #variant_names,)*
+                    }
+                }
+
+                fn from_discriminant(discriminant: i32) -> Option<Self> {
+                    match discriminant {
+                        #(#discriminants => Some(#ident::#variant_idents),)*
+                        _ => None,
+                    }
+                }
+
+                fn discriminant(&self) -> i32 {
+                    match self {
+                        #(#ident::#variant_idents => #discriminants,)*
+                    }
+                }
+            }
+
+            impl yukino::query::ToParam for #ident {
+                fn type_name() -> String {
+                    #name.to_string()
+                }
+
+                fn database_type() -> yukino::types::DatabaseType {
+                    #database_type
+                }
+
+                fn to_database_value(&self) -> yukino::types::DatabaseValue {
+                    #database_value
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_enum_definition() {
+    use syn::parse_quote;
+
+    let item: ItemEnum = parse_quote! {
+        #[Enumeration(storage = "integer")]
+        pub enum Status {
+            Active = 1,
+            Inactive,
+            Deleted = -1,
+        }
+    };
+
+    let definition = EnumDefinition::from_item(&item).unwrap();
+    assert_eq!(definition.storage, EnumStorage::Integer);
+    assert_eq!(
+        definition
+            .variants
+            .iter()
+            .map(|(ident, d)| (ident.to_string(), *d))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Active".to_string(), 1),
+            ("Inactive".to_string(), 2),
+            ("Deleted".to_string(), -1)
+        ]
+    );
+
+    let duplicated: ItemEnum = parse_quote! {
+        pub enum Status {
+            Active = 1,
+            Inactive = 0,
+            Deleted,
+        }
+    };
+    assert!(EnumDefinition::from_item(&duplicated).is_err());
+
+    let tuple: ItemEnum = parse_quote! {
+        pub enum Status {
+            Active(u32),
+        }
+    };
+    assert!(EnumDefinition::from_item(&tuple).is_err());
+}
@@ -28,7 +28,7 @@ pub enum ResolveError {
     NoSuitableResolverSeedsFound(EntityName, FieldName),
     #[error("GlobInPathIsNotSupported: Glob in path({0}) is not supported")]
     GlobInPathIsNotSupported(String),
-    #[error("GlobInPathIsNotSupported: Schema file only support `struct` and `use` block")]
+    #[error("GlobInPathIsNotSupported: Schema file only support `struct`, `enum` and `use` block")]
     UnsupportedSyntaxBlock,
     #[error("GenericIsNotSupported: Generic is not supported on entity struct: {0}")]
     GenericIsNotSupported(EntityName),
@@ -44,6 +44,46 @@ pub enum ResolveError {
     MappingFieldsNotUnique(EntityName, FieldName),
     #[error("UnsuitableColumnDataTypeForPrimaryKey: Unsuitable column data type for primary key on field({1} in {0})")]
     UnsuitableColumnDataTypeForPrimaryKey(EntityName, FieldName),
+    #[error("InvalidInversedField: Field({1} in {0}) inversed by inverse association field must be an association referencing {2}")]
+    InvalidInversedField(EntityName, FieldName, EntityName),
+    #[error("AssociationCardinalityConflict: Cardinality of inverse association field({1} in {0}) conflicts with its owning side field")]
+    AssociationCardinalityConflict(EntityName, FieldName),
+    #[error("MissingPrimaryKey: Entity({2}) associated by field({1} in {0}) must have primary key fields")]
+    MissingPrimaryKey(EntityName, FieldName, EntityName),
+    #[error("InvalidReferentialAction: Field({1} in {0}) must be nullable and not primary key to use `set_null` referential action")]
+    InvalidReferentialAction(EntityName, FieldName),
+    #[error("SelfReferencingPrimaryKey: Self-referencing association field({1} in {0}) can not be primary key")]
+    SelfReferencingPrimaryKey(EntityName, FieldName),
+    #[error("InvalidTreeParentField: Parent field({1} in {0}) of tree must be a nullable association referencing {0}")]
+    InvalidTreeParentField(EntityName, FieldName),
+    #[error("ConflictFieldDefault: Field({1} in {0}) can not have both default value and generated expression")]
+    ConflictFieldDefault(EntityName, FieldName),
+    #[error("UnsupportedFieldDefault: Default value or generated expression of field({1} in {0}) is only supported on field with single column, and generated column can not be primary key")]
+    UnsupportedFieldDefault(EntityName, FieldName),
+    #[error("InvalidFieldExpression: Invalid expression on field({1} in {0}): {2}")]
+    InvalidFieldExpression(EntityName, FieldName, String),
+    #[error("UnsupportedStringOption: Length, text, charset and collation of field({1} in {0}) are only supported on string field")]
+    UnsupportedStringOption(EntityName, FieldName),
+    #[error("InvalidCheckConstraint: Check constraint({1}) of {0} is invalid: {2}")]
+    InvalidCheckConstraint(EntityName, String, String),
+    #[error("ConflictCheckConstraint: Check constraint({1}) of {0} is declared more than once")]
+    ConflictCheckConstraint(EntityName, String),
+    #[error("ConflictVersionField: Entity({0}) can not have more than one version field")]
+    ConflictVersionField(EntityName),
+    #[error("InvalidVersionField: Version field({1} in {0}) must be a non-null integer or date time field which is not primary key")]
+    InvalidVersionField(EntityName, FieldName),
+    #[error("ConflictTimestampField: Entity({0}) can not have more than one {1} field")]
+    ConflictTimestampField(EntityName, String),
+    #[error("InvalidTimestampField: Timestamp field({1} in {0}) must be a date time or timestamp field which is not primary key")]
+    InvalidTimestampField(EntityName, FieldName),
+    #[error("InvalidIndex: Index({1}) of {0} is invalid: {2}")]
+    InvalidIndex(EntityName, String, String),
+    #[error("EnumVisibilityMustBePublic: Visibility of enum({0}) must be public")]
+    EnumVisibilityMustBePublic(String),
+    #[error("UnsupportedEnumVariant: Variant({1}) of enum({0}) must be a unit variant")]
+    UnsupportedEnumVariant(String, String),
+    #[error("InvalidEnumDiscriminant: Discriminant of variant({1}) in enum({0}) must be a unique i32 literal")]
+    InvalidEnumDiscriminant(String, String),
     #[error("IOError: {0}")]
     IOError(IOError),
     #[error("ParseError: {0}")]
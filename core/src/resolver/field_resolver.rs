@@ -104,6 +104,13 @@ pub trait FieldResolverSeed {
             name: None,
             unique: false,
             auto_increase: false,
+            default: None,
+            generated: None,
+            check: None,
+            length: None,
+            text: false,
+            charset: None,
+            collation: None,
             options: None,
         };
 
@@ -148,6 +155,12 @@ pub trait FieldResolver {
         entity_resolver: &EntityResolver,
     ) -> Result<AchievedFieldResolver, ResolveError>;
 
+    /// Deferred field is not required for its entity to be finished, so it can wait for its own
+    /// entity, e.g. inverse association field
+    fn is_deferred(&self) -> bool {
+        false
+    }
+
     fn converter_getter_ident(&self) -> Ident {
         quote::format_ident!("get_{}_converter", &self.field_path().1.to_snake_case())
     }
@@ -187,6 +200,8 @@ pub struct AchievedFieldResolver {
     pub field_setter_token_stream: TokenStream,
     pub field_type: Type,
     pub field_definition: FieldDefinition,
+    /// Definitions of fields inside the field, e.g. `shipping.city` of embedded field `shipping`
+    pub nested_field_definitions: Vec<FieldDefinition>,
 }
 
 impl AchievedFieldResolver {
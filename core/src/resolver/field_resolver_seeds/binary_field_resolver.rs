@@ -0,0 +1,721 @@
+use crate::annotations::FieldAnnotation;
+use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{
+    Binary as BinaryExpr, BinaryOperator, ColumnIdent, Expr, FunctionCall, JoinClause, Literal,
+    Locatable, Location,
+};
+use crate::query::type_check::TypeKind;
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::field_resolver_seeds::bool_field_resolver::BoolTypeResolver;
+use crate::resolver::field_resolver_seeds::numeric_field_resolver::NumericTypeResolver;
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
+    FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, TypePathResolver, ValueConverter,
+};
+use crate::types::{
+    Binary, DatabaseType, DatabaseValue, ExprWrapper, IdentResolveStatus, TypeInfo, TypeResolver,
+    ValuePack,
+};
+use heck::SnakeCase;
+use iroha::ToTokens;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use syn::{Expr as SynExpr, GenericArgument, Lit, PathArguments, Type};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BinaryType {
+    /// `Vec<u8>`
+    Vec,
+    /// `[u8; N]`
+    Fixed(usize),
+    /// `bytes::Bytes`, only with `bytes` feature
+    #[cfg(feature = "bytes")]
+    Bytes,
+}
+
+impl BinaryType {
+    pub fn from_type(ty: &Type) -> Option<Self> {
+        match ty {
+            Type::Path(type_path) => {
+                let last_segment = type_path.path.segments.last()?;
+
+                #[cfg(feature = "bytes")]
+                if last_segment.ident == *"Bytes" {
+                    return Some(BinaryType::Bytes);
+                }
+
+                match &last_segment.arguments {
+                    PathArguments::AngleBracketed(arguments)
+                        if last_segment.ident == *"Vec" && arguments.args.len() == 1 =>
+                    {
+                        match arguments.args.first() {
+                            Some(GenericArgument::Type(elem)) if is_u8(elem) => {
+                                Some(BinaryType::Vec)
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            Type::Array(array) if is_u8(&array.elem) => match &array.len {
+                SynExpr::Lit(lit) => match &lit.lit {
+                    Lit::Int(int) => int.base10_parse().ok().map(BinaryType::Fixed),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Length of literal must be the same as the fixed length
+    pub fn accept_length(&self, length: usize) -> bool {
+        match self {
+            BinaryType::Fixed(size) => *size == length,
+            _ => true,
+        }
+    }
+}
+
+impl FromStr for BinaryType {
+    type Err = ResolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Vec<u8>" => Ok(BinaryType::Vec),
+            #[cfg(feature = "bytes")]
+            "Bytes" => Ok(BinaryType::Bytes),
+            _ => s
+                .strip_prefix("[u8; ")
+                .and_then(|s| s.strip_suffix(']'))
+                .and_then(|size| size.parse().ok())
+                .map(BinaryType::Fixed)
+                .ok_or(ResolveError::UnsupportedEntityStructType),
+        }
+    }
+}
+
+impl Display for BinaryType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            BinaryType::Vec => write!(f, "Vec<u8>"),
+            BinaryType::Fixed(size) => write!(f, "[u8; {}]", size),
+            #[cfg(feature = "bytes")]
+            BinaryType::Bytes => write!(f, "Bytes"),
+        }
+    }
+}
+
+fn is_u8(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("u8"))
+}
+
+/// Parse value of binary literal, e.g. `x"0aff"` or `b"bytes"`
+pub fn parse_binary_literal(prefix: &str, value: &str) -> Option<Binary> {
+    match prefix {
+        "x" if value.len() % 2 == 0 => (0..value.len())
+            .step_by(2)
+            .map(|i| {
+                value
+                    .get(i..i + 2)
+                    .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+            })
+            .collect(),
+        "b" => Some(value.as_bytes().to_vec()),
+        _ => None,
+    }
+}
+
+/// Rust type which can be stored in binary column
+pub trait BinaryLike: Sized {
+    fn from_binary(binary: Binary) -> Option<Self>;
+
+    fn as_binary(&self) -> &[u8];
+}
+
+impl BinaryLike for Vec<u8> {
+    fn from_binary(binary: Binary) -> Option<Self> {
+        Some(binary)
+    }
+
+    fn as_binary(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> BinaryLike for [u8; N] {
+    fn from_binary(binary: Binary) -> Option<Self> {
+        binary.try_into().ok()
+    }
+
+    fn as_binary(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl BinaryLike for bytes::Bytes {
+    fn from_binary(binary: Binary) -> Option<Self> {
+        Some(binary.into())
+    }
+
+    fn as_binary(&self) -> &[u8] {
+        self.as_ref()
+    }
+}
+
+pub struct BinaryFieldResolverSeed;
+
+impl FieldResolverSeed for BinaryFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        BinaryFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(BinaryFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let (nullable, nested_type) = match Self::unwrap_option(
+            field_type,
+            (entity_name.clone(), ident.to_string()),
+            type_path_resolver,
+        ) {
+            Ok(r) => r,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let ty = BinaryType::from_type(&nested_type)?;
+        let field = Self::default_annotations(annotations);
+
+        if field.auto_increase || Self::is_primary_key(annotations) {
+            return Some(Err(ResolveError::Others(format!(
+                "PrimaryKey or AutoIncrease is not supported on binary field({0} in {1})",
+                ident, entity_name
+            ))));
+        }
+
+        Some(Ok(Box::new(BinaryFieldResolver {
+            field_path: (entity_name.clone(), ident.to_string()),
+            ty,
+            definition: ColumnDefinition {
+                name: field
+                    .name
+                    .unwrap_or_else(|| ident.to_string().to_snake_case()),
+                ty: ColumnType::NormalColumn(entity_name),
+                data_type: DatabaseType::Binary,
+                unique: field.unique,
+                auto_increase: false,
+                primary_key: false,
+                nullable,
+                default: None,
+                charset: None,
+                collation: None,
+                enum_variants: vec![],
+            },
+            field_type: type_path_resolver.get_full_type(field_type.clone()),
+            nullable,
+            nested_type,
+        })))
+    }
+}
+
+pub struct BinaryFieldResolver {
+    field_path: FieldPath,
+    ty: BinaryType,
+    definition: ColumnDefinition,
+    field_type: Type,
+    nullable: bool,
+    nested_type: Type,
+}
+
+impl FieldResolver for BinaryFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        FieldResolverStatus::WaitingAssemble
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        _resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        _resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let method_name = self.converter_getter_ident();
+        let (entity_name, field_name) = self.field_path();
+
+        let converter = BinaryValueConverter {
+            is_primary_key: self.definition.primary_key,
+            column_name: self.definition.name.clone(),
+            entity_name,
+            field_name: field_name.clone(),
+        };
+
+        let data_converter_token_stream = quote! {
+            pub fn #method_name() -> yukino::resolver::field_resolver_seeds::BinaryValueConverter {
+                #converter
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", field_name);
+        let field_type = &self.field_type;
+        let nested_type = &self.nested_type;
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> &#field_type {
+                let inner = self.get_inner();
+                &inner.#field_ident
+            }
+        };
+        let field_setter_token_stream = if self.nullable {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= Some(value);
+                    self
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= value;
+                    self
+                }
+            }
+        };
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: vec![self.definition.clone()],
+            joined_table: vec![],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: method_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            field_setter_token_stream,
+            field_type: field_type.clone(),
+            field_definition: FieldDefinition {
+                entity: self.field_path.0.clone(),
+                name: self.field_path.1.clone(),
+                type_resolver_name: BinaryTypeResolver::seed().name(),
+                field_type: self.ty.to_string(),
+                nullable: self.nullable,
+                length: None,
+                default: None,
+                columns: vec![self.definition.name.clone()],
+                tables: vec![],
+                association: None,
+            },
+            nested_field_definitions: vec![],
+        })
+    }
+}
+
+#[derive(ToTokens)]
+#[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+pub struct BinaryValueConverter {
+    is_primary_key: bool,
+    column_name: String,
+    entity_name: String,
+    field_name: String,
+}
+
+impl<T: BinaryLike> ValueConverter<T> for BinaryValueConverter {
+    fn to_field_value(&self, values: &ValuePack) -> Result<T, DataConvertError> {
+        match values.get(&self.column_name) {
+            Some(DatabaseValue::Binary(value)) => T::from_binary(value.clone()),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            DataConvertError::UnexpectedDatabaseValueType(
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )
+        })
+    }
+
+    fn to_database_values_by_ref(&self, value: &T) -> Result<ValuePack, DataConvertError> {
+        let mut map = HashMap::new();
+        map.insert(
+            self.column_name.clone(),
+            DatabaseValue::Binary(value.as_binary().to_vec()),
+        );
+
+        Ok(map)
+    }
+
+    fn primary_column_values_by_ref(&self, _value: &T) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+impl<T: BinaryLike> ValueConverter<Option<T>> for BinaryValueConverter {
+    fn to_field_value(&self, values: &ValuePack) -> Result<Option<T>, DataConvertError> {
+        match values.get(&self.column_name) {
+            Some(DatabaseValue::Binary(value)) => T::from_binary(value.clone()).map(Some),
+            Some(DatabaseValue::Null(DatabaseType::Binary)) => Some(None),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            DataConvertError::UnexpectedDatabaseValueType(
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )
+        })
+    }
+
+    fn to_database_values_by_ref(&self, value: &Option<T>) -> Result<ValuePack, DataConvertError> {
+        let mut map = HashMap::new();
+        map.insert(
+            self.column_name.clone(),
+            match value {
+                Some(v) => DatabaseValue::Binary(v.as_binary().to_vec()),
+                None => DatabaseValue::Null(DatabaseType::Binary),
+            },
+        );
+
+        Ok(map)
+    }
+
+    fn primary_column_values_by_ref(
+        &self,
+        _value: &Option<T>,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+pub struct BinaryTypeResolver;
+
+impl BinaryTypeResolver {
+    fn type_info(&self, ty: BinaryType, nullable: bool) -> TypeInfo {
+        TypeInfo {
+            resolver_name: self.name(),
+            field_type: ty.to_string(),
+            nullable,
+            length: None,
+            type_kind: TypeKind::Binary,
+        }
+    }
+}
+
+impl TypeResolver for BinaryTypeResolver {
+    fn seed() -> Box<dyn TypeResolver> {
+        Box::new(BinaryTypeResolver)
+    }
+
+    fn name(&self) -> String {
+        "binary".to_string()
+    }
+
+    /// Binary types can be compared with each other, except fixed arrays in different sizes
+    fn cmp_type_info(&self, a: &TypeInfo, b: &TypeInfo) -> bool {
+        a.resolver_name == b.resolver_name
+            && !matches!(
+                (
+                    BinaryType::from_str(a.field_type.as_str()),
+                    BinaryType::from_str(b.field_type.as_str()),
+                ),
+                (Ok(BinaryType::Fixed(a)), Ok(BinaryType::Fixed(b))) if a != b
+            )
+    }
+
+    fn is_assignable(&self, target: &TypeInfo, value: &TypeInfo) -> bool {
+        match (
+            BinaryType::from_str(target.field_type.as_str()),
+            BinaryType::from_str(value.field_type.as_str()),
+        ) {
+            (Ok(BinaryType::Fixed(target)), Ok(BinaryType::Fixed(value))) => target == value,
+            (Ok(BinaryType::Fixed(_)), _) => false,
+            (Ok(_), Ok(_)) => true,
+            _ => false,
+        }
+    }
+
+    fn literal_type_info(&self, prefix: &str) -> Option<TypeInfo> {
+        match prefix {
+            "x" | "b" => Some(self.type_info(BinaryType::Vec, false)),
+            _ => None,
+        }
+    }
+
+    fn has_function(&self, name: &str) -> bool {
+        name == "length"
+    }
+
+    fn wrap_lit(
+        &self,
+        lit: &Literal,
+        type_info: TypeInfo,
+    ) -> Result<(ExprWrapper, Vec<(String, String)>), SyntaxErrorWithPos> {
+        match lit {
+            Literal::Typed(typed) => {
+                let ty =
+                    BinaryType::from_str(type_info.field_type.as_str()).unwrap_or(BinaryType::Vec);
+                let binary = parse_binary_literal(&typed.prefix, &typed.value)
+                    .filter(|binary| ty.accept_length(binary.len()))
+                    .ok_or_else(|| {
+                        lit.location().error(SyntaxError::InvalidLiteral(
+                            typed.value.clone(),
+                            ty.to_string(),
+                        ))
+                    })?;
+
+                Ok((
+                    ExprWrapper {
+                        exprs: vec![Expr::Literal(lit.clone())],
+                        type_info: self.type_info(
+                            if matches!(ty, BinaryType::Fixed(_)) {
+                                ty
+                            } else {
+                                BinaryType::Fixed(binary.len())
+                            },
+                            false,
+                        ),
+                        location: lit.location(),
+                    },
+                    vec![],
+                ))
+            }
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(external.ident.clone(), type_info.to_string())],
+            )),
+            Literal::Null(_) if type_info.nullable => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info,
+                    location: lit.location(),
+                },
+                vec![],
+            )),
+            _ => Err(lit.location().error(SyntaxError::TypeError(
+                type_info.to_string(),
+                TypeKind::from(lit).to_string(),
+            ))),
+        }
+    }
+
+    fn wrap_ident(
+        &self,
+        ident: &ColumnIdent,
+        field_definition: &FieldDefinition,
+    ) -> Result<(IdentResolveStatus, Vec<JoinClause>), SyntaxErrorWithPos> {
+        let ty = BinaryType::from_str(field_definition.field_type.as_str()).map_err(|_| {
+            ident.location().error(SyntaxError::TypeError(
+                "binary".to_string(),
+                field_definition.field_type.clone(),
+            ))
+        })?;
+
+        Ok((
+            IdentResolveStatus::Resolved(ExprWrapper {
+                exprs: vec![Expr::ColumnIdent(ident.clone())],
+                type_info: self.type_info(ty, field_definition.nullable),
+                location: ident.location(),
+            }),
+            vec![],
+        ))
+    }
+
+    fn handle_binary(
+        &self,
+        mut left: ExprWrapper,
+        mut right: ExprWrapper,
+        location: Location,
+        operator: BinaryOperator,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if matches!(operator, BinaryOperator::Eq | BinaryOperator::Neq) {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Binary(BinaryExpr {
+                    operator,
+                    left: Box::new(left.exprs.pop().unwrap()),
+                    right: Box::new(right.exprs.pop().unwrap()),
+                    location,
+                })],
+                type_info: TypeInfo {
+                    resolver_name: BoolTypeResolver::seed().name(),
+                    field_type: "bool".to_string(),
+                    nullable: left.type_info.nullable || right.type_info.nullable,
+                    length: None,
+                    type_kind: TypeKind::Boolean,
+                },
+                location,
+            })
+        } else {
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                format!("{:?}", operator),
+                left.type_info.to_string(),
+            )))
+        }
+    }
+
+    fn handle_function(
+        &self,
+        call: &FunctionCall,
+        mut arguments: Vec<Option<ExprWrapper>>,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if arguments.len() != 1 {
+            return Err(call
+                .location()
+                .error(SyntaxError::WrongArgumentCount(call.ident.clone())));
+        }
+
+        let item = arguments
+            .pop()
+            .unwrap()
+            .filter(|item| item.type_info.resolver_name == self.name())
+            .ok_or_else(|| {
+                call.parameters[0].location().error(SyntaxError::TypeError(
+                    "binary".to_string(),
+                    format!("{:?}", call.parameters[0]),
+                ))
+            })?;
+        let nullable = item.type_info.nullable;
+
+        Ok(ExprWrapper {
+            exprs: vec![Expr::FunctionCall(FunctionCall {
+                ident: call.ident.to_lowercase(),
+                parameters: vec![self.unwrap_expr(item)?],
+                location: call.location(),
+            })],
+            type_info: TypeInfo {
+                resolver_name: NumericTypeResolver::seed().name(),
+                field_type: "u32".to_string(),
+                nullable,
+                length: None,
+                type_kind: TypeKind::Numeric,
+            },
+            location: call.location(),
+        })
+    }
+}
+
+#[test]
+fn test_binary_literal() {
+    assert_eq!(parse_binary_literal("x", "0aFF"), Some(vec![10, 255]));
+    assert_eq!(parse_binary_literal("x", "0aF"), None);
+    assert_eq!(parse_binary_literal("x", "zz"), None);
+    assert_eq!(parse_binary_literal("b", "ab"), Some(vec![97, 98]));
+
+    assert_eq!(
+        BinaryType::from_str("[u8; 16]").ok(),
+        Some(BinaryType::Fixed(16))
+    );
+    assert_eq!(
+        BinaryType::from_str(&BinaryType::Vec.to_string()).ok(),
+        Some(BinaryType::Vec)
+    );
+    assert!(BinaryType::Fixed(2).accept_length(2));
+    assert!(!BinaryType::Fixed(2).accept_length(3));
+}
+
+#[test]
+fn test_binary_type() {
+    use syn::parse_quote;
+
+    assert_eq!(
+        BinaryType::from_type(&parse_quote!(Vec<u8>)),
+        Some(BinaryType::Vec)
+    );
+    assert_eq!(
+        BinaryType::from_type(&parse_quote!([u8; 4])),
+        Some(BinaryType::Fixed(4))
+    );
+
+    #[cfg(feature = "bytes")]
+    let bytes = Some(BinaryType::Bytes);
+    #[cfg(not(feature = "bytes"))]
+    let bytes = None;
+
+    assert_eq!(BinaryType::from_type(&parse_quote!(bytes::Bytes)), bytes);
+}
+
+#[test]
+fn test_binary_type_check() {
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let definition_getter = |entity: &str, field: &str| {
+        let (field_type, nullable) = match (entity, field) {
+            ("test", "data") => ("Vec<u8>", true),
+            ("test", "hash") => ("[u8; 2]", false),
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "binary".to_string(),
+            field_type: field_type.to_string(),
+            nullable,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        })
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check(
+        "SELECT LENGTH(t.data) AS a FROM test t WHERE t.hash = x\"0aff\" AND t.data != b\"ab\"",
+    )
+    .unwrap();
+
+    assert_eq!(
+        checked.result_types.get("a").map(|s| s.as_str()),
+        Some("Option<u32>")
+    );
+
+    assert!(check("SELECT t.hash AS a FROM test t WHERE t.hash = x\"0aff01\"").is_err());
+    assert!(check("SELECT t.hash AS a FROM test t WHERE t.data = x\"0af\"").is_err());
+    assert!(check("SELECT t.hash AS a FROM test t WHERE t.data > t.hash").is_err());
+}
@@ -2,7 +2,7 @@ use crate::annotations::FieldAnnotation;
 use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{
-    Binary, BinaryOperator, ColumnIdent, Expr, JoinClause, Location, Unary, UnaryOperator,
+    Binary, BinaryOperator, Cast, ColumnIdent, Expr, JoinClause, Location, Unary, UnaryOperator,
 };
 use crate::query::ast::{Literal, Locatable};
 use crate::query::type_check::TypeKind;
@@ -75,6 +75,10 @@ impl FieldResolverSeed for BoolFieldResolverSeed {
                                 auto_increase: false,
                                 primary_key: false,
                                 nullable,
+                                default: None,
+                                charset: None,
+                                collation: None,
+                                enum_variants: vec![],
                             },
                             field_type: type_path_resolver.get_full_type(field_type.clone()),
                             nullable,
@@ -186,15 +190,18 @@ impl FieldResolver for BoolFieldResolver {
             field_setter_token_stream,
             field_type: self.field_type.clone(),
             field_definition: FieldDefinition {
-                entity: self.field_path.1.clone(),
+                entity: self.field_path.0.clone(),
                 name: self.definition.name.clone(),
                 type_resolver_name: BoolTypeResolver::seed().name(),
-                field_type: "string".to_string(),
+                field_type: "bool".to_string(),
                 nullable: self.nullable,
+                length: None,
+                default: None,
                 columns: vec![self.definition.name.clone()],
                 tables: vec![],
                 association: None,
             },
+            nested_field_definitions: vec![],
         })
     }
 }
@@ -252,7 +259,7 @@ impl ValueConverter<Option<bool>> for BoolValueConverter {
             self.column_name.clone(),
             match value {
                 Some(v) => DatabaseValue::Bool(*v),
-                None => DatabaseValue::Null(DatabaseType::String),
+                None => DatabaseValue::Null(DatabaseType::Bool),
             },
         );
 
@@ -278,6 +285,20 @@ impl TypeResolver for BoolTypeResolver {
         "bool".to_string()
     }
 
+    fn cast_type_info(&self, ty: &str) -> Option<TypeInfo> {
+        if ty == "bool" {
+            Some(TypeInfo {
+                resolver_name: self.name(),
+                field_type: ty.to_string(),
+                nullable: false,
+                length: None,
+                type_kind: TypeKind::Boolean,
+            })
+        } else {
+            None
+        }
+    }
+
     fn wrap_lit(
         &self,
         lit: &Literal,
@@ -292,6 +313,14 @@ impl TypeResolver for BoolTypeResolver {
                 },
                 vec![],
             )),
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(external.ident.clone(), type_info.to_string())],
+            )),
             Literal::Null(_) if type_info.nullable => Ok((
                 ExprWrapper {
                     exprs: vec![Expr::Literal(lit.clone())],
@@ -322,6 +351,7 @@ impl TypeResolver for BoolTypeResolver {
                 field_type: field_definition.field_type.clone(),
                 nullable: field_definition.nullable,
                 resolver_name: self.name(),
+                length: None,
                 type_kind: TypeKind::Boolean,
             };
 
@@ -343,7 +373,10 @@ impl TypeResolver for BoolTypeResolver {
         location: Location,
         operator: BinaryOperator,
     ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
-        let type_info = left.type_info.clone();
+        let type_info = TypeInfo {
+            nullable: left.type_info.nullable || right.type_info.nullable,
+            ..left.type_info.clone()
+        };
 
         if matches!(
             operator,
@@ -390,4 +423,31 @@ impl TypeResolver for BoolTypeResolver {
             location,
         })
     }
+
+    fn handle_cast(
+        &self,
+        mut item: ExprWrapper,
+        type_info: TypeInfo,
+        location: Location,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if matches!(
+            item.type_info.type_kind,
+            TypeKind::Numeric | TypeKind::Boolean
+        ) {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Cast(Cast {
+                    expr: Box::new(item.exprs.pop().unwrap()),
+                    ty: type_info.field_type.clone(),
+                    location,
+                })],
+                type_info,
+                location,
+            })
+        } else {
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                "Cast".to_string(),
+                item.type_info.to_string(),
+            )))
+        }
+    }
 }
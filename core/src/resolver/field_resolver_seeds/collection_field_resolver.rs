@@ -1,12 +1,13 @@
 use crate::annotations::FieldAnnotation;
 use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
 use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::field_resolver_seeds::JsonTypeResolver;
 use crate::resolver::{
     AchievedFieldResolver, EntityName, EntityResolver, FieldName, FieldPath, FieldResolver,
     FieldResolverBox, FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus,
     TypePathResolver, ValueConverter,
 };
-use crate::types::{DatabaseType, DatabaseValue, ValuePack};
+use crate::types::{DatabaseType, DatabaseValue, TypeResolver, ValuePack};
 use heck::SnakeCase;
 use iroha::ToTokens;
 use proc_macro2::{Ident, TokenStream};
@@ -119,6 +120,10 @@ impl FieldResolverSeed for CollectionFieldResolverSeed {
                 auto_increase: false,
                 primary_key: false,
                 nullable: false,
+                default: None,
+                charset: None,
+                collation: None,
+                enum_variants: vec![],
             };
 
             Some(Ok(Box::new(CollectionFieldResolver {
@@ -210,15 +215,18 @@ impl FieldResolver for CollectionFieldResolver {
             field_setter_token_stream,
             field_type: Type::Path(field_type.clone()),
             field_definition: FieldDefinition {
-                entity: self.field_path.1.clone(),
+                entity: self.field_path.0.clone(),
                 name: self.field_path.1.clone(),
-                type_resolver_name: "".to_string(), // todo: impl type resolver for collection
+                type_resolver_name: JsonTypeResolver::seed().name(),
                 field_type: self.field_type.to_token_stream().to_string(),
                 nullable: false,
+                length: None,
+                default: None,
                 columns: vec![self.definition.name.clone()],
                 tables: vec![],
                 association: None,
             },
+            nested_field_definitions: vec![],
         })
     }
 }
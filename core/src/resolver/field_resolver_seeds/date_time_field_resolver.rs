@@ -0,0 +1,835 @@
+use crate::annotations::FieldAnnotation;
+use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{
+    Binary, BinaryOperator, Cast, ColumnIdent, Expr, FunctionCall, JoinClause, Literal, Locatable,
+    Location,
+};
+use crate::query::type_check::TypeKind;
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::field_resolver_seeds::bool_field_resolver::BoolTypeResolver;
+use crate::resolver::field_resolver_seeds::numeric_field_resolver::NumericTypeResolver;
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
+    FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, TypePathResolver, ValueConverter,
+};
+use crate::types::{
+    DatabaseType, DatabaseValue, ExprWrapper, IdentResolveStatus, TypeInfo, TypeResolver, ValuePack,
+};
+use heck::SnakeCase;
+use iroha::ToTokens;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens as _};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use syn::{Path, Type};
+use time::{Date, PrimitiveDateTime, Time};
+
+const DATE_TIME_UNITS: [&str; 7] = ["year", "month", "week", "day", "hour", "minute", "second"];
+const INTERVAL_UNITS: [&str; 7] = ["year", "month", "week", "day", "hour", "minute", "second"];
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DateTimeType {
+    Date,
+    Time,
+    DateTime,
+    /// Interval, only available in query
+    Duration,
+}
+
+impl DateTimeType {
+    /// Resolve type from the full path of field type, only types of crate `time` are accepted
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let segments: Vec<_> = path.segments.iter().collect();
+        let last = match segments.as_slice() {
+            [last] => last,
+            [first, .., last] if first.ident == "time" => last,
+            _ => return None,
+        };
+
+        Self::from_str(last.ident.to_string().as_str())
+            .ok()
+            .filter(|ty| ty != &DateTimeType::Duration)
+    }
+
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix {
+            "date" => Some(DateTimeType::Date),
+            "time" => Some(DateTimeType::Time),
+            "datetime" | "timestamp" => Some(DateTimeType::DateTime),
+            "interval" => Some(DateTimeType::Duration),
+            _ => None,
+        }
+    }
+
+    pub fn database_type(&self) -> DatabaseType {
+        match self {
+            DateTimeType::Date => DatabaseType::Date,
+            DateTimeType::Time => DatabaseType::Time,
+            DateTimeType::DateTime => DatabaseType::DateTime,
+            DateTimeType::Duration => unreachable!(),
+        }
+    }
+
+    pub fn converter_name(&self) -> TokenStream {
+        let prefix = quote! {
+            yukino::resolver::field_resolver_seeds
+        };
+
+        match self {
+            DateTimeType::Date => quote! {
+                #prefix::DateValueConverter
+            },
+            DateTimeType::Time => quote! {
+                #prefix::TimeValueConverter
+            },
+            DateTimeType::DateTime => quote! {
+                #prefix::DateTimeValueConverter
+            },
+            DateTimeType::Duration => unreachable!(),
+        }
+    }
+
+    pub fn converter_token_stream(
+        &self,
+        column_name: String,
+        field_path: FieldPath,
+        is_primary_key: bool,
+    ) -> TokenStream {
+        let (entity_name, field_name) = field_path;
+
+        match self {
+            DateTimeType::Date => (DateValueConverter {
+                is_primary_key,
+                column_name,
+                entity_name,
+                field_name,
+            })
+            .to_token_stream(),
+            DateTimeType::Time => (TimeValueConverter {
+                is_primary_key,
+                column_name,
+                entity_name,
+                field_name,
+            })
+            .to_token_stream(),
+            DateTimeType::DateTime => (DateTimeValueConverter {
+                is_primary_key,
+                column_name,
+                entity_name,
+                field_name,
+            })
+            .to_token_stream(),
+            DateTimeType::Duration => unreachable!(),
+        }
+    }
+
+    /// Check whether the value of literal can be parsed into this type
+    pub fn is_valid_literal(&self, value: &str) -> bool {
+        match self {
+            DateTimeType::Date => Date::parse(value, "%F").is_ok(),
+            DateTimeType::Time => Time::parse(value, "%T").is_ok(),
+            DateTimeType::DateTime => PrimitiveDateTime::parse(value, "%F %T").is_ok(),
+            DateTimeType::Duration => {
+                let items: Vec<_> = value.split_whitespace().collect();
+
+                !items.is_empty()
+                    && items.len() % 2 == 0
+                    && items.chunks(2).all(|chunk| {
+                        chunk[0].parse::<i64>().is_ok()
+                            && INTERVAL_UNITS
+                                .contains(&chunk[1].to_lowercase().trim_end_matches('s'))
+                    })
+            }
+        }
+    }
+}
+
+impl FromStr for DateTimeType {
+    type Err = ResolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Date" => Ok(DateTimeType::Date),
+            "Time" => Ok(DateTimeType::Time),
+            "PrimitiveDateTime" => Ok(DateTimeType::DateTime),
+            "Duration" => Ok(DateTimeType::Duration),
+            _ => Err(ResolveError::UnsupportedEntityStructType),
+        }
+    }
+}
+
+impl Display for DateTimeType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "{}",
+            match self {
+                DateTimeType::Date => "Date",
+                DateTimeType::Time => "Time",
+                DateTimeType::DateTime => "PrimitiveDateTime",
+                DateTimeType::Duration => "Duration",
+            }
+        )
+    }
+}
+
+pub struct DateTimeFieldResolverSeed;
+
+impl FieldResolverSeed for DateTimeFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        DateTimeFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(DateTimeFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let (nullable, nested_type) = match Self::unwrap_option(
+            field_type,
+            (entity_name.clone(), ident.to_string()),
+            type_path_resolver,
+        ) {
+            Ok(r) => r,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let ty = match &nested_type {
+            Type::Path(type_path) => {
+                DateTimeType::from_path(&type_path_resolver.get_full_path(type_path.clone()).path)
+            }
+            _ => None,
+        }?;
+
+        let field = Self::default_annotations(annotations);
+
+        if field.auto_increase || Self::is_primary_key(annotations) {
+            return Some(Err(ResolveError::Others(format!(
+                "PrimaryKey or AutoIncrease is not supported on date time field({0} in {1})",
+                ident, entity_name
+            ))));
+        }
+
+        Some(Ok(Box::new(DateTimeFieldResolver {
+            field_path: (entity_name.clone(), ident.to_string()),
+            ty,
+            definition: ColumnDefinition {
+                name: field
+                    .name
+                    .unwrap_or_else(|| ident.to_string().to_snake_case()),
+                ty: ColumnType::NormalColumn(entity_name),
+                data_type: ty.database_type(),
+                unique: field.unique,
+                auto_increase: false,
+                primary_key: false,
+                nullable,
+                default: None,
+                charset: None,
+                collation: None,
+                enum_variants: vec![],
+            },
+            field_type: type_path_resolver.get_full_type(field_type.clone()),
+            nullable,
+            nested_type: type_path_resolver.get_full_type(nested_type),
+        })))
+    }
+}
+
+pub struct DateTimeFieldResolver {
+    field_path: FieldPath,
+    ty: DateTimeType,
+    definition: ColumnDefinition,
+    field_type: Type,
+    nullable: bool,
+    nested_type: Type,
+}
+
+impl FieldResolver for DateTimeFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        FieldResolverStatus::WaitingAssemble
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        _resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        _resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let method_name = self.converter_getter_ident();
+        let output_type = self.ty.converter_name();
+        let converter = self.ty.converter_token_stream(
+            self.definition.name.clone(),
+            self.field_path.clone(),
+            self.definition.primary_key,
+        );
+
+        let data_converter_token_stream = quote! {
+            pub fn #method_name() -> #output_type {
+                #converter
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", self.field_path().1);
+        let field_type = &self.field_type;
+        let nested_type = &self.nested_type;
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> #field_type {
+                let inner = self.get_inner();
+                inner.#field_ident
+            }
+        };
+        let field_setter_token_stream = if self.nullable {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= Some(value);
+                    self
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= value;
+                    self
+                }
+            }
+        };
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: vec![self.definition.clone()],
+            joined_table: vec![],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: method_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            field_setter_token_stream,
+            field_type: field_type.clone(),
+            field_definition: FieldDefinition {
+                entity: self.field_path.0.clone(),
+                name: self.field_path.1.clone(),
+                type_resolver_name: DateTimeTypeResolver::seed().name(),
+                field_type: self.ty.to_string(),
+                nullable: self.nullable,
+                length: None,
+                default: None,
+                columns: vec![self.definition.name.clone()],
+                tables: vec![],
+                association: None,
+            },
+            nested_field_definitions: vec![],
+        })
+    }
+}
+
+pub struct DateTimeTypeResolver;
+
+impl DateTimeTypeResolver {
+    fn type_info(&self, ty: DateTimeType, nullable: bool) -> TypeInfo {
+        TypeInfo {
+            resolver_name: self.name(),
+            field_type: ty.to_string(),
+            nullable,
+            length: None,
+            type_kind: TypeKind::DateTime,
+        }
+    }
+
+    fn date_time_type(type_info: &TypeInfo) -> Option<DateTimeType> {
+        DateTimeType::from_str(type_info.field_type.as_str()).ok()
+    }
+
+    /// Check the unit in parameter like `date_trunc("day", t.date)`
+    fn unit_parameter(call: &FunctionCall) -> Result<(), SyntaxErrorWithPos> {
+        match call.parameters.first() {
+            Some(Expr::Literal(Literal::String(unit)))
+                if DATE_TIME_UNITS.contains(&unit.value.to_lowercase().as_str()) =>
+            {
+                Ok(())
+            }
+            Some(parameter) => Err(parameter.location().error(SyntaxError::InvalidLiteral(
+                format!("{:?}", parameter),
+                "unit".to_string(),
+            ))),
+            None => Err(call
+                .location()
+                .error(SyntaxError::WrongArgumentCount(call.ident.clone()))),
+        }
+    }
+}
+
+impl TypeResolver for DateTimeTypeResolver {
+    fn seed() -> Box<dyn TypeResolver> {
+        Box::new(DateTimeTypeResolver)
+    }
+
+    fn name(&self) -> String {
+        "date_time".to_string()
+    }
+
+    /// Types are compatible if they are the same or one of them is interval
+    fn cmp_type_info(&self, a: &TypeInfo, b: &TypeInfo) -> bool {
+        a.resolver_name == b.resolver_name
+            && (a.field_type == b.field_type
+                || a.field_type == DateTimeType::Duration.to_string()
+                || b.field_type == DateTimeType::Duration.to_string())
+    }
+
+    fn is_assignable(&self, target: &TypeInfo, value: &TypeInfo) -> bool {
+        target.resolver_name == value.resolver_name && target.field_type == value.field_type
+    }
+
+    fn cast_type_info(&self, ty: &str) -> Option<TypeInfo> {
+        DateTimeType::from_str(ty)
+            .ok()
+            .filter(|ty| ty != &DateTimeType::Duration)
+            .map(|ty| self.type_info(ty, false))
+    }
+
+    fn literal_type_info(&self, prefix: &str) -> Option<TypeInfo> {
+        DateTimeType::from_prefix(prefix).map(|ty| self.type_info(ty, false))
+    }
+
+    fn has_function(&self, name: &str) -> bool {
+        matches!(name, "now" | "date_trunc" | "extract")
+    }
+
+    fn wrap_lit(
+        &self,
+        lit: &Literal,
+        type_info: TypeInfo,
+    ) -> Result<(ExprWrapper, Vec<(String, String)>), SyntaxErrorWithPos> {
+        match lit {
+            Literal::Typed(typed) => {
+                let ty = DateTimeType::from_prefix(&typed.prefix).ok_or_else(|| {
+                    lit.location().error(SyntaxError::TypeError(
+                        type_info.to_string(),
+                        TypeKind::from(lit).to_string(),
+                    ))
+                })?;
+
+                if !ty.is_valid_literal(&typed.value) {
+                    return Err(lit.location().error(SyntaxError::InvalidLiteral(
+                        typed.value.clone(),
+                        ty.to_string(),
+                    )));
+                }
+
+                let literal_type_info = self.type_info(ty, false);
+
+                if self.cmp_type_info(&type_info, &literal_type_info) {
+                    Ok((
+                        ExprWrapper {
+                            exprs: vec![Expr::Literal(lit.clone())],
+                            type_info: literal_type_info,
+                            location: lit.location(),
+                        },
+                        vec![],
+                    ))
+                } else {
+                    Err(lit.location().error(SyntaxError::TypeError(
+                        type_info.to_string(),
+                        literal_type_info.to_string(),
+                    )))
+                }
+            }
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(external.ident.clone(), type_info.to_string())],
+            )),
+            Literal::Null(_) if type_info.nullable => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info,
+                    location: lit.location(),
+                },
+                vec![],
+            )),
+            _ => Err(lit.location().error(SyntaxError::TypeError(
+                type_info.to_string(),
+                TypeKind::from(lit).to_string(),
+            ))),
+        }
+    }
+
+    fn wrap_ident(
+        &self,
+        ident: &ColumnIdent,
+        field_definition: &FieldDefinition,
+    ) -> Result<(IdentResolveStatus, Vec<JoinClause>), SyntaxErrorWithPos> {
+        let ty = DateTimeType::from_str(field_definition.field_type.as_str()).map_err(|_| {
+            ident.location().error(SyntaxError::TypeError(
+                "date time".to_string(),
+                field_definition.field_type.clone(),
+            ))
+        })?;
+
+        Ok((
+            IdentResolveStatus::Resolved(ExprWrapper {
+                exprs: vec![Expr::ColumnIdent(ident.clone())],
+                type_info: self.type_info(ty, field_definition.nullable),
+                location: ident.location(),
+            }),
+            vec![],
+        ))
+    }
+
+    fn handle_binary(
+        &self,
+        mut left: ExprWrapper,
+        mut right: ExprWrapper,
+        location: Location,
+        operator: BinaryOperator,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        let nullable = left.type_info.nullable || right.type_info.nullable;
+        let left_ty = Self::date_time_type(&left.type_info);
+        let right_ty = Self::date_time_type(&right.type_info);
+
+        let type_info =
+            match (left_ty, right_ty, operator) {
+                (Some(left_ty), Some(right_ty), operator)
+                    if operator.is_cmp() && left_ty == right_ty =>
+                {
+                    Some(TypeInfo {
+                        resolver_name: BoolTypeResolver::seed().name(),
+                        field_type: "bool".to_string(),
+                        nullable,
+                        length: None,
+                        type_kind: TypeKind::Boolean,
+                    })
+                }
+                (Some(ty), Some(DateTimeType::Duration), BinaryOperator::Plus)
+                | (Some(DateTimeType::Duration), Some(ty), BinaryOperator::Plus)
+                | (Some(ty), Some(DateTimeType::Duration), BinaryOperator::Minus) => {
+                    Some(self.type_info(ty, nullable))
+                }
+                (Some(DateTimeType::Date), Some(DateTimeType::Date), BinaryOperator::Minus)
+                | (
+                    Some(DateTimeType::DateTime),
+                    Some(DateTimeType::DateTime),
+                    BinaryOperator::Minus,
+                ) => Some(self.type_info(DateTimeType::Duration, nullable)),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                location.error(SyntaxError::UnimplementedOperationForType(
+                    format!("{:?}", operator),
+                    format!("{}, {}", left.type_info, right.type_info),
+                ))
+            })?;
+
+        Ok(ExprWrapper {
+            exprs: vec![Expr::Binary(Binary {
+                operator,
+                left: Box::new(left.exprs.pop().unwrap()),
+                right: Box::new(right.exprs.pop().unwrap()),
+                location,
+            })],
+            type_info,
+            location,
+        })
+    }
+
+    fn handle_cast(
+        &self,
+        mut item: ExprWrapper,
+        type_info: TypeInfo,
+        location: Location,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if matches!(
+            item.type_info.type_kind,
+            TypeKind::DateTime | TypeKind::String
+        ) {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Cast(Cast {
+                    expr: Box::new(item.exprs.pop().unwrap()),
+                    ty: type_info.field_type.clone(),
+                    location,
+                })],
+                type_info,
+                location,
+            })
+        } else {
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                "Cast".to_string(),
+                item.type_info.to_string(),
+            )))
+        }
+    }
+
+    fn handle_function(
+        &self,
+        call: &FunctionCall,
+        mut arguments: Vec<Option<ExprWrapper>>,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        let name = call.ident.to_lowercase();
+        let wrong_argument_count = || {
+            call.location()
+                .error(SyntaxError::WrongArgumentCount(call.ident.clone()))
+        };
+
+        if name == "now" {
+            return if arguments.is_empty() {
+                Ok(ExprWrapper {
+                    exprs: vec![Expr::FunctionCall(call.clone())],
+                    type_info: self.type_info(DateTimeType::DateTime, false),
+                    location: call.location(),
+                })
+            } else {
+                Err(wrong_argument_count())
+            };
+        }
+
+        if arguments.len() != 2 {
+            return Err(wrong_argument_count());
+        }
+
+        Self::unit_parameter(call)?;
+        let item = arguments.pop().unwrap().ok_or_else(|| {
+            call.parameters[1]
+                .location()
+                .error(SyntaxError::CannotInferType)
+        })?;
+        let ty = Self::date_time_type(&item.type_info)
+            .filter(|ty| ty != &DateTimeType::Duration)
+            .ok_or_else(|| {
+                item.location.error(SyntaxError::TypeError(
+                    "date time".to_string(),
+                    item.type_info.to_string(),
+                ))
+            })?;
+
+        let nullable = item.type_info.nullable;
+        let type_info = if name == "extract" {
+            TypeInfo {
+                resolver_name: NumericTypeResolver::seed().name(),
+                field_type: "i32".to_string(),
+                nullable,
+                length: None,
+                type_kind: TypeKind::Numeric,
+            }
+        } else {
+            self.type_info(ty, nullable)
+        };
+
+        Ok(ExprWrapper {
+            exprs: vec![Expr::FunctionCall(FunctionCall {
+                ident: name,
+                parameters: vec![call.parameters[0].clone(), self.unwrap_expr(item)?],
+                location: call.location(),
+            })],
+            type_info,
+            location: call.location(),
+        })
+    }
+}
+
+macro_rules! impl_converter {
+    ($ident: ident, $output_type: ty, $database_value: ident) => {
+        #[derive(ToTokens)]
+        #[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+        pub struct $ident {
+            is_primary_key: bool,
+            column_name: String,
+            entity_name: String,
+            field_name: String,
+        }
+
+        impl ValueConverter<$output_type> for $ident {
+            fn to_field_value(&self, values: &ValuePack) -> Result<$output_type, DataConvertError> {
+                match values.get(&self.column_name) {
+                    Some(DatabaseValue::$database_value(value)) => Ok(*value),
+                    _ => Err(DataConvertError::UnexpectedDatabaseValueType(
+                        self.entity_name.clone(),
+                        self.field_name.clone(),
+                    )),
+                }
+            }
+
+            fn to_database_values_by_ref(
+                &self,
+                value: &$output_type,
+            ) -> Result<ValuePack, DataConvertError> {
+                let mut map = HashMap::new();
+                map.insert(
+                    self.column_name.clone(),
+                    DatabaseValue::$database_value(*value),
+                );
+
+                Ok(map)
+            }
+
+            fn primary_column_values_by_ref(
+                &self,
+                value: &$output_type,
+            ) -> Result<ValuePack, DataConvertError> {
+                if self.is_primary_key {
+                    self.to_database_values_by_ref(value)
+                } else {
+                    Ok(HashMap::new())
+                }
+            }
+        }
+
+        impl ValueConverter<Option<$output_type>> for $ident {
+            fn to_field_value(
+                &self,
+                values: &ValuePack,
+            ) -> Result<Option<$output_type>, DataConvertError> {
+                match values.get(&self.column_name) {
+                    Some(DatabaseValue::$database_value(value)) => Ok(Some(*value)),
+                    Some(DatabaseValue::Null(DatabaseType::$database_value)) => Ok(None),
+                    _ => Err(DataConvertError::UnexpectedDatabaseValueType(
+                        self.entity_name.clone(),
+                        self.field_name.clone(),
+                    )),
+                }
+            }
+
+            fn to_database_values_by_ref(
+                &self,
+                value: &Option<$output_type>,
+            ) -> Result<ValuePack, DataConvertError> {
+                let mut map = HashMap::new();
+                map.insert(
+                    self.column_name.clone(),
+                    match value {
+                        Some(v) => DatabaseValue::$database_value(*v),
+                        None => DatabaseValue::Null(DatabaseType::$database_value),
+                    },
+                );
+
+                Ok(map)
+            }
+
+            fn primary_column_values_by_ref(
+                &self,
+                value: &Option<$output_type>,
+            ) -> Result<ValuePack, DataConvertError> {
+                if self.is_primary_key {
+                    self.to_database_values_by_ref(value)
+                } else {
+                    Ok(HashMap::new())
+                }
+            }
+        }
+    };
+}
+
+impl_converter!(DateValueConverter, Date, Date);
+impl_converter!(TimeValueConverter, Time, Time);
+impl_converter!(DateTimeValueConverter, PrimitiveDateTime, DateTime);
+
+#[test]
+fn test_date_time() {
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+    use syn::parse_quote;
+
+    let definition_getter = |entity: &str, field: &str| {
+        let (field_type, nullable) = match (entity, field) {
+            ("test", "date") => ("Date", false),
+            ("test", "created") => ("PrimitiveDateTime", true),
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "date_time".to_string(),
+            field_type: field_type.to_string(),
+            nullable,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        })
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check(
+        "SELECT t.date + interval\"1 day\" AS a, EXTRACT(\"year\", t.created) AS b, \
+        t.created - NOW() AS c FROM test t WHERE t.date > date\"2026-01-01\"",
+    )
+    .unwrap();
+
+    assert_eq!(
+        checked.result_types.get("a").map(|s| s.as_str()),
+        Some("Date")
+    );
+    assert_eq!(
+        checked.result_types.get("b").map(|s| s.as_str()),
+        Some("Option<i32>")
+    );
+    assert_eq!(
+        checked.result_types.get("c").map(|s| s.as_str()),
+        Some("Option<Duration>")
+    );
+
+    let checked =
+        check("SELECT t.date - interval\"1 week\" - interval\"1 day\" AS a FROM test t").unwrap();
+
+    assert_eq!(
+        checked.result_types.get("a").map(|s| s.as_str()),
+        Some("Date")
+    );
+
+    assert!(check("SELECT t.date AS a FROM test t WHERE t.date > date\"2026-13-01\"").is_err());
+    assert!(check("SELECT t.date AS a FROM test t WHERE t.date > time\"12:00:00\"").is_err());
+    assert!(check("SELECT DATE_TRUNC(\"week\", t.date) AS a FROM test t").is_ok());
+    assert!(check("SELECT DATE_TRUNC(\"fortnight\", t.date) AS a FROM test t").is_err());
+    assert!(check("SELECT t.date * 2 AS a FROM test t").is_err());
+
+    let path = |path: Path| DateTimeType::from_path(&path);
+
+    assert_eq!(path(parse_quote!(Date)), Some(DateTimeType::Date));
+    assert_eq!(path(parse_quote!(time::Time)), Some(DateTimeType::Time));
+    assert_eq!(path(parse_quote!(foo::Date)), None);
+    assert_eq!(path(parse_quote!(time::Duration)), None);
+}
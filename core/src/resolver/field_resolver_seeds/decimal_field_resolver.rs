@@ -0,0 +1,638 @@
+use crate::annotations::FieldAnnotation;
+use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{
+    Binary, BinaryOperator, ColumnIdent, Expr, Float, Integer, JoinClause, Literal, Locatable,
+    Location,
+};
+use crate::query::type_check::TypeKind;
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::field_resolver_seeds::bool_field_resolver::BoolTypeResolver;
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
+    FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, TypePathResolver, ValueConverter,
+};
+use crate::types::{
+    DatabaseType, DatabaseValue, ExprWrapper, IdentResolveStatus, TypeInfo, TypeResolver, ValuePack,
+};
+use heck::SnakeCase;
+use iroha::ToTokens;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use rust_decimal::Decimal;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use syn::Type;
+
+/// Max precision of `rust_decimal::Decimal`
+pub const DECIMAL_MAX_PRECISION: u32 = 28;
+
+/// Precision and scale of decimal, `DECIMAL(10, 0)` by default
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DecimalType {
+    pub precision: u32,
+    pub scale: u32,
+}
+
+impl DecimalType {
+    /// Create a decimal type, precision and scale will be clamped into the range of `Decimal`
+    pub fn new(precision: u32, scale: u32) -> Self {
+        let precision = precision.clamp(1, DECIMAL_MAX_PRECISION);
+
+        DecimalType {
+            precision,
+            scale: min(scale, precision),
+        }
+    }
+
+    pub fn from_options(options: &Option<HashMap<String, String>>) -> Result<Self, String> {
+        let get_option =
+            |name: &str, default: u32| match options.as_ref().and_then(|options| options.get(name))
+            {
+                Some(value) => value
+                    .parse::<u32>()
+                    .map_err(|_| format!("Invalid {} of decimal: {}", name, value)),
+                None => Ok(default),
+            };
+
+        let precision = get_option("precision", 10)?;
+        let scale = get_option("scale", 0)?;
+
+        if precision == 0 || precision > DECIMAL_MAX_PRECISION {
+            Err(format!(
+                "Precision of decimal must be between 1 and {}, got {}",
+                DECIMAL_MAX_PRECISION, precision
+            ))
+        } else if scale > precision {
+            Err(format!(
+                "Scale of decimal({}) can not be greater than precision({})",
+                scale, precision
+            ))
+        } else {
+            Ok(DecimalType { precision, scale })
+        }
+    }
+
+    pub fn integer_digits(&self) -> u32 {
+        self.precision - self.scale
+    }
+
+    /// Check whether the value can be stored without losing digits
+    pub fn contains(&self, value: &Decimal) -> bool {
+        value.round_dp(self.scale) == *value && integer_digits_of(value) <= self.integer_digits()
+    }
+
+    /// Type of result of arithmetic operation, `None` if operator is not supported
+    pub fn arithmetic(&self, others: &DecimalType, operator: BinaryOperator) -> Option<Self> {
+        let integer_digits = max(self.integer_digits(), others.integer_digits());
+        let scale = max(self.scale, others.scale);
+
+        match operator {
+            BinaryOperator::Plus | BinaryOperator::Minus => {
+                Some(DecimalType::new(integer_digits + scale + 1, scale))
+            }
+            BinaryOperator::Multi => Some(DecimalType::new(
+                self.precision + others.precision,
+                self.scale + others.scale,
+            )),
+            BinaryOperator::Div => Some(DecimalType::new(
+                self.integer_digits() + others.scale + self.scale + 4,
+                self.scale + 4,
+            )),
+            BinaryOperator::Mod => Some(DecimalType::new(integer_digits + scale, scale)),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for DecimalType {
+    type Err = ResolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix("Decimal(")
+            .and_then(|s| s.strip_suffix(')'))
+            .and_then(|s| {
+                let mut items = s.split(',').map(|item| item.trim().parse::<u32>());
+
+                match (items.next(), items.next(), items.next()) {
+                    (Some(Ok(precision)), Some(Ok(scale)), None) => {
+                        Some(DecimalType { precision, scale })
+                    }
+                    _ => None,
+                }
+            })
+            .ok_or(ResolveError::UnsupportedEntityStructType)
+    }
+}
+
+impl Display for DecimalType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Decimal({},{})", self.precision, self.scale)
+    }
+}
+
+fn integer_digits_of(value: &Decimal) -> u32 {
+    let integer = value.trunc().abs().to_string();
+
+    if integer == "0" {
+        0
+    } else {
+        integer.len() as u32
+    }
+}
+
+pub struct DecimalFieldResolverSeed;
+
+impl FieldResolverSeed for DecimalFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        DecimalFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(DecimalFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let (nullable, nested_type) = match Self::unwrap_option(
+            field_type,
+            (entity_name.clone(), ident.to_string()),
+            type_path_resolver,
+        ) {
+            Ok(r) => r,
+            Err(e) => return Some(Err(e)),
+        };
+
+        match &nested_type {
+            Type::Path(type_path)
+                if type_path
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident == *"Decimal")
+                    .unwrap_or(false) => {}
+            _ => return None,
+        };
+
+        let field = Self::default_annotations(annotations);
+
+        if field.auto_increase || Self::is_primary_key(annotations) {
+            return Some(Err(ResolveError::Others(format!(
+                "PrimaryKey or AutoIncrease is not supported on decimal field({0} in {1})",
+                ident, entity_name
+            ))));
+        }
+
+        let ty = match DecimalType::from_options(&field.options) {
+            Ok(ty) => ty,
+            Err(e) => {
+                return Some(Err(ResolveError::Others(format!(
+                    "{} on field({} in {})",
+                    e, ident, entity_name
+                ))))
+            }
+        };
+
+        Some(Ok(Box::new(DecimalFieldResolver {
+            field_path: (entity_name.clone(), ident.to_string()),
+            ty,
+            definition: ColumnDefinition {
+                name: field
+                    .name
+                    .unwrap_or_else(|| ident.to_string().to_snake_case()),
+                ty: ColumnType::NormalColumn(entity_name),
+                data_type: DatabaseType::Decimal(ty.precision, ty.scale),
+                unique: field.unique,
+                auto_increase: false,
+                primary_key: false,
+                nullable,
+                default: None,
+                charset: None,
+                collation: None,
+                enum_variants: vec![],
+            },
+            field_type: type_path_resolver.get_full_type(field_type.clone()),
+            nullable,
+            nested_type,
+        })))
+    }
+}
+
+pub struct DecimalFieldResolver {
+    field_path: FieldPath,
+    ty: DecimalType,
+    definition: ColumnDefinition,
+    field_type: Type,
+    nullable: bool,
+    nested_type: Type,
+}
+
+impl FieldResolver for DecimalFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        FieldResolverStatus::WaitingAssemble
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        _resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        _resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let method_name = self.converter_getter_ident();
+        let (entity_name, field_name) = self.field_path();
+
+        let converter = DecimalValueConverter {
+            is_primary_key: self.definition.primary_key,
+            column_name: self.definition.name.clone(),
+            entity_name,
+            field_name: field_name.clone(),
+            precision: self.ty.precision,
+            scale: self.ty.scale,
+        };
+
+        let data_converter_token_stream = quote! {
+            pub fn #method_name() -> yukino::resolver::field_resolver_seeds::DecimalValueConverter {
+                #converter
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", field_name);
+        let field_type = &self.field_type;
+        let nested_type = &self.nested_type;
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> #field_type {
+                let inner = self.get_inner();
+                inner.#field_ident
+            }
+        };
+        let field_setter_token_stream = if self.nullable {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= Some(value);
+                    self
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= value;
+                    self
+                }
+            }
+        };
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: vec![self.definition.clone()],
+            joined_table: vec![],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: method_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            field_setter_token_stream,
+            field_type: field_type.clone(),
+            field_definition: FieldDefinition {
+                entity: self.field_path.0.clone(),
+                name: self.field_path.1.clone(),
+                type_resolver_name: DecimalTypeResolver::seed().name(),
+                field_type: self.ty.to_string(),
+                nullable: self.nullable,
+                length: None,
+                default: None,
+                columns: vec![self.definition.name.clone()],
+                tables: vec![],
+                association: None,
+            },
+            nested_field_definitions: vec![],
+        })
+    }
+}
+
+#[derive(ToTokens)]
+#[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+pub struct DecimalValueConverter {
+    is_primary_key: bool,
+    column_name: String,
+    entity_name: String,
+    field_name: String,
+    precision: u32,
+    scale: u32,
+}
+
+impl DecimalValueConverter {
+    /// Check the value fits the column, trailing zeros beyond scale are dropped
+    fn enforce_scale(&self, value: &Decimal) -> Result<Decimal, DataConvertError> {
+        let ty = DecimalType {
+            precision: self.precision,
+            scale: self.scale,
+        };
+
+        if ty.contains(value) {
+            Ok(value.round_dp(self.scale))
+        } else {
+            Err(DataConvertError::DatabaseValueConvertError(
+                format!("{} can not be stored as {}", value, ty),
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            ))
+        }
+    }
+}
+
+impl ValueConverter<Decimal> for DecimalValueConverter {
+    fn to_field_value(&self, values: &ValuePack) -> Result<Decimal, DataConvertError> {
+        match values.get(&self.column_name) {
+            Some(DatabaseValue::Decimal(value)) => Ok(*value),
+            _ => Err(DataConvertError::UnexpectedDatabaseValueType(
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )),
+        }
+    }
+
+    fn to_database_values_by_ref(&self, value: &Decimal) -> Result<ValuePack, DataConvertError> {
+        let mut map = HashMap::new();
+        map.insert(
+            self.column_name.clone(),
+            DatabaseValue::Decimal(self.enforce_scale(value)?),
+        );
+
+        Ok(map)
+    }
+
+    fn primary_column_values_by_ref(
+        &self,
+        _value: &Decimal,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+impl ValueConverter<Option<Decimal>> for DecimalValueConverter {
+    fn to_field_value(&self, values: &ValuePack) -> Result<Option<Decimal>, DataConvertError> {
+        match values.get(&self.column_name) {
+            Some(DatabaseValue::Decimal(value)) => Ok(Some(*value)),
+            Some(DatabaseValue::Null(DatabaseType::Decimal(_, _))) => Ok(None),
+            _ => Err(DataConvertError::UnexpectedDatabaseValueType(
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )),
+        }
+    }
+
+    fn to_database_values_by_ref(
+        &self,
+        value: &Option<Decimal>,
+    ) -> Result<ValuePack, DataConvertError> {
+        let mut map = HashMap::new();
+        map.insert(
+            self.column_name.clone(),
+            match value {
+                Some(v) => DatabaseValue::Decimal(self.enforce_scale(v)?),
+                None => DatabaseValue::Null(DatabaseType::Decimal(self.precision, self.scale)),
+            },
+        );
+
+        Ok(map)
+    }
+
+    fn primary_column_values_by_ref(
+        &self,
+        _value: &Option<Decimal>,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+pub struct DecimalTypeResolver;
+
+impl DecimalTypeResolver {
+    fn decimal_type(
+        type_info: &TypeInfo,
+        location: Location,
+    ) -> Result<DecimalType, SyntaxErrorWithPos> {
+        DecimalType::from_str(type_info.field_type.as_str()).map_err(|_| {
+            location.error(SyntaxError::TypeError(
+                "Decimal".to_string(),
+                type_info.field_type.clone(),
+            ))
+        })
+    }
+}
+
+impl TypeResolver for DecimalTypeResolver {
+    fn seed() -> Box<dyn TypeResolver> {
+        Box::new(DecimalTypeResolver)
+    }
+
+    fn name(&self) -> String {
+        "decimal".to_string()
+    }
+
+    /// Decimals with different precision or scale can be operated together
+    fn cmp_type_info(&self, a: &TypeInfo, b: &TypeInfo) -> bool {
+        a.resolver_name == b.resolver_name
+    }
+
+    fn is_assignable(&self, target: &TypeInfo, value: &TypeInfo) -> bool {
+        match (
+            DecimalType::from_str(target.field_type.as_str()),
+            DecimalType::from_str(value.field_type.as_str()),
+        ) {
+            (Ok(target), Ok(value)) => {
+                target.scale >= value.scale && target.integer_digits() >= value.integer_digits()
+            }
+            _ => false,
+        }
+    }
+
+    fn wrap_lit(
+        &self,
+        lit: &Literal,
+        type_info: TypeInfo,
+    ) -> Result<(ExprWrapper, Vec<(String, String)>), SyntaxErrorWithPos> {
+        let decimal_type = Self::decimal_type(&type_info, lit.location())?;
+
+        match lit {
+            Literal::Integer(Integer { value, .. }) | Literal::Float(Float { value, .. }) => {
+                match Decimal::from_str(value) {
+                    Ok(decimal) if decimal_type.contains(&decimal) => Ok((
+                        ExprWrapper {
+                            exprs: vec![Expr::Literal(lit.clone())],
+                            type_info,
+                            location: lit.location(),
+                        },
+                        vec![],
+                    )),
+                    _ => Err(lit
+                        .location()
+                        .error(SyntaxError::LitOverflow(decimal_type.to_string()))),
+                }
+            }
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(
+                    external.ident.clone(),
+                    if type_info.nullable {
+                        "Option<Decimal>".to_string()
+                    } else {
+                        "Decimal".to_string()
+                    },
+                )],
+            )),
+            Literal::Null(_) if type_info.nullable => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info,
+                    location: lit.location(),
+                },
+                vec![],
+            )),
+            _ => Err(lit.location().error(SyntaxError::TypeError(
+                type_info.to_string(),
+                TypeKind::from(lit).to_string(),
+            ))),
+        }
+    }
+
+    fn wrap_ident(
+        &self,
+        ident: &ColumnIdent,
+        field_definition: &FieldDefinition,
+    ) -> Result<(IdentResolveStatus, Vec<JoinClause>), SyntaxErrorWithPos> {
+        let type_info = TypeInfo {
+            resolver_name: self.name(),
+            field_type: field_definition.field_type.clone(),
+            nullable: field_definition.nullable,
+            length: None,
+            type_kind: TypeKind::Numeric,
+        };
+        Self::decimal_type(&type_info, ident.location())?;
+
+        Ok((
+            IdentResolveStatus::Resolved(ExprWrapper {
+                exprs: vec![Expr::ColumnIdent(ident.clone())],
+                type_info,
+                location: ident.location(),
+            }),
+            vec![],
+        ))
+    }
+
+    fn handle_binary(
+        &self,
+        mut left: ExprWrapper,
+        mut right: ExprWrapper,
+        location: Location,
+        operator: BinaryOperator,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        let left_ty = Self::decimal_type(&left.type_info, left.location)?;
+        let right_ty = Self::decimal_type(&right.type_info, right.location)?;
+        let nullable = left.type_info.nullable || right.type_info.nullable;
+
+        let type_info = if operator.is_cmp() {
+            TypeInfo {
+                resolver_name: BoolTypeResolver::seed().name(),
+                field_type: "bool".to_string(),
+                nullable,
+                length: None,
+                type_kind: TypeKind::Boolean,
+            }
+        } else {
+            let ty = left_ty.arithmetic(&right_ty, operator).ok_or_else(|| {
+                location.error(SyntaxError::UnimplementedOperationForType(
+                    format!("{:?}", operator),
+                    left.type_info.to_string(),
+                ))
+            })?;
+
+            TypeInfo {
+                resolver_name: self.name(),
+                field_type: ty.to_string(),
+                nullable,
+                length: None,
+                type_kind: TypeKind::Numeric,
+            }
+        };
+
+        Ok(ExprWrapper {
+            exprs: vec![Expr::Binary(Binary {
+                operator,
+                left: Box::new(left.exprs.pop().unwrap()),
+                right: Box::new(right.exprs.pop().unwrap()),
+                location,
+            })],
+            type_info,
+            location,
+        })
+    }
+}
+
+#[test]
+fn test_decimal_type() {
+    let ty = DecimalType::new(10, 2);
+
+    assert!(ty.contains(&Decimal::from_str("12345678.9").unwrap()));
+    assert!(ty.contains(&Decimal::from_str("-0.50").unwrap()));
+    assert!(!ty.contains(&Decimal::from_str("0.125").unwrap()));
+    assert!(!ty.contains(&Decimal::from_str("123456789").unwrap()));
+
+    assert_eq!(
+        ty.arithmetic(&DecimalType::new(5, 4), BinaryOperator::Plus),
+        Some(DecimalType::new(13, 4))
+    );
+    assert_eq!(
+        ty.arithmetic(&DecimalType::new(5, 4), BinaryOperator::Multi),
+        Some(DecimalType::new(15, 6))
+    );
+    assert_eq!(ty.arithmetic(&ty, BinaryOperator::BitAnd), None);
+    assert_eq!(DecimalType::from_str(&ty.to_string()).ok(), Some(ty));
+
+    let mut options = HashMap::new();
+    options.insert("precision".to_string(), "4".to_string());
+    options.insert("scale".to_string(), "6".to_string());
+    assert!(DecimalType::from_options(&Some(options)).is_err());
+    assert_eq!(
+        DecimalType::from_options(&None),
+        Ok(DecimalType::new(10, 0))
+    );
+}
@@ -0,0 +1,379 @@
+use crate::annotations::FieldAnnotation;
+use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{ColumnIdent, Expr, JoinClause, Literal, Locatable};
+use crate::query::type_check::TypeKind;
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::{
+    AchievedFieldResolver, Embeddable, EntityName, EntityResolver, FieldPath, FieldResolver,
+    FieldResolverBox, FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus,
+    TypePathResolver, ValueConverter,
+};
+use crate::types::{ExprWrapper, IdentResolveStatus, TypeInfo, TypeResolver, ValuePack};
+use heck::SnakeCase;
+use iroha::ToTokens;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use syn::Type;
+
+pub struct EmbeddedFieldResolverSeed;
+
+impl FieldResolverSeed for EmbeddedFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        EmbeddedFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(EmbeddedFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let embedded = annotations
+            .iter()
+            .filter_map(|attr| match attr {
+                FieldAnnotation::Embedded(embedded) => Some(embedded),
+                _ => None,
+            })
+            .next();
+
+        let full_type = type_path_resolver.get_full_type(field_type.clone());
+        let embeddable = match (type_path_resolver.get_embeddable(&full_type), embedded) {
+            (Some(embeddable), _) => embeddable,
+            (None, Some(_)) => {
+                return Some(Err(ResolveError::Others(format!(
+                    "Type of embedded field({0} in {1}) must be a embeddable struct",
+                    ident, entity_name
+                ))))
+            }
+            (None, None) => return None,
+        };
+
+        if Self::is_primary_key(annotations) {
+            return Some(Err(ResolveError::Others(format!(
+                "PrimaryKey is not supported on embedded field({0} in {1})",
+                ident, entity_name
+            ))));
+        }
+
+        let field_name = ident.to_string();
+        let prefix = embedded
+            .and_then(|embedded| embedded.prefix.clone())
+            .unwrap_or_else(|| format!("{}_", field_name.to_snake_case()));
+        let prefixed = |columns: &[String]| -> Vec<String> {
+            columns
+                .iter()
+                .map(|column| format!("{}{}", prefix, column))
+                .collect()
+        };
+
+        let columns = embeddable
+            .fields
+            .iter()
+            .flat_map(|resolver| resolver.columns.iter())
+            .map(|column| ColumnDefinition {
+                name: format!("{}{}", prefix, column.name),
+                ty: ColumnType::NormalColumn(entity_name.clone()),
+                ..column.clone()
+            })
+            .collect();
+
+        let nested_field_definitions = embeddable
+            .fields
+            .iter()
+            .flat_map(|resolver| {
+                std::iter::once((&resolver.field_path.1, &resolver.field_definition)).chain(
+                    resolver
+                        .nested_field_definitions
+                        .iter()
+                        .map(|definition| (&definition.name, definition)),
+                )
+            })
+            .map(|(name, definition)| FieldDefinition {
+                entity: entity_name.clone(),
+                name: format!("{}.{}", field_name, name),
+                columns: prefixed(&definition.columns),
+                ..definition.clone()
+            })
+            .collect();
+
+        Some(Ok(Box::new(EmbeddedFieldResolver {
+            field_path: (entity_name, field_name),
+            prefix,
+            columns,
+            nested_field_definitions,
+            field_type: full_type,
+        })))
+    }
+}
+
+pub struct EmbeddedFieldResolver {
+    field_path: FieldPath,
+    prefix: String,
+    columns: Vec<ColumnDefinition>,
+    nested_field_definitions: Vec<FieldDefinition>,
+    field_type: Type,
+}
+
+impl FieldResolver for EmbeddedFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        FieldResolverStatus::WaitingAssemble
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        _resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        _resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let method_name = self.converter_getter_ident();
+        let (entity_name, field_name) = self.field_path();
+
+        let converter = EmbeddedValueConverter {
+            entity_name,
+            field_name: field_name.clone(),
+            prefix: self.prefix.clone(),
+        };
+
+        let data_converter_token_stream = quote! {
+            pub fn #method_name() -> yukino::resolver::field_resolver_seeds::EmbeddedValueConverter {
+                #converter
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", field_name);
+        let field_type = &self.field_type;
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> &#field_type {
+                let inner = self.get_inner();
+                &inner.#field_ident
+            }
+        };
+        let field_setter_token_stream = quote! {
+            pub fn #setter_name(&mut self, value: #field_type) -> &mut Self {
+                let inner = self.get_inner_mut();
+                inner.#field_ident= value;
+                self
+            }
+        };
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: self.columns.clone(),
+            joined_table: vec![],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: method_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            field_setter_token_stream,
+            field_type: field_type.clone(),
+            field_definition: FieldDefinition {
+                entity: self.field_path.0.clone(),
+                name: self.field_path.1.clone(),
+                type_resolver_name: EmbeddedTypeResolver::seed().name(),
+                field_type: quote!(#field_type).to_string(),
+                nullable: false,
+                length: None,
+                default: None,
+                columns: self
+                    .columns
+                    .iter()
+                    .map(|column| column.name.clone())
+                    .collect(),
+                tables: vec![],
+                association: None,
+            },
+            nested_field_definitions: self.nested_field_definitions.clone(),
+        })
+    }
+}
+
+/// Value converter of embedded field, columns of embeddable struct are prefixed
+#[derive(ToTokens)]
+#[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+pub struct EmbeddedValueConverter {
+    entity_name: String,
+    field_name: String,
+    prefix: String,
+}
+
+impl<T: Embeddable> ValueConverter<T> for EmbeddedValueConverter {
+    fn to_field_value(&self, values: &ValuePack) -> Result<T, DataConvertError> {
+        let values = values
+            .iter()
+            .filter_map(|(column, value)| {
+                column
+                    .strip_prefix(&self.prefix)
+                    .map(|column| (column.to_string(), value.clone()))
+            })
+            .collect();
+
+        T::from_database_values(&values)
+    }
+
+    fn to_database_values_by_ref(&self, value: &T) -> Result<ValuePack, DataConvertError> {
+        Ok(value
+            .to_database_values()?
+            .into_iter()
+            .map(|(column, value)| (format!("{}{}", self.prefix, column), value))
+            .collect())
+    }
+
+    fn primary_column_values_by_ref(&self, _value: &T) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+pub struct EmbeddedTypeResolver;
+
+impl TypeResolver for EmbeddedTypeResolver {
+    fn seed() -> Box<dyn TypeResolver> {
+        Box::new(EmbeddedTypeResolver)
+    }
+
+    fn name(&self) -> String {
+        "embedded".to_string()
+    }
+
+    fn wrap_lit(
+        &self,
+        lit: &Literal,
+        type_info: TypeInfo,
+    ) -> Result<(ExprWrapper, Vec<(String, String)>), SyntaxErrorWithPos> {
+        match lit {
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(external.ident.clone(), type_info.field_type)],
+            )),
+            _ => Err(lit.location().error(SyntaxError::TypeError(
+                type_info.to_string(),
+                TypeKind::from(lit).to_string(),
+            ))),
+        }
+    }
+
+    /// `o.shipping.city` will be resolved as nested field `shipping.city` of `o`
+    fn wrap_ident(
+        &self,
+        ident: &ColumnIdent,
+        field_definition: &FieldDefinition,
+    ) -> Result<(IdentResolveStatus, Vec<JoinClause>), SyntaxErrorWithPos> {
+        if ident.segments.len() > 2 {
+            let mut segments = ident.segments.clone();
+            let nested = segments.remove(2);
+            segments[1] = format!("{}.{}", segments[1], nested);
+
+            Ok((
+                IdentResolveStatus::Unresolved(ColumnIdent {
+                    segments,
+                    location: ident.location(),
+                }),
+                vec![],
+            ))
+        } else {
+            Ok((
+                IdentResolveStatus::Resolved(ExprWrapper {
+                    exprs: vec![Expr::ColumnIdent(ident.clone())],
+                    type_info: TypeInfo {
+                        resolver_name: self.name(),
+                        field_type: field_definition.field_type.clone(),
+                        nullable: field_definition.nullable,
+                        length: None,
+                        type_kind: TypeKind::Object(field_definition.field_type.clone()),
+                    },
+                    location: ident.location(),
+                }),
+                vec![],
+            ))
+        }
+    }
+}
+
+#[test]
+fn test_embedded_type_check() {
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let definition_getter = |entity: &str, field: &str| {
+        let (type_resolver_name, field_type, columns) = match (entity, field) {
+            ("test", "shipping") => ("embedded", "Address", vec!["shipping_city", "shipping_zip"]),
+            ("test", "shipping.city") => ("string", "String", vec!["shipping_city"]),
+            ("test", "shipping.zip") => ("numeric", "u32", vec!["shipping_zip"]),
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: type_resolver_name.to_string(),
+            field_type: field_type.to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: columns.into_iter().map(|c| c.to_string()).collect(),
+            tables: vec![],
+            association: None,
+        })
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check(
+        "SELECT t.shipping.city AS a, t.shipping.zip + 1 AS b FROM test t \
+        WHERE t.shipping.city = \"Tokyo\"",
+    )
+    .unwrap();
+
+    assert_eq!(
+        checked.result_types.get("a").map(|s| s.as_str()),
+        Some("String")
+    );
+    assert_eq!(
+        checked.result_types.get("b").map(|s| s.as_str()),
+        Some("u32")
+    );
+
+    assert!(check("SELECT t.shipping.street AS a FROM test t").is_err());
+    assert!(check("SELECT t.shipping AS a FROM test t WHERE t.shipping = 1").is_err());
+}
@@ -0,0 +1,609 @@
+use crate::annotations::{EnumStorage, FieldAnnotation};
+use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{
+    Binary, BinaryOperator, ColumnIdent, Expr, Integer, JoinClause, Literal, Locatable, Location,
+};
+use crate::query::type_check::TypeKind;
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::field_resolver_seeds::bool_field_resolver::BoolTypeResolver;
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, EnumDefinition, EnumLike, FieldPath,
+    FieldResolver, FieldResolverBox, FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus,
+    TypePathResolver, ValueConverter,
+};
+use crate::types::{
+    DatabaseValue, ExprWrapper, IdentResolveStatus, TypeInfo, TypeResolver, ValuePack,
+};
+use heck::SnakeCase;
+use iroha::ToTokens;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use syn::Type;
+
+/// Field type of enum in type check, e.g. `Status(integer: Active = 0, Inactive = 1)`
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct EnumType {
+    pub name: String,
+    pub storage: EnumStorage,
+    pub variants: Vec<(String, i32)>,
+}
+
+impl EnumType {
+    pub fn discriminant(&self, variant: &str) -> Option<i32> {
+        self.variants
+            .iter()
+            .find(|(name, _)| name == variant)
+            .map(|(_, discriminant)| *discriminant)
+    }
+}
+
+impl From<&EnumDefinition> for EnumType {
+    fn from(definition: &EnumDefinition) -> Self {
+        EnumType {
+            name: definition.ident.to_string(),
+            storage: definition.storage,
+            variants: definition
+                .variants
+                .iter()
+                .map(|(ident, discriminant)| (ident.to_string(), *discriminant))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for EnumType {
+    type Err = ResolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ResolveError::ParseError(format!("Invalid enum type: {}", s));
+
+        let (name, rest) = s.split_once('(').ok_or_else(error)?;
+        let (storage, variants) = rest
+            .strip_suffix(')')
+            .and_then(|rest| rest.split_once(": "))
+            .ok_or_else(error)?;
+
+        Ok(EnumType {
+            name: name.to_string(),
+            storage: match storage {
+                "string" => EnumStorage::String,
+                "integer" => EnumStorage::Integer,
+                "native" => EnumStorage::Native,
+                _ => return Err(error()),
+            },
+            variants: variants
+                .split(", ")
+                .map(|variant| {
+                    variant
+                        .split_once(" = ")
+                        .and_then(|(name, discriminant)| {
+                            discriminant
+                                .parse()
+                                .ok()
+                                .map(|discriminant| (name.to_string(), discriminant))
+                        })
+                        .ok_or_else(error)
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Display for EnumType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "{}({}: {})",
+            self.name,
+            match self.storage {
+                EnumStorage::String => "string",
+                EnumStorage::Integer => "integer",
+                EnumStorage::Native => "native",
+            },
+            self.variants
+                .iter()
+                .map(|(name, discriminant)| format!("{} = {}", name, discriminant))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+pub struct EnumFieldResolverSeed;
+
+impl FieldResolverSeed for EnumFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        EnumFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(EnumFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let (nullable, nested_type) = match Self::unwrap_option(
+            field_type,
+            (entity_name.clone(), ident.to_string()),
+            type_path_resolver,
+        ) {
+            Ok(r) => r,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let ty = EnumType::from(type_path_resolver.get_enum(&nested_type)?);
+        let field = Self::default_annotations(annotations);
+
+        if field.auto_increase || Self::is_primary_key(annotations) {
+            return Some(Err(ResolveError::Others(format!(
+                "PrimaryKey or AutoIncrease is not supported on enum field({0} in {1})",
+                ident, entity_name
+            ))));
+        }
+
+        Some(Ok(Box::new(EnumFieldResolver {
+            field_path: (entity_name.clone(), ident.to_string()),
+            definition: ColumnDefinition {
+                name: field
+                    .name
+                    .unwrap_or_else(|| ident.to_string().to_snake_case()),
+                ty: ColumnType::NormalColumn(entity_name),
+                data_type: ty.storage.database_type(),
+                unique: field.unique,
+                auto_increase: false,
+                primary_key: false,
+                nullable,
+                default: None,
+                charset: None,
+                collation: None,
+                enum_variants: match ty.storage {
+                    EnumStorage::Native => {
+                        ty.variants.iter().map(|(name, _)| name.clone()).collect()
+                    }
+                    _ => vec![],
+                },
+            },
+            ty,
+            field_type: type_path_resolver.get_full_type(field_type.clone()),
+            nullable,
+            nested_type,
+        })))
+    }
+}
+
+pub struct EnumFieldResolver {
+    field_path: FieldPath,
+    ty: EnumType,
+    definition: ColumnDefinition,
+    field_type: Type,
+    nullable: bool,
+    nested_type: Type,
+}
+
+impl FieldResolver for EnumFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        FieldResolverStatus::WaitingAssemble
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        _resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        _resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let method_name = self.converter_getter_ident();
+        let (entity_name, field_name) = self.field_path();
+
+        let converter = EnumValueConverter {
+            entity_name,
+            field_name: field_name.clone(),
+            column_name: self.definition.name.clone(),
+            storage: self.ty.storage,
+        };
+
+        let data_converter_token_stream = quote! {
+            pub fn #method_name() -> yukino::resolver::field_resolver_seeds::EnumValueConverter {
+                #converter
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", field_name);
+        let field_type = &self.field_type;
+        let nested_type = &self.nested_type;
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> &#field_type {
+                let inner = self.get_inner();
+                &inner.#field_ident
+            }
+        };
+        let field_setter_token_stream = if self.nullable {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= Some(value);
+                    self
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= value;
+                    self
+                }
+            }
+        };
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: vec![self.definition.clone()],
+            joined_table: vec![],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: method_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            field_setter_token_stream,
+            field_type: field_type.clone(),
+            field_definition: FieldDefinition {
+                entity: self.field_path.0.clone(),
+                name: self.field_path.1.clone(),
+                type_resolver_name: EnumTypeResolver::seed().name(),
+                field_type: self.ty.to_string(),
+                nullable: self.nullable,
+                length: None,
+                default: None,
+                columns: vec![self.definition.name.clone()],
+                tables: vec![],
+                association: None,
+            },
+            nested_field_definitions: vec![],
+        })
+    }
+}
+
+#[derive(ToTokens)]
+#[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+pub struct EnumValueConverter {
+    entity_name: String,
+    field_name: String,
+    column_name: String,
+    storage: EnumStorage,
+}
+
+impl EnumValueConverter {
+    /// Unknown names or discriminants are rejected instead of falling back to a variant
+    fn decode<T: EnumLike>(&self, value: &DatabaseValue) -> Result<T, DataConvertError> {
+        let decoded = match (self.storage, value) {
+            (EnumStorage::Integer, DatabaseValue::Integer(discriminant)) => {
+                T::from_discriminant(*discriminant).ok_or_else(|| discriminant.to_string())
+            }
+            (EnumStorage::String, DatabaseValue::String(name))
+            | (EnumStorage::Native, DatabaseValue::String(name)) => {
+                T::from_variant(name).ok_or_else(|| name.clone())
+            }
+            _ => {
+                return Err(DataConvertError::UnexpectedDatabaseValueType(
+                    self.entity_name.clone(),
+                    self.field_name.clone(),
+                ))
+            }
+        };
+
+        decoded.map_err(|value| {
+            DataConvertError::DatabaseValueConvertError(
+                format!("Unknown variant: {}", value),
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )
+        })
+    }
+
+    fn encode<T: EnumLike>(&self, value: &T) -> DatabaseValue {
+        match self.storage {
+            EnumStorage::Integer => DatabaseValue::Integer(value.discriminant()),
+            _ => DatabaseValue::String(value.variant().to_string()),
+        }
+    }
+}
+
+impl<T: EnumLike> ValueConverter<T> for EnumValueConverter {
+    fn to_field_value(&self, values: &ValuePack) -> Result<T, DataConvertError> {
+        match values.get(&self.column_name) {
+            Some(value) => self.decode(value),
+            None => Err(DataConvertError::UnexpectedDatabaseValueType(
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )),
+        }
+    }
+
+    fn to_database_values_by_ref(&self, value: &T) -> Result<ValuePack, DataConvertError> {
+        let mut map = HashMap::new();
+        map.insert(self.column_name.clone(), self.encode(value));
+
+        Ok(map)
+    }
+
+    fn primary_column_values_by_ref(&self, _value: &T) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+impl<T: EnumLike> ValueConverter<Option<T>> for EnumValueConverter {
+    fn to_field_value(&self, values: &ValuePack) -> Result<Option<T>, DataConvertError> {
+        match values.get(&self.column_name) {
+            Some(DatabaseValue::Null(ty)) if *ty == self.storage.database_type() => Ok(None),
+            Some(value) => self.decode(value).map(Some),
+            None => Err(DataConvertError::UnexpectedDatabaseValueType(
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )),
+        }
+    }
+
+    fn to_database_values_by_ref(&self, value: &Option<T>) -> Result<ValuePack, DataConvertError> {
+        let mut map = HashMap::new();
+        map.insert(
+            self.column_name.clone(),
+            match value {
+                Some(v) => self.encode(v),
+                None => DatabaseValue::Null(self.storage.database_type()),
+            },
+        );
+
+        Ok(map)
+    }
+
+    fn primary_column_values_by_ref(
+        &self,
+        _value: &Option<T>,
+    ) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+pub struct EnumTypeResolver;
+
+impl TypeResolver for EnumTypeResolver {
+    fn seed() -> Box<dyn TypeResolver> {
+        Box::new(EnumTypeResolver)
+    }
+
+    fn name(&self) -> String {
+        "enum".to_string()
+    }
+
+    /// Variant literal is a string literal of variant name, e.g. `"Active"`
+    fn wrap_lit(
+        &self,
+        lit: &Literal,
+        type_info: TypeInfo,
+    ) -> Result<(ExprWrapper, Vec<(String, String)>), SyntaxErrorWithPos> {
+        let ty = EnumType::from_str(&type_info.field_type).map_err(|_| {
+            lit.location().error(SyntaxError::TypeError(
+                type_info.to_string(),
+                TypeKind::from(lit).to_string(),
+            ))
+        })?;
+
+        match lit {
+            Literal::String(variant) => {
+                let discriminant = ty.discriminant(&variant.value).ok_or_else(|| {
+                    lit.location().error(SyntaxError::InvalidLiteral(
+                        variant.value.clone(),
+                        ty.name.clone(),
+                    ))
+                })?;
+
+                let location = lit.location();
+                let lit = match ty.storage {
+                    EnumStorage::Integer => Literal::Integer(Integer {
+                        value: discriminant.to_string(),
+                        location,
+                    }),
+                    _ => lit.clone(),
+                };
+
+                Ok((
+                    ExprWrapper {
+                        exprs: vec![Expr::Literal(lit)],
+                        type_info: TypeInfo {
+                            nullable: false,
+                            ..type_info
+                        },
+                        location,
+                    },
+                    vec![],
+                ))
+            }
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(
+                    external.ident.clone(),
+                    if type_info.nullable {
+                        format!("Option<{}>", ty.name)
+                    } else {
+                        ty.name
+                    },
+                )],
+            )),
+            Literal::Null(_) if type_info.nullable => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info,
+                    location: lit.location(),
+                },
+                vec![],
+            )),
+            _ => Err(lit.location().error(SyntaxError::TypeError(
+                type_info.to_string(),
+                TypeKind::from(lit).to_string(),
+            ))),
+        }
+    }
+
+    fn wrap_ident(
+        &self,
+        ident: &ColumnIdent,
+        field_definition: &FieldDefinition,
+    ) -> Result<(IdentResolveStatus, Vec<JoinClause>), SyntaxErrorWithPos> {
+        Ok((
+            IdentResolveStatus::Resolved(ExprWrapper {
+                exprs: vec![Expr::ColumnIdent(ident.clone())],
+                type_info: TypeInfo {
+                    resolver_name: self.name(),
+                    field_type: field_definition.field_type.clone(),
+                    nullable: field_definition.nullable,
+                    length: None,
+                    type_kind: TypeKind::Enum,
+                },
+                location: ident.location(),
+            }),
+            vec![],
+        ))
+    }
+
+    fn handle_binary(
+        &self,
+        mut left: ExprWrapper,
+        mut right: ExprWrapper,
+        location: Location,
+        operator: BinaryOperator,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if matches!(operator, BinaryOperator::Eq | BinaryOperator::Neq) {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Binary(Binary {
+                    operator,
+                    left: Box::new(left.exprs.pop().unwrap()),
+                    right: Box::new(right.exprs.pop().unwrap()),
+                    location,
+                })],
+                type_info: TypeInfo {
+                    resolver_name: BoolTypeResolver::seed().name(),
+                    field_type: "bool".to_string(),
+                    nullable: left.type_info.nullable || right.type_info.nullable,
+                    length: None,
+                    type_kind: TypeKind::Boolean,
+                },
+                location,
+            })
+        } else {
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                format!("{:?}", operator),
+                left.type_info.to_string(),
+            )))
+        }
+    }
+}
+
+#[test]
+fn test_enum_type() {
+    let ty = EnumType {
+        name: "Status".to_string(),
+        storage: EnumStorage::Integer,
+        variants: vec![("Active".to_string(), 1), ("Deleted".to_string(), -1)],
+    };
+
+    assert_eq!(ty.to_string(), "Status(integer: Active = 1, Deleted = -1)");
+    assert_eq!(EnumType::from_str(&ty.to_string()).ok(), Some(ty.clone()));
+    assert_eq!(ty.discriminant("Deleted"), Some(-1));
+    assert_eq!(ty.discriminant("Inactive"), None);
+
+    assert!(EnumType::from_str("Status").is_err());
+    assert!(EnumType::from_str("Status(tinyint: Active = 1)").is_err());
+}
+
+#[test]
+fn test_enum_type_check() {
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let definition_getter = |entity: &str, field: &str| {
+        let (field_type, nullable) = match (entity, field) {
+            ("test", "status") => ("Status(integer: Active = 1, Inactive = 2)", false),
+            ("test", "level") => ("Level(string: Low = 0, High = 1)", true),
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "enum".to_string(),
+            field_type: field_type.to_string(),
+            nullable,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        })
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check(
+        "SELECT t.status = \"Active\" AS a FROM test t WHERE t.level != \"High\" AND t.status = $status",
+    )
+    .unwrap();
+
+    assert_eq!(
+        checked.result_types.get("a").map(|s| s.as_str()),
+        Some("bool")
+    );
+    assert_eq!(
+        checked
+            .external_value_assertion
+            .get("status")
+            .map(|s| s.as_str()),
+        Some("Status")
+    );
+
+    assert!(check("SELECT t.status AS a FROM test t WHERE t.status = \"Deleted\"").is_err());
+    assert!(check("SELECT t.status AS a FROM test t WHERE t.status = 1").is_err());
+    assert!(check("SELECT t.status AS a FROM test t WHERE t.status = t.level").is_err());
+    assert!(check("SELECT t.status AS a FROM test t WHERE t.status > \"Active\"").is_err());
+}
@@ -0,0 +1,617 @@
+use crate::annotations::FieldAnnotation;
+use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
+use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
+use crate::query::ast::{
+    Binary, BinaryOperator, ColumnIdent, Expr, FunctionCall, JoinClause, JsonAccess, Literal,
+    Locatable, Location,
+};
+use crate::query::type_check::TypeKind;
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::field_resolver_seeds::bool_field_resolver::BoolTypeResolver;
+use crate::resolver::field_resolver_seeds::numeric_field_resolver::NumericTypeResolver;
+use crate::resolver::field_resolver_seeds::string_field_resolver::StringTypeResolver;
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
+    FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, TypePathResolver, ValueConverter,
+};
+use crate::types::{
+    DatabaseType, DatabaseValue, ExprWrapper, IdentResolveStatus, TypeInfo, TypeResolver, ValuePack,
+};
+use heck::SnakeCase;
+use iroha::ToTokens;
+use proc_macro2::Ident;
+use quote::{format_ident, quote, ToTokens as QuoteToTokens};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{from_value, to_value, Value};
+use std::collections::HashMap;
+use syn::Type;
+
+/// Field type of JSON value which is not a field, e.g. literal or result of path access
+pub const JSON_VALUE_TYPE: &str = "Value";
+
+pub struct JsonFieldResolverSeed;
+
+impl JsonFieldResolverSeed {
+    fn is_json_value(ty: &Type) -> bool {
+        match ty {
+            Type::Path(type_path) => {
+                let segments = &type_path.path.segments;
+
+                segments.len() == 2
+                    && segments.first().unwrap().ident == *"serde_json"
+                    && segments.last().unwrap().ident == *"Value"
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FieldResolverSeed for JsonFieldResolverSeed {
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        JsonFieldResolverSeed
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(JsonFieldResolverSeed)
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let (nullable, nested_type) = match Self::unwrap_option(
+            field_type,
+            (entity_name.clone(), ident.to_string()),
+            type_path_resolver,
+        ) {
+            Ok(r) => r,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let field = Self::default_annotations(annotations);
+        let json_option = field
+            .options
+            .as_ref()
+            .and_then(|options| options.get("json"))
+            .map(|json| json == "true")
+            .unwrap_or(false);
+
+        if !json_option && !Self::is_json_value(&nested_type) {
+            return None;
+        }
+
+        if field.unique || field.auto_increase || Self::is_primary_key(annotations) {
+            return Some(Err(ResolveError::Others(format!(
+                "PrimaryKey Unique or AutoIncrease is not supported on json field({0} in {1})",
+                ident, entity_name
+            ))));
+        }
+
+        Some(Ok(Box::new(JsonFieldResolver {
+            field_path: (entity_name.clone(), ident.to_string()),
+            definition: ColumnDefinition {
+                name: field
+                    .name
+                    .unwrap_or_else(|| ident.to_string().to_snake_case()),
+                ty: ColumnType::NormalColumn(entity_name),
+                data_type: DatabaseType::Json,
+                unique: false,
+                auto_increase: false,
+                primary_key: false,
+                nullable,
+                default: None,
+                charset: None,
+                collation: None,
+                enum_variants: vec![],
+            },
+            field_type: type_path_resolver.get_full_type(field_type.clone()),
+            nullable,
+            nested_type,
+        })))
+    }
+}
+
+pub struct JsonFieldResolver {
+    field_path: FieldPath,
+    definition: ColumnDefinition,
+    field_type: Type,
+    nullable: bool,
+    nested_type: Type,
+}
+
+impl FieldResolver for JsonFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        FieldResolverStatus::WaitingAssemble
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.field_path.clone()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        _resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        _resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        unreachable!()
+    }
+
+    fn assemble(
+        &mut self,
+        _entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let method_name = self.converter_getter_ident();
+        let (entity_name, field_name) = self.field_path();
+
+        let converter = JsonValueConverter {
+            entity_name,
+            field_name: field_name.clone(),
+            column_name: self.definition.name.clone(),
+            nullable: self.nullable,
+        };
+
+        let data_converter_token_stream = quote! {
+            pub fn #method_name() -> yukino::resolver::field_resolver_seeds::JsonValueConverter {
+                #converter
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", field_name);
+        let field_type = &self.field_type;
+        let nested_type = &self.nested_type;
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> &#field_type {
+                let inner = self.get_inner();
+                &inner.#field_ident
+            }
+        };
+        let field_setter_token_stream = if self.nullable {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= Some(value);
+                    self
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter_name(&mut self, value: #nested_type) -> &mut Self {
+                    let inner = self.get_inner_mut();
+                    inner.#field_ident= value;
+                    self
+                }
+            }
+        };
+
+        Ok(AchievedFieldResolver {
+            field_path: self.field_path.clone(),
+            indexes: vec![],
+            columns: vec![self.definition.clone()],
+            joined_table: vec![],
+            foreign_keys: vec![],
+            data_converter_token_stream,
+            converter_getter_ident: method_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            field_setter_token_stream,
+            field_type: field_type.clone(),
+            field_definition: FieldDefinition {
+                entity: self.field_path.0.clone(),
+                name: self.field_path.1.clone(),
+                type_resolver_name: JsonTypeResolver::seed().name(),
+                field_type: if JsonFieldResolverSeed::is_json_value(&self.nested_type) {
+                    JSON_VALUE_TYPE.to_string()
+                } else {
+                    self.nested_type.to_token_stream().to_string()
+                },
+                nullable: self.nullable,
+                length: None,
+                default: None,
+                columns: vec![self.definition.name.clone()],
+                tables: vec![],
+                association: None,
+            },
+            nested_field_definitions: vec![],
+        })
+    }
+}
+
+/// Value converter of JSON column, `None` of nullable field is stored as `NULL` instead of JSON `null`
+#[derive(ToTokens)]
+#[Iroha(mod_path = "yukino::resolver::field_resolver_seeds")]
+pub struct JsonValueConverter {
+    entity_name: String,
+    field_name: String,
+    column_name: String,
+    nullable: bool,
+}
+
+impl<T> ValueConverter<T> for JsonValueConverter
+where
+    T: Serialize + DeserializeOwned,
+{
+    fn to_field_value(&self, values: &ValuePack) -> Result<T, DataConvertError> {
+        let value = match values.get(&self.column_name) {
+            Some(DatabaseValue::Json(value)) => value.clone(),
+            Some(DatabaseValue::Null(DatabaseType::Json)) if self.nullable => Value::Null,
+            _ => {
+                return Err(DataConvertError::UnexpectedDatabaseValueType(
+                    self.entity_name.clone(),
+                    self.field_name.clone(),
+                ))
+            }
+        };
+
+        from_value(value).map_err(|e| {
+            DataConvertError::DatabaseValueConvertError(
+                e.to_string(),
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )
+        })
+    }
+
+    fn to_database_values_by_ref(&self, value: &T) -> Result<ValuePack, DataConvertError> {
+        let json_value = to_value(value).map_err(|e| {
+            DataConvertError::DatabaseValueConvertError(
+                e.to_string(),
+                self.entity_name.clone(),
+                self.field_name.clone(),
+            )
+        })?;
+
+        let mut result = HashMap::new();
+
+        result.insert(
+            self.column_name.clone(),
+            if self.nullable && json_value.is_null() {
+                DatabaseValue::Null(DatabaseType::Json)
+            } else {
+                DatabaseValue::Json(json_value)
+            },
+        );
+
+        Ok(result)
+    }
+
+    fn primary_column_values_by_ref(&self, _value: &T) -> Result<ValuePack, DataConvertError> {
+        Ok(HashMap::new())
+    }
+}
+
+pub struct JsonTypeResolver;
+
+impl JsonTypeResolver {
+    fn value_type_info(&self, nullable: bool) -> TypeInfo {
+        TypeInfo {
+            resolver_name: self.name(),
+            field_type: JSON_VALUE_TYPE.to_string(),
+            nullable,
+            length: None,
+            type_kind: TypeKind::Json,
+        }
+    }
+}
+
+impl TypeResolver for JsonTypeResolver {
+    fn seed() -> Box<dyn TypeResolver> {
+        Box::new(JsonTypeResolver)
+    }
+
+    fn name(&self) -> String {
+        "json".to_string()
+    }
+
+    /// JSON values can be compared with each other regardless of the rust type
+    fn cmp_type_info(&self, a: &TypeInfo, b: &TypeInfo) -> bool {
+        a.resolver_name == b.resolver_name
+    }
+
+    /// `Value` is checked when it is deserialized into the field type
+    fn is_assignable(&self, target: &TypeInfo, value: &TypeInfo) -> bool {
+        target.resolver_name == value.resolver_name
+            && (target.field_type == value.field_type
+                || target.field_type == JSON_VALUE_TYPE
+                || value.field_type == JSON_VALUE_TYPE)
+    }
+
+    fn has_function(&self, name: &str) -> bool {
+        matches!(name, "json_length" | "contains" | "has_key")
+    }
+
+    fn wrap_lit(
+        &self,
+        lit: &Literal,
+        type_info: TypeInfo,
+    ) -> Result<(ExprWrapper, Vec<(String, String)>), SyntaxErrorWithPos> {
+        match lit {
+            Literal::Boolean(_) | Literal::Integer(_) | Literal::Float(_) | Literal::String(_) => {
+                Ok((
+                    ExprWrapper {
+                        exprs: vec![Expr::Literal(lit.clone())],
+                        type_info: self.value_type_info(false),
+                        location: lit.location(),
+                    },
+                    vec![],
+                ))
+            }
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(
+                    external.ident.clone(),
+                    if type_info.nullable {
+                        format!("Option<{}>", JSON_VALUE_TYPE)
+                    } else {
+                        JSON_VALUE_TYPE.to_string()
+                    },
+                )],
+            )),
+            Literal::Null(_) if type_info.nullable => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info,
+                    location: lit.location(),
+                },
+                vec![],
+            )),
+            _ => Err(lit.location().error(SyntaxError::TypeError(
+                type_info.to_string(),
+                TypeKind::from(lit).to_string(),
+            ))),
+        }
+    }
+
+    fn wrap_ident(
+        &self,
+        ident: &ColumnIdent,
+        field_definition: &FieldDefinition,
+    ) -> Result<(IdentResolveStatus, Vec<JoinClause>), SyntaxErrorWithPos> {
+        Ok((
+            IdentResolveStatus::Resolved(ExprWrapper {
+                exprs: vec![Expr::ColumnIdent(ident.clone())],
+                type_info: TypeInfo {
+                    resolver_name: self.name(),
+                    field_type: field_definition.field_type.clone(),
+                    nullable: field_definition.nullable,
+                    length: None,
+                    type_kind: TypeKind::Json,
+                },
+                location: ident.location(),
+            }),
+            vec![],
+        ))
+    }
+
+    fn handle_binary(
+        &self,
+        mut left: ExprWrapper,
+        mut right: ExprWrapper,
+        location: Location,
+        operator: BinaryOperator,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if matches!(operator, BinaryOperator::Eq | BinaryOperator::Neq) {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Binary(Binary {
+                    operator,
+                    left: Box::new(left.exprs.pop().unwrap()),
+                    right: Box::new(right.exprs.pop().unwrap()),
+                    location,
+                })],
+                type_info: TypeInfo {
+                    resolver_name: BoolTypeResolver::seed().name(),
+                    field_type: "bool".to_string(),
+                    nullable: left.type_info.nullable || right.type_info.nullable,
+                    length: None,
+                    type_kind: TypeKind::Boolean,
+                },
+                location,
+            })
+        } else {
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                format!("{:?}", operator),
+                left.type_info.to_string(),
+            )))
+        }
+    }
+
+    /// Value at the path may not exist, so the result is always nullable
+    fn handle_json_access(
+        &self,
+        item: ExprWrapper,
+        access: &JsonAccess,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        let ident = match self.unwrap_expr(item)? {
+            Expr::ColumnIdent(ident) => ident,
+            expr => {
+                return Err(expr
+                    .location()
+                    .error(SyntaxError::UnimplementedOperationForType(
+                        "JsonAccess".to_string(),
+                        format!("{:?}", expr),
+                    )))
+            }
+        };
+
+        Ok(ExprWrapper {
+            exprs: vec![Expr::JsonAccess(JsonAccess {
+                ident,
+                path: access.path.clone(),
+                location: access.location(),
+            })],
+            type_info: self.value_type_info(true),
+            location: access.location(),
+        })
+    }
+
+    fn function_argument_type_info(
+        &self,
+        name: &str,
+        _arguments: &[Option<ExprWrapper>],
+        index: usize,
+    ) -> Option<TypeInfo> {
+        match (name, index) {
+            ("contains", 1) => Some(self.value_type_info(false)),
+            ("has_key", 1) => Some(TypeInfo {
+                resolver_name: StringTypeResolver::seed().name(),
+                field_type: "String".to_string(),
+                nullable: false,
+                length: None,
+                type_kind: TypeKind::String,
+            }),
+            _ => None,
+        }
+    }
+
+    fn handle_function(
+        &self,
+        call: &FunctionCall,
+        arguments: Vec<Option<ExprWrapper>>,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        let name = call.ident.to_lowercase();
+        let (argument_count, resolver_name, field_type, type_kind) = match name.as_str() {
+            "json_length" => (
+                1,
+                NumericTypeResolver::seed().name(),
+                "u32",
+                TypeKind::Numeric,
+            ),
+            _ => (
+                2,
+                BoolTypeResolver::seed().name(),
+                "bool",
+                TypeKind::Boolean,
+            ),
+        };
+
+        if arguments.len() != argument_count {
+            return Err(call
+                .location()
+                .error(SyntaxError::WrongArgumentCount(call.ident.clone())));
+        }
+
+        let mut arguments = arguments.into_iter();
+        let item = arguments
+            .next()
+            .unwrap()
+            .filter(|item| item.type_info.resolver_name == self.name())
+            .ok_or_else(|| {
+                call.parameters[0].location().error(SyntaxError::TypeError(
+                    "json".to_string(),
+                    format!("{:?}", call.parameters[0]),
+                ))
+            })?;
+        let nullable = item.type_info.nullable;
+
+        let mut parameters = vec![self.unwrap_expr(item)?];
+        for (index, (argument, parameter)) in
+            arguments.zip(call.parameters.iter().skip(1)).enumerate()
+        {
+            let mut argument =
+                argument.ok_or_else(|| parameter.location().error(SyntaxError::TypeInferError))?;
+
+            if let Some(expected) = self.function_argument_type_info(&name, &[], index + 1) {
+                if expected.resolver_name != argument.type_info.resolver_name {
+                    return Err(parameter.location().error(SyntaxError::TypeError(
+                        expected.to_string(),
+                        argument.type_info.to_string(),
+                    )));
+                }
+            }
+
+            parameters.push(argument.exprs.pop().unwrap());
+        }
+
+        Ok(ExprWrapper {
+            exprs: vec![Expr::FunctionCall(FunctionCall {
+                ident: name,
+                parameters,
+                location: call.location(),
+            })],
+            type_info: TypeInfo {
+                resolver_name,
+                field_type: field_type.to_string(),
+                nullable,
+                type_kind,
+                length: None,
+            },
+            location: call.location(),
+        })
+    }
+}
+
+#[test]
+fn test_json_type_check() {
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let definition_getter = |entity: &str, field: &str| {
+        let field_type = match (entity, field) {
+            ("test", "meta") => "Value",
+            ("test", "tags") => "Vec < String >",
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "json".to_string(),
+            field_type: field_type.to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        })
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check(
+        "SELECT t.meta->\"author\"[0] AS a, JSON_LENGTH(t.tags) AS b FROM test t \
+        WHERE t.meta->\"version\" = 2 AND CONTAINS(t.tags, \"rust\") AND HAS_KEY(t.meta, \"author\")",
+    )
+    .unwrap();
+
+    assert_eq!(
+        checked.result_types.get("a").map(|s| s.as_str()),
+        Some("Option<Value>")
+    );
+    assert_eq!(
+        checked.result_types.get("b").map(|s| s.as_str()),
+        Some("u32")
+    );
+
+    assert!(check("SELECT t.meta AS a FROM test t WHERE t.meta > 1").is_err());
+    assert!(check("SELECT t.meta AS a FROM test t WHERE HAS_KEY(t.meta, 1)").is_err());
+    assert!(check("SELECT t.meta AS a FROM test t WHERE CONTAINS(t.meta)").is_err());
+    // `LENGTH` is the function of binary
+    assert!(check("SELECT LENGTH(t.tags) AS a FROM test t").is_err());
+}
@@ -1,14 +1,37 @@
+mod binary_field_resolver;
 mod bool_field_resolver;
 mod collection_field_resolver;
+#[cfg(feature = "data-time")]
+mod date_time_field_resolver;
+#[cfg(feature = "decimal")]
+mod decimal_field_resolver;
+mod embedded_field_resolver;
+mod enum_field_resolver;
+#[cfg(feature = "json")]
+mod json_field_resolver;
+mod newtype_field_resolver;
 mod numeric_field_resolver;
 mod string_field_resolver;
 
+pub use binary_field_resolver::*;
 pub use bool_field_resolver::*;
 pub use collection_field_resolver::*;
+#[cfg(feature = "data-time")]
+pub use date_time_field_resolver::*;
+#[cfg(feature = "decimal")]
+pub use decimal_field_resolver::*;
+pub use embedded_field_resolver::*;
+pub use enum_field_resolver::*;
+#[cfg(feature = "json")]
+pub use json_field_resolver::*;
+pub use newtype_field_resolver::*;
 pub use numeric_field_resolver::*;
 pub use string_field_resolver::*;
 
 pub use crate::association::{
-    AssociatedEntity, AssociatedEntityFieldResolver, AssociatedEntityFieldResolverSeed,
-    AssociatedEntityValueConverter,
+    AssociatedCollectionFieldResolver, AssociatedCollectionFieldResolverSeed,
+    AssociatedCollectionValueConverter, AssociatedEntity, AssociatedEntityFieldResolver,
+    AssociatedEntityFieldResolverSeed, AssociatedEntityTypeResolver,
+    AssociatedEntityValueConverter, InverseAssociationFieldResolver,
+    InverseAssociationFieldResolverSeed, InversedEntitiesValueConverter,
 };
@@ -0,0 +1,315 @@
+use crate::annotations::FieldAnnotation;
+use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::{
+    AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
+    FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, Newtype, TypePathResolver,
+    ValueConverter,
+};
+use crate::types::ValuePack;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, parse_str, ImplItemMethod, ReturnType, Type};
+
+/// Seed of newtype fields, the wrapped type is resolved by the inner seeds
+pub struct NewtypeFieldResolverSeed {
+    seeds: Vec<FieldResolverSeedBox>,
+}
+
+impl NewtypeFieldResolverSeed {
+    pub fn with_seeds(seeds: Vec<FieldResolverSeedBox>) -> Self {
+        NewtypeFieldResolverSeed { seeds }
+    }
+}
+
+impl FieldResolverSeed for NewtypeFieldResolverSeed {
+    /// Seed without inner seeds, every newtype field will fail to be resolved
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        NewtypeFieldResolverSeed { seeds: vec![] }
+    }
+
+    fn boxed(&self) -> FieldResolverSeedBox {
+        Box::new(NewtypeFieldResolverSeed {
+            seeds: self.seeds.iter().map(|seed| seed.boxed()).collect(),
+        })
+    }
+
+    fn try_breed(
+        &self,
+        entity_name: EntityName,
+        ident: &Ident,
+        annotations: &[FieldAnnotation],
+        field_type: &Type,
+        type_path_resolver: &TypePathResolver,
+    ) -> Option<Result<FieldResolverBox, ResolveError>> {
+        let field_path = (entity_name.clone(), ident.to_string());
+        let (nullable, ty) = match Self::unwrap_option(field_type, field_path, type_path_resolver) {
+            Ok(result) => result,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let annotated = annotations
+            .iter()
+            .filter_map(|attr| match attr {
+                FieldAnnotation::Newtype(newtype) => Some(newtype),
+                _ => None,
+            })
+            .next();
+
+        let inner = match (type_path_resolver.get_newtype(&ty), annotated) {
+            (Some(definition), _) => definition.inner.clone(),
+            (None, Some(newtype)) => match parse_str::<Type>(&newtype.inner) {
+                Ok(inner) => type_path_resolver.get_full_type(inner),
+                Err(e) => {
+                    return Some(Err(ResolveError::Others(format!(
+                        "Unexpected inner type of newtype field({0} in {1}): {2}",
+                        ident, entity_name, e
+                    ))))
+                }
+            },
+            (None, None) => return None,
+        };
+
+        let inner_type: Type = if nullable {
+            parse_quote!(Option<#inner>)
+        } else {
+            inner
+        };
+
+        let inner_annotations: Vec<_> = annotations
+            .iter()
+            .filter(|attr| !matches!(attr, FieldAnnotation::Newtype(_)))
+            .cloned()
+            .collect();
+
+        let inner_resolver = self.seeds.iter().fold(
+            Err(ResolveError::NoSuitableResolverSeedsFound(
+                entity_name.clone(),
+                ident.to_string(),
+            )),
+            |result, seed| {
+                if result.is_err() {
+                    seed.try_breed(
+                        entity_name.clone(),
+                        ident,
+                        &inner_annotations,
+                        &inner_type,
+                        type_path_resolver,
+                    )
+                    .unwrap_or(result)
+                } else {
+                    result
+                }
+            },
+        );
+
+        Some(inner_resolver.map(|inner| {
+            Box::new(NewtypeFieldResolver {
+                inner,
+                field_type: type_path_resolver.get_full_type(field_type.clone()),
+            }) as FieldResolverBox
+        }))
+    }
+}
+
+/// Field resolver of newtype, column mapping and query typing are delegated to the resolver of the
+/// wrapped type
+pub struct NewtypeFieldResolver {
+    inner: FieldResolverBox,
+    field_type: Type,
+}
+
+impl FieldResolver for NewtypeFieldResolver {
+    fn status(&self) -> FieldResolverStatus {
+        self.inner.status()
+    }
+
+    fn field_path(&self) -> FieldPath {
+        self.inner.field_path()
+    }
+
+    fn resolve_by_waiting_entity(
+        &mut self,
+        resolver: &EntityResolver,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        self.inner.resolve_by_waiting_entity(resolver)
+    }
+
+    fn resolve_by_waiting_fields(
+        &mut self,
+        resolvers: Vec<&AchievedFieldResolver>,
+    ) -> Result<FieldResolverStatus, ResolveError> {
+        self.inner.resolve_by_waiting_fields(resolvers)
+    }
+
+    fn is_deferred(&self) -> bool {
+        self.inner.is_deferred()
+    }
+
+    fn assemble(
+        &mut self,
+        entity_resolver: &EntityResolver,
+    ) -> Result<AchievedFieldResolver, ResolveError> {
+        let achieved = self.inner.assemble(entity_resolver)?;
+        let (entity_name, field_name) = self.field_path();
+        let unexpected_converter = || {
+            ResolveError::Others(format!(
+                "Unexpected converter of newtype field({0} in {1})",
+                field_name, entity_name
+            ))
+        };
+
+        let mut inner_method: ImplItemMethod =
+            syn::parse2(achieved.data_converter_token_stream.clone())
+                .map_err(|_| unexpected_converter())?;
+        let inner_converter_type = match &inner_method.sig.output {
+            ReturnType::Type(_, ty) => ty.clone(),
+            ReturnType::Default => return Err(unexpected_converter()),
+        };
+        let inner_method_name = format_ident!("{}_inner", achieved.converter_getter_ident);
+        inner_method.sig.ident = inner_method_name.clone();
+
+        let method_name = self.converter_getter_ident();
+        let data_converter_token_stream = quote! {
+            #inner_method
+
+            pub fn #method_name() -> yukino::resolver::field_resolver_seeds::NewtypeValueConverter<
+                #inner_converter_type
+            > {
+                yukino::resolver::field_resolver_seeds::NewtypeValueConverter::new(
+                    Self::#inner_method_name()
+                )
+            }
+        };
+
+        let getter_name = self.getter_ident();
+        let setter_name = self.setter_ident();
+        let field_ident = format_ident!("{}", field_name);
+        let field_type = &self.field_type;
+
+        let field_getter_token_stream = quote! {
+            pub fn #getter_name(&self) -> &#field_type {
+                let inner = self.get_inner();
+                &inner.#field_ident
+            }
+        };
+        let field_setter_token_stream = quote! {
+            pub fn #setter_name(&mut self, value: #field_type) -> &mut Self {
+                let inner = self.get_inner_mut();
+                inner.#field_ident= value;
+                self
+            }
+        };
+
+        Ok(AchievedFieldResolver {
+            data_converter_token_stream,
+            converter_getter_ident: method_name,
+            field_getter_ident: getter_name,
+            field_getter_token_stream,
+            field_setter_ident: setter_name,
+            field_setter_token_stream,
+            field_type: field_type.clone(),
+            ..achieved
+        })
+    }
+}
+
+/// Value converter of newtype, values are converted by the converter of the wrapped type
+pub struct NewtypeValueConverter<C> {
+    inner: C,
+}
+
+impl<C> NewtypeValueConverter<C> {
+    pub fn new(inner: C) -> Self {
+        NewtypeValueConverter { inner }
+    }
+}
+
+impl<C: ToTokens> ToTokens for NewtypeValueConverter<C> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let inner = &self.inner;
+
+        tokens.extend(quote! {
+            yukino::resolver::field_resolver_seeds::NewtypeValueConverter::new(#inner)
+        })
+    }
+}
+
+impl<T: Newtype, C: ValueConverter<T::Inner>> ValueConverter<T> for NewtypeValueConverter<C> {
+    fn to_field_value(&self, values: &ValuePack) -> Result<T, DataConvertError> {
+        self.inner.to_field_value(values).map(T::from_inner)
+    }
+
+    fn to_database_values_by_ref(&self, value: &T) -> Result<ValuePack, DataConvertError> {
+        self.inner.to_database_values_by_ref(value.inner())
+    }
+
+    fn primary_column_values_by_ref(&self, value: &T) -> Result<ValuePack, DataConvertError> {
+        self.inner.primary_column_values_by_ref(value.inner())
+    }
+}
+
+impl<T, C> ValueConverter<Option<T>> for NewtypeValueConverter<C>
+where
+    T: Newtype,
+    T::Inner: Clone,
+    C: ValueConverter<Option<T::Inner>>,
+{
+    fn to_field_value(&self, values: &ValuePack) -> Result<Option<T>, DataConvertError> {
+        self.inner
+            .to_field_value(values)
+            .map(|value| value.map(T::from_inner))
+    }
+
+    fn to_database_values_by_ref(&self, value: &Option<T>) -> Result<ValuePack, DataConvertError> {
+        self.inner
+            .to_database_values_by_ref(&value.as_ref().map(|value| value.inner().clone()))
+    }
+
+    fn primary_column_values_by_ref(
+        &self,
+        value: &Option<T>,
+    ) -> Result<ValuePack, DataConvertError> {
+        self.inner
+            .primary_column_values_by_ref(&value.as_ref().map(|value| value.inner().clone()))
+    }
+}
+
+#[test]
+fn test_newtype_field() {
+    use crate::resolver::field_resolver_seeds::NumericFieldResolverSeed;
+    use syn::ItemStruct;
+
+    let mut type_path_resolver = TypePathResolver::default();
+    type_path_resolver
+        .append_newtype_item(&parse_quote! {
+            pub struct UserId(pub u64);
+        })
+        .unwrap();
+
+    let seed =
+        NewtypeFieldResolverSeed::with_seeds(vec![Box::new(NumericFieldResolverSeed::new())]);
+    let ident = format_ident!("id");
+    let breed =
+        |ty: Type| seed.try_breed("test".to_string(), &ident, &[], &ty, &type_path_resolver);
+
+    assert!(breed(parse_quote!(u64)).is_none());
+    assert!(breed(parse_quote!(String)).is_none());
+
+    let item: ItemStruct = parse_quote! {
+        pub struct Test {
+            id: Option<UserId>
+        }
+    };
+    let entity_resolver = EntityResolver::new(item.ident.clone(), 1, None, vec![], item);
+    let mut resolver = breed(parse_quote!(Option<UserId>)).unwrap().unwrap();
+    let achieved = resolver.assemble(&entity_resolver).unwrap();
+
+    let field_type = &achieved.field_type;
+    assert_eq!(quote!(#field_type).to_string(), "Option < UserId >");
+    assert_eq!(achieved.field_definition.type_resolver_name, "numeric");
+    assert_eq!(achieved.field_definition.field_type, "u64");
+    assert!(achieved.field_definition.nullable);
+}
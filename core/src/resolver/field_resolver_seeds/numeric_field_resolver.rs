@@ -2,7 +2,8 @@ use crate::annotations::FieldAnnotation;
 use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{
-    Binary, BinaryOperator, JoinClause, Literal, Locatable, Location, Unary, UnaryOperator,
+    Binary, BinaryOperator, Cast, Float, Integer, JoinClause, Literal, Locatable, Location, Unary,
+    UnaryOperator,
 };
 use crate::query::ast::{ColumnIdent, Expr};
 use crate::query::type_check::TypeKind;
@@ -35,6 +36,10 @@ pub enum NumericType {
 }
 
 impl NumericType {
+    /// Type which both of the numeric types can be promoted to:
+    /// - signed and unsigned integers are promoted to a signed integer which can hold both,
+    ///   e.g. `i16` and `u32` to `i64`, there is no such type for `u64`
+    /// - `i16`/`u16` and floats are promoted to the float, other integers and floats to `f64`
     pub fn concrete(&self, others: &NumericType) -> Option<NumericType> {
         match (self, others) {
             (NumericType::Integer(a), NumericType::Integer(b)) => {
@@ -44,7 +49,26 @@ impl NumericType {
             (NumericType::UnsignedInteger(a), NumericType::UnsignedInteger(b)) => {
                 Some(NumericType::UnsignedInteger(max(*a, *b)))
             }
-            _ => None,
+            (NumericType::Integer(signed), NumericType::UnsignedInteger(unsigned))
+            | (NumericType::UnsignedInteger(unsigned), NumericType::Integer(signed)) => {
+                let size = max(*signed, *unsigned * 2);
+
+                if size > 64 {
+                    None
+                } else {
+                    Some(NumericType::Integer(size))
+                }
+            }
+            (NumericType::Float(float), NumericType::Integer(integer))
+            | (NumericType::Float(float), NumericType::UnsignedInteger(integer))
+            | (NumericType::Integer(integer), NumericType::Float(float))
+            | (NumericType::UnsignedInteger(integer), NumericType::Float(float)) => {
+                if *integer <= 16 {
+                    Some(NumericType::Float(*float))
+                } else {
+                    Some(NumericType::Float(64))
+                }
+            }
         }
     }
 
@@ -288,6 +312,10 @@ impl FieldResolverSeed for NumericFieldResolverSeed {
             auto_increase: field.auto_increase,
             primary_key: Self::is_primary_key(annotations),
             nullable,
+            default: None,
+            charset: None,
+            collation: None,
+            enum_variants: vec![],
         };
 
         Some(Ok(Box::new(NumericFieldResolver {
@@ -395,15 +423,18 @@ impl FieldResolver for NumericFieldResolver {
             field_setter_token_stream,
             field_type: field_type.clone(),
             field_definition: FieldDefinition {
-                entity: self.field_path.1.clone(),
+                entity: self.field_path.0.clone(),
                 name: self.field_path.1.clone(),
                 type_resolver_name: NumericTypeResolver::seed().name(),
                 field_type: self.ty.to_string(),
                 nullable: self.nullable,
+                length: None,
+                default: None,
                 columns: vec![self.definition.name.clone()],
                 tables: vec![],
                 association: None,
             },
+            nested_field_definitions: vec![],
         })
     }
 }
@@ -420,10 +451,35 @@ impl TypeResolver for NumericTypeResolver {
     }
 
     fn cmp_type_info(&self, a: &TypeInfo, b: &TypeInfo) -> bool {
-        let numeric_type_a = NumericType::from_str(a.field_type.as_str()).unwrap();
-        let numeric_type_b = NumericType::from_str(b.field_type.as_str()).unwrap();
+        match (
+            NumericType::from_str(a.field_type.as_str()),
+            NumericType::from_str(b.field_type.as_str()),
+        ) {
+            (Ok(numeric_type_a), Ok(numeric_type_b)) => {
+                numeric_type_a.concrete(&numeric_type_b).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    fn is_assignable(&self, target: &TypeInfo, value: &TypeInfo) -> bool {
+        match (
+            NumericType::from_str(target.field_type.as_str()),
+            NumericType::from_str(value.field_type.as_str()),
+        ) {
+            (Ok(target), Ok(value)) => target.concrete(&value).as_ref() == Some(&target),
+            _ => false,
+        }
+    }
 
-        numeric_type_a.concrete(&numeric_type_b).is_some() && a.nullable == b.nullable
+    fn cast_type_info(&self, ty: &str) -> Option<TypeInfo> {
+        NumericType::from_str(ty).ok().map(|numeric_type| TypeInfo {
+            resolver_name: self.name(),
+            field_type: numeric_type.to_string(),
+            nullable: false,
+            length: None,
+            type_kind: TypeKind::Numeric,
+        })
     }
 
     fn wrap_lit(
@@ -451,8 +507,9 @@ impl TypeResolver for NumericTypeResolver {
                     ))
                 }
             }
-            (Literal::Float(number), NumericType::Float(_)) => {
-                if numeric_type.is_overflow(number.value.as_str()) {
+            (Literal::Integer(Integer { value, .. }), NumericType::Float(_))
+            | (Literal::Float(Float { value, .. }), NumericType::Float(_)) => {
+                if numeric_type.is_overflow(value.as_str()) {
                     Err(lit
                         .location()
                         .error(SyntaxError::LitOverflow(numeric_type.to_string())))
@@ -467,6 +524,35 @@ impl TypeResolver for NumericTypeResolver {
                     ))
                 }
             }
+            (Literal::Float(number), _) => {
+                let float_type = NumericType::Float(64);
+
+                if float_type.is_overflow(number.value.as_str()) {
+                    Err(lit
+                        .location()
+                        .error(SyntaxError::LitOverflow(float_type.to_string())))
+                } else {
+                    Ok((
+                        ExprWrapper {
+                            exprs: vec![Expr::Literal(lit.clone())],
+                            type_info: TypeInfo {
+                                field_type: float_type.to_string(),
+                                ..type_info
+                            },
+                            location: lit.location(),
+                        },
+                        vec![],
+                    ))
+                }
+            }
+            (Literal::External(external), _) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(external.ident.clone(), type_info.to_string())],
+            )),
             (Literal::Null(_), _) if type_info.nullable => Ok((
                 ExprWrapper {
                     exprs: vec![Expr::Literal(lit.clone())],
@@ -497,6 +583,7 @@ impl TypeResolver for NumericTypeResolver {
         let type_info = TypeInfo {
             field_type: field_definition.field_type.clone(),
             nullable: field_definition.nullable,
+            length: None,
             type_kind: TypeKind::Numeric,
             resolver_name: self.name(),
         };
@@ -553,18 +640,21 @@ impl TypeResolver for NumericTypeResolver {
                 (BinaryOperator::Div, NumericType::Integer(size)) => TypeInfo {
                     field_type: NumericType::Float(max(32, *size)).to_string(),
                     nullable,
+                    length: None,
                     type_kind: TypeKind::Numeric,
                     resolver_name: self.name(),
                 },
                 (operator, _) if operator.is_cmp() => TypeInfo {
                     field_type: "bool".to_string(),
                     nullable,
-                    type_kind: left.type_info.type_kind,
+                    length: None,
+                    type_kind: TypeKind::Boolean,
                     resolver_name: BoolTypeResolver::seed().name(),
                 },
                 _ => TypeInfo {
                     field_type: numeric_ty.to_string(),
                     nullable,
+                    length: None,
                     type_kind: left.type_info.type_kind,
                     resolver_name: self.name(),
                 },
@@ -606,6 +696,33 @@ impl TypeResolver for NumericTypeResolver {
             })
         }
     }
+
+    fn handle_cast(
+        &self,
+        mut item: ExprWrapper,
+        type_info: TypeInfo,
+        location: Location,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if matches!(
+            item.type_info.type_kind,
+            TypeKind::Numeric | TypeKind::Boolean
+        ) {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Cast(Cast {
+                    expr: Box::new(item.exprs.pop().unwrap()),
+                    ty: type_info.field_type.clone(),
+                    location,
+                })],
+                type_info,
+                location,
+            })
+        } else {
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                "Cast".to_string(),
+                item.type_info.to_string(),
+            )))
+        }
+    }
 }
 
 macro_rules! impl_converter {
@@ -713,3 +830,62 @@ impl_converter!(UnsignedIntegerValueConverter, u32, UnsignedInteger);
 impl_converter!(UnsignedBigIntegerValueConverter, u64, UnsignedBigInteger);
 impl_converter!(FloatValueConverter, f32, Float);
 impl_converter!(DoubleValueConverter, f64, Double);
+
+#[test]
+fn test_numeric_promotion() {
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let definition_getter = |entity: &str, field: &str| {
+        let field_type = match (entity, field) {
+            ("test", "small") => "i16",
+            ("test", "unsigned") => "u32",
+            ("test", "big") => "u64",
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "numeric".to_string(),
+            field_type: field_type.to_string(),
+            nullable: false,
+            length: None,
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        })
+    };
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![],
+        )
+    };
+
+    let checked = check(
+        "SELECT t.small + t.unsigned AS a, t.small * 1.5 AS b, CAST(t.big AS i64) + t.small AS c \
+        FROM test t",
+    )
+    .unwrap();
+
+    assert_eq!(
+        checked.result_types.get("a").map(|s| s.as_str()),
+        Some("i64")
+    );
+    assert_eq!(
+        checked.result_types.get("b").map(|s| s.as_str()),
+        Some("f64")
+    );
+    assert_eq!(
+        checked.result_types.get("c").map(|s| s.as_str()),
+        Some("i64")
+    );
+
+    assert!(check("SELECT t.big + t.small AS a FROM test t").is_err());
+    assert!(check("SELECT CAST(t.big AS Foo) AS a FROM test t").is_err());
+    assert!(check("UPDATE test t SET t.unsigned = t.small").is_err());
+    assert!(check("UPDATE test t SET t.big = t.unsigned").is_ok());
+}
@@ -1,9 +1,13 @@
 use crate::annotations::FieldAnnotation;
 use crate::definitions::{ColumnDefinition, ColumnType, FieldDefinition};
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
-use crate::query::ast::{ColumnIdent, Expr, JoinClause, Literal, Locatable};
+use crate::query::ast::{
+    Binary, BinaryOperator, Cast, ColumnIdent, Expr, FunctionCall, JoinClause, Literal, Locatable,
+    Location,
+};
 use crate::query::type_check::TypeKind;
 use crate::resolver::error::{DataConvertError, ResolveError};
+use crate::resolver::field_resolver_seeds::bool_field_resolver::BoolTypeResolver;
 use crate::resolver::{
     AchievedFieldResolver, EntityName, EntityResolver, FieldPath, FieldResolver, FieldResolverBox,
     FieldResolverSeed, FieldResolverSeedBox, FieldResolverStatus, TypePathResolver, ValueConverter,
@@ -16,8 +20,37 @@ use iroha::ToTokens;
 use proc_macro2::Ident;
 use quote::{format_ident, quote};
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use syn::Type;
 
+/// Length of `VARCHAR` column if length of string field is not specified
+pub const STRING_DEFAULT_LENGTH: u32 = 255;
+
+/// Max length of string value, displayed as `String(64)` in error messages
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct StringType {
+    pub length: Option<u32>,
+}
+
+impl StringType {
+    /// Length is counted in characters, same as `VARCHAR` in database
+    pub fn accept(&self, value: &str) -> bool {
+        match self.length {
+            Some(length) => value.chars().count() <= length as usize,
+            None => true,
+        }
+    }
+}
+
+impl Display for StringType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.length {
+            Some(length) => write!(f, "String({})", length),
+            None => write!(f, "String"),
+        }
+    }
+}
+
 pub struct StringFieldResolverSeed;
 
 impl FieldResolverSeed for StringFieldResolverSeed {
@@ -52,30 +85,58 @@ impl FieldResolverSeed for StringFieldResolverSeed {
             if let Some(first_segment) = type_path.path.segments.first() {
                 if first_segment.ident == *"String" {
                     let field = Self::default_annotations(annotations);
-
-                    if field.auto_increase {
-                        Some(Err(ResolveError::Others(format!(
-                            "AutoIncrease is not supported on string field({0} in {1})",
-                            ident, entity_name
-                        ))))
+                    let primary_key = Self::is_primary_key(annotations);
+
+                    let error = if field.auto_increase {
+                        Some("AutoIncrease is not supported on string field")
+                    } else if field.text && field.length.is_some() {
+                        Some("Length is not supported on text field")
+                    } else if field.text && primary_key {
+                        Some("PrimaryKey is not supported on text field")
+                    } else if field.length == Some(0) {
+                        Some("Length of string field must be greater than 0")
                     } else {
-                        Some(Ok(Box::new(StringFieldResolver {
-                            field_path: (entity_name.clone(), ident.to_string()),
-                            definition: ColumnDefinition {
-                                name: field
-                                    .name
-                                    .unwrap_or_else(|| ident.to_string().to_snake_case()),
-                                ty: ColumnType::NormalColumn(entity_name),
-                                data_type: DatabaseType::String,
-                                unique: field.unique,
-                                auto_increase: field.auto_increase,
-                                primary_key: Self::is_primary_key(annotations),
-                                nullable,
+                        None
+                    };
+
+                    if let Some(error) = error {
+                        return Some(Err(ResolveError::Others(format!(
+                            "{}({} in {})",
+                            error, ident, entity_name
+                        ))));
+                    }
+
+                    Some(Ok(Box::new(StringFieldResolver {
+                        field_path: (entity_name.clone(), ident.to_string()),
+                        ty: StringType {
+                            length: if field.text {
+                                None
+                            } else {
+                                Some(field.length.unwrap_or(STRING_DEFAULT_LENGTH))
                             },
-                            field_type: type_path_resolver.get_full_type(field_type.clone()),
+                        },
+                        definition: ColumnDefinition {
+                            name: field
+                                .name
+                                .unwrap_or_else(|| ident.to_string().to_snake_case()),
+                            ty: ColumnType::NormalColumn(entity_name),
+                            data_type: if field.text {
+                                DatabaseType::Text
+                            } else {
+                                DatabaseType::String(field.length.unwrap_or(STRING_DEFAULT_LENGTH))
+                            },
+                            unique: field.unique,
+                            auto_increase: false,
+                            primary_key,
                             nullable,
-                        })))
-                    }
+                            default: None,
+                            charset: field.charset,
+                            collation: field.collation,
+                            enum_variants: vec![],
+                        },
+                        field_type: type_path_resolver.get_full_type(field_type.clone()),
+                        nullable,
+                    })))
                 } else {
                     None
                 }
@@ -90,6 +151,7 @@ impl FieldResolverSeed for StringFieldResolverSeed {
 
 pub struct StringFieldResolver {
     field_path: FieldPath,
+    ty: StringType,
     definition: ColumnDefinition,
     field_type: Type,
     nullable: bool,
@@ -126,12 +188,13 @@ impl FieldResolver for StringFieldResolver {
 
         let (entity_name, field_name) = self.field_path();
 
-        let converter = StringValueConverter {
-            is_primary_key: self.definition.primary_key,
+        let converter = StringValueConverter::new(
+            self.definition.primary_key,
             entity_name,
-            field_name: field_name.clone(),
-            column_name: self.definition.name.clone(),
-        };
+            field_name.clone(),
+            self.definition.name.clone(),
+            self.definition.data_type,
+        );
 
         let data_converter_token_stream = quote! {
             pub fn #method_name() -> yukino::resolver::field_resolver_seeds::StringValueConverter {
@@ -182,15 +245,18 @@ impl FieldResolver for StringFieldResolver {
             field_setter_token_stream,
             field_type: self.field_type.clone(),
             field_definition: FieldDefinition {
-                entity: self.field_path.1.clone(),
+                entity: self.field_path.0.clone(),
                 name: self.definition.name.clone(),
                 type_resolver_name: StringTypeResolver::seed().name(),
-                field_type: "string".to_string(),
+                field_type: "String".to_string(),
                 nullable: self.nullable,
+                length: self.ty.length,
+                default: None,
                 columns: vec![self.definition.name.clone()],
                 tables: vec![],
                 association: None,
             },
+            nested_field_definitions: vec![],
         })
     }
 }
@@ -202,12 +268,25 @@ pub struct StringValueConverter {
     entity_name: String,
     field_name: String,
     column_name: String,
+    data_type: DatabaseType,
+}
+
+impl StringValueConverter {
+    /// Text column keeps values as `DatabaseValue::Text`
+    fn database_value(&self, value: &str) -> DatabaseValue {
+        match self.data_type {
+            DatabaseType::Text => DatabaseValue::Text(value.to_string()),
+            _ => DatabaseValue::String(value.to_string()),
+        }
+    }
 }
 
 impl ValueConverter<String> for StringValueConverter {
     fn to_field_value(&self, values: &ValuePack) -> Result<String, DataConvertError> {
         match values.get(&self.column_name) {
-            Some(DatabaseValue::String(value)) => Ok(value.clone()),
+            Some(DatabaseValue::String(value)) | Some(DatabaseValue::Text(value)) => {
+                Ok(value.clone())
+            }
             _ => Err(DataConvertError::UnexpectedDatabaseValueType(
                 self.entity_name.clone(),
                 self.field_name.clone(),
@@ -217,10 +296,7 @@ impl ValueConverter<String> for StringValueConverter {
 
     fn to_database_values_by_ref(&self, value: &String) -> Result<ValuePack, DataConvertError> {
         let mut map = HashMap::new();
-        map.insert(
-            self.column_name.clone(),
-            DatabaseValue::String(value.clone()),
-        );
+        map.insert(self.column_name.clone(), self.database_value(value));
 
         Ok(map)
     }
@@ -237,8 +313,11 @@ impl ValueConverter<String> for StringValueConverter {
 impl ValueConverter<Option<String>> for StringValueConverter {
     fn to_field_value(&self, values: &ValuePack) -> Result<Option<String>, DataConvertError> {
         match values.get(&self.column_name) {
-            Some(DatabaseValue::String(value)) => Ok(Some(value.clone())),
-            Some(DatabaseValue::Null(DatabaseType::String)) => Ok(None),
+            Some(DatabaseValue::String(value)) | Some(DatabaseValue::Text(value)) => {
+                Ok(Some(value.clone()))
+            }
+            Some(DatabaseValue::Null(DatabaseType::String(_)))
+            | Some(DatabaseValue::Null(DatabaseType::Text)) => Ok(None),
             _ => Err(DataConvertError::UnexpectedDatabaseValueType(
                 self.entity_name.clone(),
                 self.field_name.clone(),
@@ -254,8 +333,8 @@ impl ValueConverter<Option<String>> for StringValueConverter {
         map.insert(
             self.column_name.clone(),
             match value {
-                Some(v) => DatabaseValue::String(v.clone()),
-                None => DatabaseValue::Null(DatabaseType::String),
+                Some(v) => self.database_value(v),
+                None => DatabaseValue::Null(self.data_type),
             },
         );
 
@@ -288,19 +367,51 @@ impl TypeResolver for StringTypeResolver {
         "string".to_string()
     }
 
+    /// Strings with different length can be operated together
+    fn cmp_type_info(&self, a: &TypeInfo, b: &TypeInfo) -> bool {
+        a.resolver_name == b.resolver_name && a.type_kind == b.type_kind
+    }
+
+    /// Value longer than the target column is rejected if both lengths are known
+    fn is_assignable(&self, target: &TypeInfo, value: &TypeInfo) -> bool {
+        self.cmp_type_info(target, value)
+            && !matches!(
+                (target.length, value.length),
+                (Some(target_length), Some(value_length)) if value_length > target_length
+            )
+    }
+
+    fn cast_type_info(&self, ty: &str) -> Option<TypeInfo> {
+        if ty == "String" {
+            Some(TypeInfo {
+                resolver_name: self.name(),
+                field_type: ty.to_string(),
+                nullable: false,
+                length: None,
+                type_kind: TypeKind::String,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn has_function(&self, name: &str) -> bool {
+        matches!(name, "lower" | "upper")
+    }
+
     fn wrap_lit(
         &self,
         lit: &Literal,
         type_info: TypeInfo,
     ) -> Result<(ExprWrapper, Vec<(String, String)>), SyntaxErrorWithPos> {
-        if &type_info.field_type != "String" {
-            return Err(lit.location().error(SyntaxError::TypeError(
-                "String".to_string(),
-                type_info.field_type,
-            )));
-        }
+        let string_type = StringType {
+            length: type_info.length,
+        };
 
         match lit {
+            Literal::String(value) if !string_type.accept(&value.value) => Err(lit
+                .location()
+                .error(SyntaxError::LitOverflow(string_type.to_string()))),
             Literal::String(_) => Ok((
                 ExprWrapper {
                     exprs: vec![Expr::Literal(lit.clone())],
@@ -309,6 +420,21 @@ impl TypeResolver for StringTypeResolver {
                 },
                 vec![],
             )),
+            Literal::External(external) => Ok((
+                ExprWrapper {
+                    exprs: vec![Expr::Literal(lit.clone())],
+                    type_info: type_info.clone(),
+                    location: lit.location(),
+                },
+                vec![(
+                    external.ident.clone(),
+                    if type_info.nullable {
+                        "Option<String>".to_string()
+                    } else {
+                        "String".to_string()
+                    },
+                )],
+            )),
             Literal::Null(_) if type_info.nullable => Ok((
                 ExprWrapper {
                     exprs: vec![Expr::Literal(lit.clone())],
@@ -329,26 +455,224 @@ impl TypeResolver for StringTypeResolver {
         ident: &ColumnIdent,
         field_definition: &FieldDefinition,
     ) -> Result<(IdentResolveStatus, Vec<JoinClause>), SyntaxErrorWithPos> {
-        if &field_definition.field_type != "String" {
-            Err(ident.location().error(SyntaxError::TypeError(
+        if field_definition.field_type != "String" {
+            return Err(ident.location().error(SyntaxError::TypeError(
                 "String".to_string(),
                 field_definition.field_type.clone(),
+            )));
+        }
+
+        let type_info = TypeInfo {
+            field_type: field_definition.field_type.clone(),
+            nullable: field_definition.nullable,
+            resolver_name: self.name(),
+            length: field_definition.length,
+            type_kind: TypeKind::String,
+        };
+        Ok((
+            IdentResolveStatus::Resolved(ExprWrapper {
+                exprs: vec![Expr::ColumnIdent(ident.clone())],
+                type_info,
+                location: ident.location(),
+            }),
+            vec![],
+        ))
+    }
+
+    /// Strings can only be compared, result is a boolean
+    fn handle_binary(
+        &self,
+        mut left: ExprWrapper,
+        mut right: ExprWrapper,
+        location: Location,
+        operator: BinaryOperator,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if operator.is_cmp() {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Binary(Binary {
+                    operator,
+                    left: Box::new(left.exprs.pop().unwrap()),
+                    right: Box::new(right.exprs.pop().unwrap()),
+                    location,
+                })],
+                type_info: TypeInfo {
+                    resolver_name: BoolTypeResolver::seed().name(),
+                    field_type: "bool".to_string(),
+                    nullable: left.type_info.nullable || right.type_info.nullable,
+                    length: None,
+                    type_kind: TypeKind::Boolean,
+                },
+                location,
+            })
+        } else {
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                format!("{:?}", operator),
+                left.type_info.to_string(),
             )))
+        }
+    }
+
+    fn handle_cast(
+        &self,
+        mut item: ExprWrapper,
+        type_info: TypeInfo,
+        location: Location,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if matches!(
+            item.type_info.type_kind,
+            TypeKind::Numeric | TypeKind::String | TypeKind::Boolean
+        ) {
+            Ok(ExprWrapper {
+                exprs: vec![Expr::Cast(Cast {
+                    expr: Box::new(item.exprs.pop().unwrap()),
+                    ty: type_info.field_type.clone(),
+                    location,
+                })],
+                type_info,
+                location,
+            })
         } else {
-            let type_info = TypeInfo {
-                field_type: field_definition.field_type.clone(),
-                nullable: field_definition.nullable,
-                resolver_name: self.name(),
-                type_kind: TypeKind::String,
-            };
-            Ok((
-                IdentResolveStatus::Resolved(ExprWrapper {
-                    exprs: vec![Expr::ColumnIdent(ident.clone())],
-                    type_info,
-                    location: ident.location(),
-                }),
-                vec![],
-            ))
+            Err(location.error(SyntaxError::UnimplementedOperationForType(
+                "Cast".to_string(),
+                item.type_info.to_string(),
+            )))
         }
     }
+
+    fn function_argument_type_info(
+        &self,
+        _name: &str,
+        _arguments: &[Option<ExprWrapper>],
+        _index: usize,
+    ) -> Option<TypeInfo> {
+        self.cast_type_info("String")
+    }
+
+    /// `lower` and `upper` keep the nullability of argument
+    fn handle_function(
+        &self,
+        call: &FunctionCall,
+        mut arguments: Vec<Option<ExprWrapper>>,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        if arguments.len() != 1 {
+            return Err(call
+                .location()
+                .error(SyntaxError::WrongArgumentCount(call.ident.clone())));
+        }
+
+        let item = arguments
+            .pop()
+            .unwrap()
+            .filter(|item| item.type_info.resolver_name == self.name())
+            .ok_or_else(|| {
+                call.parameters[0].location().error(SyntaxError::TypeError(
+                    "String".to_string(),
+                    format!("{:?}", call.parameters[0]),
+                ))
+            })?;
+        let type_info = item.type_info.clone();
+
+        Ok(ExprWrapper {
+            exprs: vec![Expr::FunctionCall(FunctionCall {
+                ident: call.ident.to_lowercase(),
+                parameters: vec![self.unwrap_expr(item)?],
+                location: call.location(),
+            })],
+            type_info,
+            location: call.location(),
+        })
+    }
+}
+
+#[test]
+fn test_string_type() {
+    let ty = StringType { length: Some(4) };
+
+    assert_eq!(ty.to_string(), "String(4)");
+    assert!(ty.accept("中文字符"));
+    assert!(!ty.accept("abcde"));
+
+    let text = StringType { length: None };
+
+    assert_eq!(text.to_string(), "String");
+    assert!(text.accept(&"a".repeat(1024)));
+}
+
+#[test]
+fn test_string_length() {
+    use crate::definitions::{ColumnDefinition, ColumnType, TableDefinition, TableType};
+    use crate::query::{check_query, default_type_resolvers, parse_query};
+
+    let definition_getter = |entity: &str, field: &str| {
+        let length = match (entity, field) {
+            ("test", "code") => 4,
+            ("test", "name") => 255,
+            _ => return None,
+        };
+
+        Some(FieldDefinition {
+            entity: "test".to_string(),
+            name: field.to_string(),
+            type_resolver_name: "string".to_string(),
+            field_type: "String".to_string(),
+            nullable: false,
+            length: Some(length),
+            default: None,
+            columns: vec![field.to_string()],
+            tables: vec![],
+            association: None,
+        })
+    };
+
+    let column = |name: &str, length: u32| ColumnDefinition {
+        name: name.to_string(),
+        ty: ColumnType::NormalColumn(name.to_string()),
+        data_type: DatabaseType::String(length),
+        unique: false,
+        auto_increase: false,
+        primary_key: false,
+        nullable: false,
+        default: None,
+        charset: None,
+        collation: None,
+        enum_variants: vec![],
+    };
+
+    let table = TableDefinition {
+        name: "test".to_string(),
+        ty: TableType::NormalEntityTable("test".to_string()),
+        columns: vec![column("code", 4), column("name", 255)],
+        indexes: vec![],
+        foreign_keys: vec![],
+        checks: vec![],
+        version: None,
+        created_at: None,
+        updated_at: None,
+    };
+
+    let check = |input: &str| {
+        check_query(
+            parse_query(input).unwrap(),
+            default_type_resolvers(),
+            definition_getter,
+            vec![table.clone()],
+        )
+    };
+
+    assert!(check("UPDATE test t SET t.code = \"abcd\"").is_ok());
+    assert!(check("UPDATE test t SET t.name = t.code").is_ok());
+    assert!(matches!(
+        check("UPDATE test t SET t.code = t.name").map_err(|e| e.error),
+        Err(SyntaxError::TypeError(_, _))
+    ));
+    assert!(check("UPDATE test t SET t.code = lower(t.code)").is_ok());
+    assert!(check("SELECT t.code AS a FROM test t WHERE lower(t.code) = t.name").is_ok());
+    assert!(matches!(
+        check("UPDATE test t SET t.code = \"abcde\"").map_err(|e| e.error),
+        Err(SyntaxError::LitOverflow(_))
+    ));
+    assert!(matches!(
+        check("INSERT INTO test (code, name) VALUES (\"abcde\", \"abcde\")").map_err(|e| e.error),
+        Err(SyntaxError::LitOverflow(_))
+    ));
 }
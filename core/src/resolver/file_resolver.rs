@@ -1,21 +1,35 @@
-use crate::association::AssociatedEntityFieldResolverSeed;
+use crate::annotations::Embeddable;
+use crate::association::{
+    AssociatedCollectionFieldResolverSeed, AssociatedEntityFieldResolverSeed,
+    InverseAssociationFieldResolverSeed,
+};
+use crate::definitions::{FieldDefinition, TableDefinition};
 use crate::resolver::entity_resolver_passes::{
-    EntityImplementResolverPass, EntityProxyResolverPass, EntityStructResolverPass,
+    EntityFieldHandleResolverPass, EntityImplementResolverPass, EntityProxyResolverPass,
+    EntityStructResolverPass, EntityTreeResolverPass,
 };
 use crate::resolver::error::ResolveError;
+#[cfg(feature = "data-time")]
+use crate::resolver::field_resolver_seeds::DateTimeFieldResolverSeed;
+#[cfg(feature = "decimal")]
+use crate::resolver::field_resolver_seeds::DecimalFieldResolverSeed;
+#[cfg(feature = "json")]
+use crate::resolver::field_resolver_seeds::JsonFieldResolverSeed;
 use crate::resolver::field_resolver_seeds::{
-    BoolFieldResolverSeed, CollectionFieldResolverSeed, NumericFieldResolverSeed,
-    StringFieldResolverSeed,
+    BinaryFieldResolverSeed, BoolFieldResolverSeed, CollectionFieldResolverSeed,
+    EmbeddedFieldResolverSeed, EnumFieldResolverSeed, NewtypeFieldResolverSeed,
+    NumericFieldResolverSeed, StringFieldResolverSeed,
 };
 use crate::resolver::{
     EntityResolverPass, EntityResolverPassBox, FieldResolverSeed, FieldResolverSeedBox,
-    ImmutableSchemaResolver, SchemaResolver, TypePathResolver,
+    ImmutableSchemaResolver, NewtypeDefinition, SchemaResolver, TypePathResolver,
 };
+use annotation_rs::AnnotationStructure;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::fs::File;
 use std::io::Read;
-use syn::{parse_file, Error as SynError, Item};
+use syn::{parse_file, Error as SynError, Item, ItemStruct};
 
 pub struct FileResolver {
     schema_resolver: SchemaResolver,
@@ -32,20 +46,38 @@ impl FileResolver {
         let schema_file = File::open(schema_file_path).map_err(ResolveError::IOError)?;
 
         let mut default_seeds: Vec<FieldResolverSeedBox> = vec![
+            Box::new(EmbeddedFieldResolverSeed::new()),
+            Box::new(InverseAssociationFieldResolverSeed::new()),
+            Box::new(AssociatedCollectionFieldResolverSeed::new()),
             Box::new(NumericFieldResolverSeed::new()),
+            Box::new(EnumFieldResolverSeed::new()),
+            Box::new(BinaryFieldResolverSeed::new()),
+            #[cfg(feature = "json")]
+            Box::new(JsonFieldResolverSeed::new()),
             Box::new(CollectionFieldResolverSeed::new()),
             Box::new(StringFieldResolverSeed::new()),
             Box::new(BoolFieldResolverSeed::new()),
+            #[cfg(feature = "data-time")]
+            Box::new(DateTimeFieldResolverSeed::new()),
+            #[cfg(feature = "decimal")]
+            Box::new(DecimalFieldResolverSeed::new()),
             Box::new(AssociatedEntityFieldResolverSeed::new()),
         ];
 
         let mut default_passes: Vec<EntityResolverPassBox> = vec![
             Box::new(EntityStructResolverPass::new()),
             Box::new(EntityImplementResolverPass::new()),
+            Box::new(EntityFieldHandleResolverPass::new()),
             Box::new(EntityProxyResolverPass::new()),
+            Box::new(EntityTreeResolverPass::new()),
         ];
 
         customized_seeds.append(&mut default_seeds);
+
+        let newtype_seed = NewtypeFieldResolverSeed::with_seeds(
+            customized_seeds.iter().map(|s| s.boxed()).collect(),
+        );
+        customized_seeds.insert(0, Box::new(newtype_seed));
         customized_entity_resolver_pass.append(&mut default_passes);
 
         Ok(FileResolver {
@@ -73,34 +105,81 @@ impl FileResolver {
                 .map_err(|e| e.into_syn_error(use_item))?;
         }
 
+        for enum_item in syntax.items.iter().filter_map(|item| match item {
+            Item::Enum(item_enum) => Some(item_enum),
+            _ => None,
+        }) {
+            self.type_path_resolver.append_enum_item(enum_item)?;
+        }
+
+        for struct_item in syntax.items.iter().filter_map(|item| match item {
+            Item::Struct(item_struct) if NewtypeDefinition::is_newtype(item_struct) => {
+                Some(item_struct)
+            }
+            _ => None,
+        }) {
+            self.type_path_resolver.append_newtype_item(struct_item)?;
+        }
+
+        for struct_item in syntax.items.iter().filter_map(|item| match item {
+            Item::Struct(item_struct) if Self::is_embeddable(item_struct) => Some(item_struct),
+            _ => None,
+        }) {
+            let definition = self
+                .schema_resolver
+                .parse_embeddable(struct_item.clone(), &self.type_path_resolver)?;
+            self.type_path_resolver.append_embeddable(definition);
+        }
+
         for item in syntax.items {
             match item {
+                Item::Struct(item_struct)
+                    if Self::is_embeddable(&item_struct)
+                        || NewtypeDefinition::is_newtype(&item_struct) => {}
                 Item::Struct(item_struct) => {
                     self.schema_resolver
                         .parse(item_struct, &self.type_path_resolver)?;
                 }
-                Item::Use(_) => {}
+                Item::Use(_) | Item::Enum(_) => {}
                 _ => return Err(ResolveError::UnsupportedSyntaxBlock.into_syn_error(item)),
             }
         }
 
         Ok(AchievedFileResolver {
             schema_resolver: self.schema_resolver.achieve(&self.type_path_resolver)?,
+            type_implements: self.type_path_resolver.get_implements(),
         })
     }
+
+    fn is_embeddable(item: &ItemStruct) -> bool {
+        item.attrs
+            .iter()
+            .any(|attr| attr.path == Embeddable::get_path())
+    }
 }
 
 pub struct AchievedFileResolver {
     schema_resolver: ImmutableSchemaResolver,
+    type_implements: TokenStream,
 }
 
 impl AchievedFileResolver {
     pub fn get_result(&self) -> TokenStream {
         let result = self.schema_resolver.get_implements();
+        let type_implements = &self.type_implements;
 
         quote! {
             #![allow(unknown_lints)]
+            #type_implements
             #result
         }
     }
+
+    pub fn get_field_definition(&self, entity: &str, field: &str) -> Option<FieldDefinition> {
+        self.schema_resolver.get_field_definition(entity, field)
+    }
+
+    pub fn get_definitions(&self) -> Vec<TableDefinition> {
+        self.schema_resolver.get_definitions()
+    }
 }
@@ -0,0 +1,96 @@
+use crate::definitions::{ColumnDefinition, ColumnType, TableDefinition, TableType};
+use crate::resolver::field_resolver_seeds::StringValueConverter;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use rand::random;
+use syn::{parse_quote, Field, Visibility};
+
+/// Generate a random(version 4) uuid
+pub fn generate_uuid() -> String {
+    let mut bytes: [u8; 16] = random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// Uuid primary key generated for entity without `ID` field, it is stored in a hidden field of
+/// entity which is empty until the entity is inserted
+pub struct HiddenPrimaryKey {
+    entity_name: String,
+    column: ColumnDefinition,
+}
+
+impl HiddenPrimaryKey {
+    pub fn from_definitions(entity_name: &str, definitions: &[TableDefinition]) -> Option<Self> {
+        definitions
+            .iter()
+            .filter(|table| {
+                matches!(&table.ty, TableType::NormalEntityTable(entity) if entity == entity_name)
+            })
+            .flat_map(|table| table.columns.iter())
+            .find(|column| column.primary_key && matches!(column.ty, ColumnType::VisualColumn))
+            .map(|column| HiddenPrimaryKey {
+                entity_name: entity_name.to_string(),
+                column: column.clone(),
+            })
+    }
+
+    pub fn column_name(&self) -> &str {
+        &self.column.name
+    }
+
+    pub fn field_ident(&self) -> Ident {
+        format_ident!("{}", self.column.name)
+    }
+
+    pub fn converter_getter_ident(&self) -> Ident {
+        format_ident!("get_hidden_primary_key_converter")
+    }
+
+    pub fn field(&self) -> Field {
+        Field {
+            attrs: vec![],
+            vis: Visibility::Inherited,
+            ident: Some(self.field_ident()),
+            colon_token: Some(Default::default()),
+            ty: parse_quote!(String),
+        }
+    }
+
+    pub fn data_converter_token_stream(&self) -> TokenStream {
+        let method_name = self.converter_getter_ident();
+        let converter = StringValueConverter::new(
+            true,
+            self.entity_name.clone(),
+            self.column.name.clone(),
+            self.column.name.clone(),
+            self.column.data_type,
+        );
+
+        quote! {
+            pub fn #method_name() -> yukino::resolver::field_resolver_seeds::StringValueConverter {
+                #converter
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generate_uuid() {
+    let uuid = generate_uuid();
+
+    assert_eq!(uuid.len(), 36);
+    assert_eq!(uuid.chars().nth(14), Some('4'));
+    assert!(matches!(uuid.chars().nth(19), Some('8' | '9' | 'a' | 'b')));
+    assert_ne!(uuid, generate_uuid());
+}
@@ -1,14 +1,22 @@
+mod embeddable_resolver;
 mod entity_resolver;
 pub mod entity_resolver_passes;
+mod enum_resolver;
 pub mod error;
 mod field_resolver;
 pub mod field_resolver_seeds;
 mod file_resolver;
+mod hidden_primary_key;
+mod newtype_resolver;
 mod schema_resolver;
 mod type_path_resolver;
 
+pub use embeddable_resolver::*;
 pub use entity_resolver::*;
+pub use enum_resolver::*;
 pub use field_resolver::*;
 pub use file_resolver::*;
+pub use hidden_primary_key::*;
+pub use newtype_resolver::*;
 pub use schema_resolver::*;
 pub use type_path_resolver::*;
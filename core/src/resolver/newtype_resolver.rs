@@ -0,0 +1,127 @@
+use crate::resolver::error::ResolveError;
+use crate::resolver::TypePathResolver;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Error as SynError, Fields, ItemStruct, Type, Visibility};
+
+/// Wrapper of a single value, implemented for tuple structs with a single field declared in schema
+/// file
+pub trait Newtype: Sized {
+    type Inner;
+
+    fn from_inner(inner: Self::Inner) -> Self;
+
+    fn inner(&self) -> &Self::Inner;
+}
+
+/// Tuple struct with a single field declared in schema file
+#[derive(Clone)]
+pub struct NewtypeDefinition {
+    pub ident: Ident,
+    /// Full type of the wrapped field
+    pub inner: Type,
+    item: ItemStruct,
+}
+
+impl NewtypeDefinition {
+    pub fn from_item(
+        item: &ItemStruct,
+        type_path_resolver: &TypePathResolver,
+    ) -> Result<Self, SynError> {
+        let name = item.ident.to_string();
+
+        if !item.generics.params.is_empty() {
+            return Err(ResolveError::GenericIsNotSupported(name).into_syn_error(item));
+        } else if !matches!(&item.vis, Visibility::Public(_)) {
+            return Err(ResolveError::EntityVisibilityMustBePublic(name).into_syn_error(item));
+        } else if !Self::is_newtype(item) {
+            return Err(ResolveError::Others(format!(
+                "Newtype({}) must be a tuple struct with a single field",
+                name
+            ))
+            .into_syn_error(item));
+        }
+
+        let field = item.fields.iter().next().unwrap();
+
+        Ok(NewtypeDefinition {
+            ident: item.ident.clone(),
+            inner: type_path_resolver.get_full_type(field.ty.clone()),
+            item: item.clone(),
+        })
+    }
+
+    pub fn is_newtype(item: &ItemStruct) -> bool {
+        matches!(&item.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+    }
+
+    pub fn get_implement(&self) -> TokenStream {
+        let ident = &self.ident;
+        let inner = &self.inner;
+
+        let mut item = self.item.clone();
+        item.attrs = vec![];
+
+        for field in item.fields.iter_mut() {
+            field.attrs = vec![];
+            field.ty = inner.clone();
+        }
+
+        quote! {
+            #[derive(Clone, Debug, PartialEq)]
+            #item
+
+            impl yukino::resolver::Newtype for #ident {
+                type Inner = #inner;
+
+                fn from_inner(inner: Self::Inner) -> Self {
+                    #ident(inner)
+                }
+
+                fn inner(&self) -> &Self::Inner {
+                    &self.0
+                }
+            }
+
+            impl yukino::query::ToParam for #ident {
+                fn type_name() -> String {
+                    <#inner as yukino::query::ToParam>::type_name()
+                }
+
+                fn database_type() -> yukino::types::DatabaseType {
+                    <#inner as yukino::query::ToParam>::database_type()
+                }
+
+                fn to_database_value(&self) -> yukino::types::DatabaseValue {
+                    yukino::query::ToParam::to_database_value(&self.0)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_newtype_definition() {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    let type_path_resolver = TypePathResolver::default();
+
+    let item: ItemStruct = parse_quote! {
+        pub struct UserId(pub u64);
+    };
+    let definition = NewtypeDefinition::from_item(&item, &type_path_resolver).unwrap();
+    assert_eq!(definition.ident.to_string(), "UserId");
+    assert_eq!(definition.inner.to_token_stream().to_string(), "u64");
+
+    let private: ItemStruct = parse_quote! {
+        struct UserId(u64);
+    };
+    assert!(NewtypeDefinition::from_item(&private, &type_path_resolver).is_err());
+
+    let pair: ItemStruct = parse_quote! {
+        pub struct Pair(pub u64, pub u64);
+    };
+    assert!(!NewtypeDefinition::is_newtype(&pair));
+    assert!(NewtypeDefinition::from_item(&pair, &type_path_resolver).is_err());
+}
@@ -1,16 +1,18 @@
 use crate::annotations::{Entity, FieldAnnotation};
-use crate::definitions::TableDefinition;
+use crate::definitions::{ColumnDefault, FieldDefinition, TableDefinition};
+use crate::query::ast::Expr;
+use crate::query::parse_expr;
 use crate::resolver::error::ResolveError;
 use crate::resolver::{
-    AchievedEntityResolver, AchievedFieldResolver, EntityResolveStatus, EntityResolver,
-    EntityResolverPassBox, FieldResolverBox, FieldResolverSeedBox, FieldResolverStatus,
-    TypePathResolver,
+    AchievedEntityResolver, AchievedFieldResolver, EmbeddableDefinition, EntityResolveStatus,
+    EntityResolver, EntityResolverPassBox, FieldResolverBox, FieldResolverSeedBox,
+    FieldResolverStatus, TypePathResolver,
 };
 use annotation_rs::AnnotationStructure;
 use proc_macro2::{Ident, TokenStream};
 use quote::ToTokens;
 use std::collections::HashMap;
-use syn::{Error as SynError, Fields, ItemStruct};
+use syn::{Error as SynError, Field, Fields, ItemStruct, Visibility};
 
 pub type EntityName = String;
 pub type FieldName = String;
@@ -68,37 +70,73 @@ impl SchemaResolver {
                 )
                 .map_err(|err| err.into_syn_error(&input))?;
 
+            let mut field_resolvers = vec![];
             for field in named_fields.named.iter() {
                 let field_annotations = field
                     .attrs
                     .iter()
                     .map(|attr| FieldAnnotation::from_attr(attr))
                     .collect::<Result<Vec<FieldAnnotation>, SynError>>()?;
-                let field_name = field.ident.as_ref().unwrap().to_string();
                 let field_resolver = self
-                    .field_resolver_seeds
-                    .iter()
-                    .fold(
-                        Err(ResolveError::NoSuitableResolverSeedsFound(
-                            entity_name.clone(),
-                            field_name,
-                        )),
-                        |result, seed| {
-                            if result.is_err() {
-                                seed.try_breed(
-                                    entity_name.clone(),
-                                    field.ident.as_ref().unwrap(),
-                                    &field_annotations,
-                                    &field.ty,
-                                    type_path_resolver,
-                                )
-                                .unwrap_or(result)
-                            } else {
-                                result
-                            }
-                        },
+                    .breed_field_resolver(
+                        entity_name.clone(),
+                        field,
+                        &field_annotations,
+                        type_path_resolver,
                     )
                     .map_err(|e| e.into_syn_error(field))?;
+                let field_path = field_resolver.field_path();
+                let field_default = Self::resolve_field_default(&field_path, &field_annotations)
+                    .map_err(|e| e.into_syn_error(field))?;
+                let field_check = Self::get_field_annotation(&field_annotations)
+                    .and_then(|field_annotation| field_annotation.check.clone());
+                let string_option = Self::get_field_annotation(&field_annotations).map_or(
+                    false,
+                    |field_annotation| {
+                        field_annotation.length.is_some()
+                            || field_annotation.text
+                            || field_annotation.charset.is_some()
+                            || field_annotation.collation.is_some()
+                    },
+                );
+
+                let entity_resolver = self.get_entity_resolver_mut(&entity_name).unwrap();
+                if let Some(default) = field_default {
+                    entity_resolver.set_field_default(field_path.1.clone(), default);
+                }
+                if let Some(check) = field_check {
+                    entity_resolver.add_field_check(field_path.1.clone(), check);
+                }
+                if string_option {
+                    entity_resolver.add_string_option_field(field_path.1.clone());
+                }
+                for annotation in field_annotations.iter() {
+                    match annotation {
+                        FieldAnnotation::Version(_) => {
+                            entity_resolver.set_version_field(field_path.1.clone())
+                        }
+                        FieldAnnotation::CreatedAt(_) => {
+                            entity_resolver.set_created_at_field(field_path.1.clone())
+                        }
+                        FieldAnnotation::UpdatedAt(_) => {
+                            entity_resolver.set_updated_at_field(field_path.1.clone())
+                        }
+                        _ => Ok(()),
+                    }
+                    .map_err(|e| e.into_syn_error(field))?;
+                }
+
+                field_resolvers.push((field, field_resolver));
+            }
+
+            let entity_resolver = self.get_entity_resolver_mut(&entity_name).unwrap();
+            for (_, field_resolver) in field_resolvers.iter() {
+                if field_resolver.is_deferred() {
+                    entity_resolver.defer_field(field_resolver.field_path().1);
+                }
+            }
+
+            for (field, field_resolver) in field_resolvers {
                 let status = field_resolver.status();
                 let field_path = field_resolver.field_path();
 
@@ -113,6 +151,67 @@ impl SchemaResolver {
         }
     }
 
+    /// Resolve fields of embeddable struct, which must not depend on other fields or entities
+    pub fn parse_embeddable(
+        &self,
+        input: ItemStruct,
+        type_path_resolver: &TypePathResolver,
+    ) -> Result<EmbeddableDefinition, SynError> {
+        let name = input.ident.to_string();
+
+        if !input.generics.params.is_empty() {
+            return Err(ResolveError::GenericIsNotSupported(name).into_syn_error(&input));
+        } else if !matches!(&input.vis, Visibility::Public(_)) {
+            return Err(ResolveError::EntityVisibilityMustBePublic(name).into_syn_error(&input));
+        }
+
+        let named_fields = match &input.fields {
+            Fields::Named(named_fields) => named_fields,
+            _ => return Err(ResolveError::UnsupportedEntityStructType.into_syn_error(&input)),
+        };
+
+        let entity_resolver = EntityResolver::new(
+            input.ident.clone(),
+            input.fields.len(),
+            None,
+            vec![],
+            input.clone(),
+        );
+
+        let mut fields = vec![];
+        for field in named_fields.named.iter() {
+            let field_annotations = field
+                .attrs
+                .iter()
+                .map(|attr| FieldAnnotation::from_attr(attr))
+                .collect::<Result<Vec<FieldAnnotation>, SynError>>()?;
+
+            let mut field_resolver = self
+                .breed_field_resolver(name.clone(), field, &field_annotations, type_path_resolver)
+                .map_err(|e| e.into_syn_error(field))?;
+
+            let status = field_resolver.status();
+            if !matches!(status, FieldResolverStatus::WaitingAssemble) {
+                let (entity_name, field_name) = field_resolver.field_path();
+                return Err(ResolveError::UnexpectedFieldResolverStatus(
+                    entity_name,
+                    field_name,
+                    "WaitingAssemble".to_string(),
+                    status,
+                )
+                .into_syn_error(field));
+            }
+
+            fields.push(
+                field_resolver
+                    .assemble(&entity_resolver)
+                    .map_err(|e| e.into_syn_error(field))?,
+            );
+        }
+
+        Ok(EmbeddableDefinition::new(input, fields))
+    }
+
     pub fn achieve(
         self,
         type_path_resolver: &TypePathResolver,
@@ -144,6 +243,73 @@ impl SchemaResolver {
         })
     }
 
+    fn breed_field_resolver(
+        &self,
+        entity_name: EntityName,
+        field: &Field,
+        annotations: &[FieldAnnotation],
+        type_path_resolver: &TypePathResolver,
+    ) -> Result<FieldResolverBox, ResolveError> {
+        let ident = field.ident.as_ref().unwrap();
+
+        self.field_resolver_seeds.iter().fold(
+            Err(ResolveError::NoSuitableResolverSeedsFound(
+                entity_name.clone(),
+                ident.to_string(),
+            )),
+            |result, seed| {
+                if result.is_err() {
+                    seed.try_breed(
+                        entity_name.clone(),
+                        ident,
+                        annotations,
+                        &field.ty,
+                        type_path_resolver,
+                    )
+                    .unwrap_or(result)
+                } else {
+                    result
+                }
+            },
+        )
+    }
+
+    fn get_field_annotation(annotations: &[FieldAnnotation]) -> Option<&crate::annotations::Field> {
+        annotations.iter().find_map(|annotation| match annotation {
+            FieldAnnotation::Field(field) => Some(field),
+            _ => None,
+        })
+    }
+
+    /// Default of column declared by `default` or `generated` of Field annotation
+    fn resolve_field_default(
+        field_path: &FieldPath,
+        annotations: &[FieldAnnotation],
+    ) -> Result<Option<ColumnDefault>, ResolveError> {
+        let (default, generated) = match Self::get_field_annotation(annotations) {
+            Some(field) => (field.default.clone(), field.generated.clone()),
+            None => return Ok(None),
+        };
+
+        match (default, generated) {
+            (Some(_), Some(_)) => Err(ResolveError::ConflictFieldDefault(
+                field_path.0.clone(),
+                field_path.1.clone(),
+            )),
+            (Some(default), None) => match parse_expr(&default) {
+                Ok(Expr::Literal(_)) => Ok(Some(ColumnDefault::Literal(default))),
+                Ok(_) => Ok(Some(ColumnDefault::Expression(default))),
+                Err(e) => Err(ResolveError::InvalidFieldExpression(
+                    field_path.0.clone(),
+                    field_path.1.clone(),
+                    e.to_string(),
+                )),
+            },
+            (None, Some(generated)) => Ok(Some(ColumnDefault::Generated(generated))),
+            (None, None) => Ok(None),
+        }
+    }
+
     fn get_achieved_field_resolver(
         &self,
         field_path: &FieldPath,
@@ -196,7 +362,7 @@ impl SchemaResolver {
                 .map(|path| (path.clone(), self.get_achieved_field_resolver(path).ok()))
                 .collect();
 
-            if resolvers.iter().any(|(_, resolver)| resolver.is_none()) {
+            if resolvers.iter().all(|(_, resolver)| resolver.is_some()) {
                 resolver
                     .resolve_by_waiting_fields(
                         resolvers
@@ -208,7 +374,7 @@ impl SchemaResolver {
             } else {
                 let paths: Vec<_> = resolvers
                     .into_iter()
-                    .filter_map(|(name, item)| item.map(|_| name))
+                    .filter_map(|(name, item)| if item.is_none() { Some(name) } else { None })
                     .collect();
                 for path in paths {
                     if let Some(list) = self.waiting_fields.get_mut(&path) {
@@ -236,13 +402,7 @@ impl SchemaResolver {
         status: EntityResolveStatus,
     ) -> Result<(), ResolveError> {
         if EntityResolveStatus::Finished == status {
-            let empty = vec![];
-
-            let paths = self
-                .waiting_entity
-                .get(entity_name)
-                .unwrap_or_else(|| empty.as_ref())
-                .clone();
+            let paths = self.waiting_entity.remove(entity_name).unwrap_or_default();
 
             for field_path in paths.iter() {
                 let mut resolver = self.remove_field_resolver(field_path)?;
@@ -294,7 +454,9 @@ impl SchemaResolver {
                 let waiting_list = self.waiting_fields.remove(&field_path).unwrap_or(default);
 
                 for field in waiting_list {
-                    self.try_to_resolve_by_fields(&field)?;
+                    if let Some(status) = self.try_to_resolve_by_fields(&field)? {
+                        self.update_field_resolver_status(&field, status)?;
+                    }
                 }
 
                 None
@@ -385,6 +547,13 @@ impl ImmutableSchemaResolver {
             .collect()
     }
 
+    pub fn get_field_definition(&self, entity: &str, field: &str) -> Option<FieldDefinition> {
+        self.resolvers
+            .get(entity)
+            .and_then(|resolver| resolver.field_definitions.get(field))
+            .cloned()
+    }
+
     pub fn get_implements(&self) -> TokenStream {
         self.resolvers
             .values()
@@ -396,3 +565,344 @@ impl ImmutableSchemaResolver {
             })
     }
 }
+
+#[test]
+fn test_self_referencing_association() {
+    use crate::association::AssociatedEntityFieldResolverSeed;
+    use crate::resolver::field_resolver_seeds::NumericFieldResolverSeed;
+    use crate::resolver::FieldResolverSeed;
+    use syn::parse_quote;
+
+    let mut schema_resolver = SchemaResolver::new(
+        vec![
+            Box::new(NumericFieldResolverSeed::new()),
+            Box::new(AssociatedEntityFieldResolverSeed::new()),
+        ],
+        vec![],
+    );
+    let type_path_resolver = TypePathResolver::default();
+
+    schema_resolver
+        .parse(
+            parse_quote! {
+                pub struct Category {
+                    #[ID]
+                    id: u32,
+                    parent: Option<AssociatedEntity<Category>>,
+                }
+            },
+            &type_path_resolver,
+        )
+        .unwrap();
+
+    let achieved = schema_resolver.achieve(&type_path_resolver).unwrap();
+    let parent = achieved.get_field_definition("Category", "parent").unwrap();
+    let association = parent.association.unwrap();
+
+    assert!(parent.nullable);
+    assert_eq!(parent.columns, vec!["parent_id".to_string()]);
+    assert_eq!(association.referenced_entity, "Category");
+    assert_eq!(
+        association.column_map,
+        vec![("parent_id".to_string(), "id".to_string())]
+    );
+}
+
+#[test]
+fn test_field_default() {
+    use crate::resolver::field_resolver_seeds::NumericFieldResolverSeed;
+    use crate::resolver::FieldResolverSeed;
+    use syn::parse_quote;
+
+    let parse = |input: ItemStruct| {
+        let mut schema_resolver =
+            SchemaResolver::new(vec![Box::new(NumericFieldResolverSeed::new())], vec![]);
+        let type_path_resolver = TypePathResolver::default();
+
+        schema_resolver.parse(input, &type_path_resolver)?;
+        schema_resolver.achieve(&type_path_resolver)
+    };
+
+    let achieved = parse(parse_quote! {
+        pub struct Item {
+            #[Field(default = "1")]
+            quantity: u32,
+            price: u32,
+            #[Field(generated = "quantity * price")]
+            total: u32,
+        }
+    })
+    .unwrap();
+
+    let quantity = achieved.get_field_definition("Item", "quantity").unwrap();
+    assert!(matches!(quantity.default, Some(ColumnDefault::Literal(_))));
+
+    let total = achieved.get_field_definition("Item", "total").unwrap();
+    assert!(matches!(total.default, Some(ColumnDefault::Generated(_))));
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[Field(default = "true")]
+            quantity: u32,
+        }
+    })
+    .is_err());
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[Field(generated = "unknown * 2")]
+            total: u32,
+        }
+    })
+    .is_err());
+}
+
+#[test]
+fn test_check_constraint() {
+    use crate::resolver::field_resolver_seeds::NumericFieldResolverSeed;
+    use crate::resolver::FieldResolverSeed;
+    use syn::parse_quote;
+
+    let parse = |input: ItemStruct| {
+        let mut schema_resolver =
+            SchemaResolver::new(vec![Box::new(NumericFieldResolverSeed::new())], vec![]);
+        let type_path_resolver = TypePathResolver::default();
+
+        schema_resolver.parse(input, &type_path_resolver)?;
+        schema_resolver.achieve(&type_path_resolver)
+    };
+
+    let achieved = parse(parse_quote! {
+        pub struct Item {
+            #[Field(name = "unit_price", check = "value > 0")]
+            price: u32,
+        }
+    })
+    .unwrap();
+
+    let definitions = achieved.get_definitions();
+    let checks = &definitions[0].checks;
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].name, "price_check");
+    assert_eq!(checks[0].expr, "unit_price > 0");
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[Field(check = "value + 1")]
+            price: u32,
+        }
+    })
+    .is_err());
+}
+
+#[test]
+fn test_version_field() {
+    use crate::resolver::field_resolver_seeds::NumericFieldResolverSeed;
+    use crate::resolver::FieldResolverSeed;
+    use syn::parse_quote;
+
+    let parse = |input: ItemStruct| {
+        let mut schema_resolver =
+            SchemaResolver::new(vec![Box::new(NumericFieldResolverSeed::new())], vec![]);
+        let type_path_resolver = TypePathResolver::default();
+
+        schema_resolver.parse(input, &type_path_resolver)?;
+        schema_resolver.achieve(&type_path_resolver)
+    };
+
+    let achieved = parse(parse_quote! {
+        pub struct Item {
+            #[ID]
+            id: u32,
+            #[Version]
+            #[Field(name = "row_version")]
+            version: u64,
+        }
+    })
+    .unwrap();
+
+    let definitions = achieved.get_definitions();
+    assert_eq!(definitions[0].version.as_deref(), Some("row_version"));
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[ID]
+            #[Version]
+            id: u32,
+        }
+    })
+    .is_err());
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[Version]
+            version: Option<u32>,
+        }
+    })
+    .is_err());
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[Version]
+            version: u32,
+            #[Version]
+            revision: u32,
+        }
+    })
+    .is_err());
+}
+
+#[test]
+#[cfg(feature = "data-time")]
+fn test_timestamp_field() {
+    use crate::resolver::field_resolver_seeds::{
+        DateTimeFieldResolverSeed, NumericFieldResolverSeed,
+    };
+    use crate::resolver::FieldResolverSeed;
+    use syn::parse_quote;
+
+    let parse = |input: ItemStruct| {
+        let mut schema_resolver = SchemaResolver::new(
+            vec![
+                Box::new(NumericFieldResolverSeed::new()),
+                Box::new(DateTimeFieldResolverSeed::new()),
+            ],
+            vec![],
+        );
+        let type_path_resolver = TypePathResolver::default();
+
+        schema_resolver.parse(input, &type_path_resolver)?;
+        schema_resolver.achieve(&type_path_resolver)
+    };
+
+    let achieved = parse(parse_quote! {
+        pub struct Item {
+            #[CreatedAt]
+            created: PrimitiveDateTime,
+            #[UpdatedAt]
+            updated: Option<PrimitiveDateTime>,
+        }
+    })
+    .unwrap();
+
+    let definitions = achieved.get_definitions();
+    assert_eq!(definitions[0].created_at.as_deref(), Some("created"));
+    assert_eq!(definitions[0].updated_at.as_deref(), Some("updated"));
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[UpdatedAt]
+            updated: u64,
+        }
+    })
+    .is_err());
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[CreatedAt]
+            created: PrimitiveDateTime,
+            #[CreatedAt]
+            inserted: PrimitiveDateTime,
+        }
+    })
+    .is_err());
+}
+
+#[test]
+fn test_string_options() {
+    use crate::resolver::field_resolver_seeds::{
+        NumericFieldResolverSeed, StringFieldResolverSeed, STRING_DEFAULT_LENGTH,
+    };
+    use crate::resolver::FieldResolverSeed;
+    use syn::parse_quote;
+
+    let parse = |input: ItemStruct| {
+        let mut schema_resolver = SchemaResolver::new(
+            vec![
+                Box::new(NumericFieldResolverSeed::new()),
+                Box::new(StringFieldResolverSeed::new()),
+            ],
+            vec![],
+        );
+        let type_path_resolver = TypePathResolver::default();
+
+        schema_resolver.parse(input, &type_path_resolver)?;
+        schema_resolver.achieve(&type_path_resolver)
+    };
+
+    let achieved = parse(parse_quote! {
+        pub struct Item {
+            #[Field(length = 64)]
+            code: String,
+            name: String,
+            #[Field(text = true)]
+            description: String,
+        }
+    })
+    .unwrap();
+    let length = |field: &str| achieved.get_field_definition("Item", field).unwrap().length;
+
+    assert_eq!(length("code"), Some(64));
+    assert_eq!(length("name"), Some(STRING_DEFAULT_LENGTH));
+    assert_eq!(length("description"), None);
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[Field(length = 8)]
+            quantity: u32,
+        }
+    })
+    .is_err());
+
+    assert!(parse(parse_quote! {
+        pub struct Item {
+            #[Field(collation = "utf8mb4_bin")]
+            quantity: u32,
+        }
+    })
+    .is_err());
+}
+
+#[test]
+fn test_hidden_primary_key() {
+    use crate::definitions::ColumnType;
+    use crate::resolver::entity_resolver_passes::EntityImplementResolverPass;
+    use crate::resolver::field_resolver_seeds::NumericFieldResolverSeed;
+    use crate::resolver::{EntityResolverPass, FieldResolverSeed, HiddenPrimaryKey};
+    use syn::parse_quote;
+
+    let mut schema_resolver = SchemaResolver::new(
+        vec![Box::new(NumericFieldResolverSeed::new())],
+        vec![Box::new(EntityImplementResolverPass::new())],
+    );
+    let type_path_resolver = TypePathResolver::default();
+
+    schema_resolver
+        .parse(
+            parse_quote! {
+                pub struct Item {
+                    quantity: u32,
+                }
+            },
+            &type_path_resolver,
+        )
+        .unwrap();
+
+    let achieved = schema_resolver.achieve(&type_path_resolver).unwrap();
+    let definitions = achieved.get_definitions();
+    let primary_key = HiddenPrimaryKey::from_definitions("Item", &definitions).unwrap();
+
+    assert_eq!(primary_key.column_name(), "__item_id");
+    assert!(definitions
+        .iter()
+        .flat_map(|definition| definition.columns.iter())
+        .any(|column| column.name == "__item_id"
+            && column.primary_key
+            && matches!(column.ty, ColumnType::VisualColumn)));
+
+    let implements = achieved.get_implements().to_string();
+    let primary_key_values = implements.split("fn primary_key_values").nth(1).unwrap();
+
+    assert!(primary_key_values.contains(&primary_key.converter_getter_ident().to_string()));
+}
@@ -1,13 +1,17 @@
 use crate::resolver::error::ResolveError;
-use proc_macro2::Ident;
+use crate::resolver::{EmbeddableDefinition, EnumDefinition, NewtypeDefinition};
+use proc_macro2::{Ident, TokenStream};
 use std::collections::HashMap;
-use syn::{ItemUse, PathSegment, Type, TypePath, UseTree};
+use syn::{Error as SynError, ItemEnum, ItemStruct, ItemUse, PathSegment, Type, TypePath, UseTree};
 
 pub type FullPath = Vec<Ident>;
 pub type TypeName = String;
 
 pub struct TypePathResolver {
     maps: HashMap<TypeName, FullPath>,
+    enums: HashMap<TypeName, EnumDefinition>,
+    embeddables: HashMap<TypeName, EmbeddableDefinition>,
+    newtypes: HashMap<TypeName, NewtypeDefinition>,
 }
 
 impl Default for TypePathResolver {
@@ -20,6 +24,9 @@ impl TypePathResolver {
     pub fn new() -> TypePathResolver {
         TypePathResolver {
             maps: HashMap::new(),
+            enums: HashMap::new(),
+            embeddables: HashMap::new(),
+            newtypes: HashMap::new(),
         }
     }
 
@@ -31,6 +38,78 @@ impl TypePathResolver {
         Ok(())
     }
 
+    pub fn append_enum_item(&mut self, item: &ItemEnum) -> Result<(), SynError> {
+        let definition = EnumDefinition::from_item(item)?;
+
+        self.enums.insert(item.ident.to_string(), definition);
+
+        Ok(())
+    }
+
+    /// Enum declared in schema file
+    pub fn get_enum(&self, ty: &Type) -> Option<&EnumDefinition> {
+        match ty {
+            Type::Path(type_path) if type_path.qself.is_none() => type_path
+                .path
+                .get_ident()
+                .and_then(|ident| self.enums.get(&ident.to_string())),
+            _ => None,
+        }
+    }
+
+    pub fn append_newtype_item(&mut self, item: &ItemStruct) -> Result<(), SynError> {
+        let definition = NewtypeDefinition::from_item(item, self)?;
+
+        self.newtypes.insert(item.ident.to_string(), definition);
+
+        Ok(())
+    }
+
+    /// Tuple struct with a single field declared in schema file
+    pub fn get_newtype(&self, ty: &Type) -> Option<&NewtypeDefinition> {
+        match ty {
+            Type::Path(type_path) if type_path.qself.is_none() => type_path
+                .path
+                .get_ident()
+                .and_then(|ident| self.newtypes.get(&ident.to_string())),
+            _ => None,
+        }
+    }
+
+    pub fn append_embeddable(&mut self, definition: EmbeddableDefinition) {
+        self.embeddables
+            .insert(definition.ident.to_string(), definition);
+    }
+
+    /// Embeddable struct declared in schema file
+    pub fn get_embeddable(&self, ty: &Type) -> Option<&EmbeddableDefinition> {
+        match ty {
+            Type::Path(type_path) if type_path.qself.is_none() => type_path
+                .path
+                .get_ident()
+                .and_then(|ident| self.embeddables.get(&ident.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Implements of enums, newtypes and embeddable structs declared in schema file
+    pub fn get_implements(&self) -> TokenStream {
+        self.enums
+            .values()
+            .map(|definition| definition.get_implement())
+            .chain(
+                self.newtypes
+                    .values()
+                    .map(|definition| definition.get_implement()),
+            )
+            .chain(
+                self.embeddables
+                    .values()
+                    .map(|definition| definition.get_implement()),
+            )
+            .collect()
+    }
+
     fn resolve_use_tree(tree: &UseTree) -> Result<Vec<(TypeName, FullPath)>, ResolveError> {
         Ok(match tree {
             UseTree::Name(use_name) => {
@@ -0,0 +1,16 @@
+use crate::resolver::error::DataConvertError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TransactionError {
+    #[error("RestrictedByForeignKey: Row is still referenced by foreign key({1}) of table {0}")]
+    RestrictedByForeignKey(String, String),
+    #[error("CheckViolation: Check constraint({1}) of table {0} is violated")]
+    CheckViolation(String, String),
+    #[error(
+        "StaleEntity: Row of table {0} has been changed or deleted since the entity was loaded"
+    )]
+    StaleEntity(String),
+    #[error("DataConvertError: {0}")]
+    DataConvertError(#[from] DataConvertError),
+}
@@ -1,10 +1,14 @@
+use crate::definitions::TableType;
+use crate::error::TransactionError;
 use crate::query::QueryBuilderFactory;
 use crate::repository::Repository;
 use crate::transaction::repository_container::RepositoryContainer;
 use crate::{Entity, EntityProxy};
 
+pub mod error;
 mod repository_container;
 
+#[cfg_attr(test, derive(Default))]
 pub struct Transaction {
     repository_container: RepositoryContainer,
 }
@@ -21,6 +25,28 @@ impl Transaction {
         P::create_proxy(value(), self)
     }
 
+    /// Delete entity, referential actions of associations are applied on entities in this
+    /// transaction
+    pub fn delete_entity<'t, E: Entity + Clone, P: EntityProxy<'t, E>>(
+        &'t self,
+        entity_proxy: P,
+    ) -> Result<(), TransactionError> {
+        let values = entity_proxy.inner().to_database_values()?;
+
+        if let Some(table) = E::get_definitions()
+            .into_iter()
+            .find(|definition| matches!(definition.ty, TableType::NormalEntityTable(_)))
+        {
+            self.repository_container
+                .cascade_delete(&table.name, &values)?;
+        }
+
+        // todo: delete from db
+        drop(entity_proxy);
+
+        Ok(())
+    }
+
     pub fn create_query_builder(&self) -> QueryBuilderFactory {
         QueryBuilderFactory::create(self)
     }
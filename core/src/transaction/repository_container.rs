@@ -1,13 +1,21 @@
-use crate::repository::{Repository, RepositoryInternal};
-use crate::Entity;
+use crate::error::TransactionError;
+use crate::repository::{ReferentialChange, Repository, RepositoryInternal};
+use crate::types::ValuePack;
+use crate::{Entity, EntityUniqueID};
 use std::any::TypeId;
 use std::cell::UnsafeCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::mem::size_of;
 
+type PlanDelete =
+    fn(&RepositoryBox, &str, &ValuePack) -> Result<Vec<ReferentialChange>, TransactionError>;
+type ApplyChanges = fn(&RepositoryBox, Vec<ReferentialChange>) -> Result<(), TransactionError>;
+
 struct RepositoryBox {
     storage: Vec<u8>,
     type_id: TypeId,
+    plan_delete: PlanDelete,
+    apply_changes: ApplyChanges,
 }
 
 impl RepositoryBox {
@@ -17,7 +25,16 @@ impl RepositoryBox {
         let ptr: *mut Repository<E> = &mut v;
         let storage = unsafe { Vec::from_raw_parts(ptr as *mut u8, size, size) };
 
-        RepositoryBox { storage, type_id }
+        RepositoryBox {
+            storage,
+            type_id,
+            plan_delete: |repository_box, table, values| {
+                repository_box.as_ref::<E>().plan_delete(table, values)
+            },
+            apply_changes: |repository_box, changes| {
+                repository_box.as_ref::<E>().apply_changes(changes)
+            },
+        }
     }
 
     pub fn as_ref<E: Entity + Clone>(&self) -> &Repository<E> {
@@ -43,6 +60,38 @@ impl RepositoryContainer {
 
         repositories.get(&type_id).unwrap().as_ref()
     }
+
+    /// Apply referential actions on pooled entities of all repositories after a row of `table` is
+    /// deleted, nothing is changed if any of them is restricted
+    pub fn cascade_delete(&self, table: &str, values: &ValuePack) -> Result<(), TransactionError> {
+        let repositories = unsafe { self.repositories.get().as_ref().unwrap() };
+        let mut deleted: HashSet<(TypeId, EntityUniqueID)> = HashSet::new();
+        let mut changes: HashMap<TypeId, Vec<ReferentialChange>> = HashMap::new();
+        let mut queue = vec![(table.to_string(), values.clone())];
+
+        while let Some((table, values)) = queue.pop() {
+            for (type_id, repository_box) in repositories.iter() {
+                for change in (repository_box.plan_delete)(repository_box, &table, &values)? {
+                    if let ReferentialChange::Drop(id, table, values) = &change {
+                        if !deleted.insert((*type_id, *id)) {
+                            continue;
+                        }
+
+                        queue.push((table.clone(), values.clone()));
+                    }
+
+                    changes.entry(*type_id).or_default().push(change);
+                }
+            }
+        }
+
+        for (type_id, changes) in changes {
+            let repository_box = repositories.get(&type_id).unwrap();
+            (repository_box.apply_changes)(repository_box, changes)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for RepositoryContainer {
@@ -1,12 +1,16 @@
 use crate::resolver::error::DataConvertError;
+#[cfg(any(feature = "decimal"))]
+use crate::resolver::field_resolver_seeds::DECIMAL_MAX_PRECISION;
+use crate::resolver::field_resolver_seeds::STRING_DEFAULT_LENGTH;
 #[doc(hidden)]
 use iroha::ToTokens;
 #[doc(hidden)]
 use serde_json::Value;
 use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
 #[doc(hidden)]
 #[cfg(any(feature = "data-time"))]
-use time::{Date, PrimitiveDateTime, Time};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
 
 /// Field type in Yukino, may be different depends on platform or feature configuration
 #[derive(Copy, Clone, ToTokens, Debug, Eq, PartialEq)]
@@ -23,8 +27,9 @@ pub enum DatabaseType {
     Float,
     Double,
 
+    /// Precision and scale
     #[cfg(any(feature = "decimal"))]
-    Decimal(u32),
+    Decimal(u32, u32),
 
     Binary,
 
@@ -38,8 +43,11 @@ pub enum DatabaseType {
     Timestamp,
 
     Character,
-    String,
+    /// Max length
+    String(u32),
     Text,
+    /// Native `ENUM` column, variants are stored as string
+    Enum,
 
     #[cfg(any(feature = "json"))]
     Json,
@@ -51,7 +59,7 @@ pub type Binary = Vec<u8>;
 pub type ValuePack = HashMap<String, DatabaseValue>;
 
 /// Raw data of database. It can be automatically converted to and from variables in the entity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum DatabaseValue {
     Bool(bool),
     SmallInteger(i16),
@@ -98,7 +106,7 @@ impl DatabaseType {
                 | Self::Date
                 | Self::Time
                 | Self::Binary
-                | Self::Decimal(_)
+                | Self::Decimal(_, _)
                 | Self::Double
                 | Self::Float
                 | Self::Bool
@@ -106,6 +114,26 @@ impl DatabaseType {
     }
 }
 
+impl DatabaseType {
+    /// Integer or time point types, which can be increased as version of optimistic locking
+    pub fn suitable_for_version(&self) -> bool {
+        matches!(
+            self,
+            Self::SmallInteger
+                | Self::UnsignedSmallInteger
+                | Self::Integer
+                | Self::UnsignedInteger
+                | Self::BigInteger
+                | Self::UnsignedBigInteger
+        ) || self.suitable_for_timestamp()
+    }
+
+    /// Time point types, which can be filled by current time
+    pub fn suitable_for_timestamp(&self) -> bool {
+        matches!(self, Self::DateTime | Self::Timestamp)
+    }
+}
+
 impl From<&DatabaseValue> for DatabaseType {
     fn from(database_value: &DatabaseValue) -> Self {
         match database_value {
@@ -118,14 +146,16 @@ impl From<&DatabaseValue> for DatabaseType {
             DatabaseValue::UnsignedBigInteger(_) => DatabaseType::UnsignedBigInteger,
             DatabaseValue::Float(_) => DatabaseType::Float,
             DatabaseValue::Double(_) => DatabaseType::Double,
-            DatabaseValue::Decimal(value) => DatabaseType::Decimal(value.scale()),
+            DatabaseValue::Decimal(value) => {
+                DatabaseType::Decimal(DECIMAL_MAX_PRECISION, value.scale())
+            }
             DatabaseValue::Binary(_) => DatabaseType::Binary,
             DatabaseValue::Time(_) => DatabaseType::Time,
             DatabaseValue::Date(_) => DatabaseType::Date,
             DatabaseValue::DateTime(_) => DatabaseType::DateTime,
             DatabaseValue::Timestamp(_) => DatabaseType::Timestamp,
             DatabaseValue::Character(_) => DatabaseType::Character,
-            DatabaseValue::String(_) => DatabaseType::String,
+            DatabaseValue::String(_) => DatabaseType::String(STRING_DEFAULT_LENGTH),
             DatabaseValue::Text(_) => DatabaseType::Text,
             DatabaseValue::Json(_) => DatabaseType::Json,
             DatabaseValue::Null(ty) => *ty,
@@ -152,4 +182,88 @@ impl DatabaseValue {
             Err(DataConvertError::UnsuitableColumnDataTypeForPrimaryKey)
         }
     }
+
+    /// Next version of optimistic locking. Integers are increased, time points are refreshed to
+    /// current time and always move forward
+    pub fn next_version(&self) -> Option<DatabaseValue> {
+        Some(match self {
+            DatabaseValue::SmallInteger(value) => {
+                DatabaseValue::SmallInteger(value.wrapping_add(1))
+            }
+            DatabaseValue::UnsignedSmallInteger(value) => {
+                DatabaseValue::UnsignedSmallInteger(value.wrapping_add(1))
+            }
+            DatabaseValue::Integer(value) => DatabaseValue::Integer(value.wrapping_add(1)),
+            DatabaseValue::UnsignedInteger(value) => {
+                DatabaseValue::UnsignedInteger(value.wrapping_add(1))
+            }
+            DatabaseValue::BigInteger(value) => DatabaseValue::BigInteger(value.wrapping_add(1)),
+            DatabaseValue::UnsignedBigInteger(value) => {
+                DatabaseValue::UnsignedBigInteger(value.wrapping_add(1))
+            }
+            #[cfg(any(feature = "data-time"))]
+            DatabaseValue::DateTime(value) => {
+                let now = now_date_time();
+
+                DatabaseValue::DateTime(if now > *value {
+                    now
+                } else {
+                    *value + Duration::microseconds(1)
+                })
+            }
+            DatabaseValue::Timestamp(value) => {
+                DatabaseValue::Timestamp(now_timestamp().max(value + 1))
+            }
+            _ => return None,
+        })
+    }
+
+    /// Whether `self` holds the same value as `other`. Unlike `==`, NaN floats are the same as
+    /// themselves, so an unchanged NaN column is not taken as a modification
+    pub fn same_value(&self, other: &DatabaseValue) -> bool {
+        match (self, other) {
+            (DatabaseValue::Float(left), DatabaseValue::Float(right)) => {
+                left == right || left.is_nan() && right.is_nan()
+            }
+            (DatabaseValue::Double(left), DatabaseValue::Double(right)) => {
+                left == right || left.is_nan() && right.is_nan()
+            }
+            _ => self == other,
+        }
+    }
+
+    /// Current time as value of column in type `ty`, `None` if `ty` is not a time point type
+    pub fn now(ty: DatabaseType) -> Option<DatabaseValue> {
+        match ty {
+            #[cfg(any(feature = "data-time"))]
+            DatabaseType::DateTime => Some(DatabaseValue::DateTime(now_date_time())),
+            DatabaseType::Timestamp => Some(DatabaseValue::Timestamp(now_timestamp())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(any(feature = "data-time"))]
+fn now_date_time() -> PrimitiveDateTime {
+    let now = OffsetDateTime::now_utc();
+
+    PrimitiveDateTime::new(now.date(), now.time())
+}
+
+/// Seconds since unix epoch
+fn now_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_same_value() {
+    assert!(DatabaseValue::Double(f64::NAN).same_value(&DatabaseValue::Double(f64::NAN)));
+    assert!(DatabaseValue::Float(f32::NAN).same_value(&DatabaseValue::Float(f32::NAN)));
+    assert!(DatabaseValue::Double(1.5).same_value(&DatabaseValue::Double(1.5)));
+    assert!(!DatabaseValue::Double(f64::NAN).same_value(&DatabaseValue::Double(1.5)));
+    assert!(!DatabaseValue::Float(f32::NAN).same_value(&DatabaseValue::Double(f64::NAN)));
+    assert!(!DatabaseValue::Integer(1).same_value(&DatabaseValue::Integer(2)));
 }
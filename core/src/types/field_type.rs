@@ -1,7 +1,8 @@
 use crate::definitions::FieldDefinition;
 use crate::query::ast::error::{SyntaxError, SyntaxErrorWithPos};
 use crate::query::ast::{
-    BinaryOperator, ColumnIdent, Expr, JoinClause, Literal, Locatable, Location, UnaryOperator,
+    BinaryOperator, ColumnIdent, Expr, FunctionCall, JoinClause, JsonAccess, Literal, Locatable,
+    Location, UnaryOperator,
 };
 use crate::query::type_check::TypeKind;
 use std::fmt::{Display, Formatter, Result as FmtResult};
@@ -16,6 +17,8 @@ pub struct TypeInfo {
     pub resolver_name: String,
     pub field_type: String,
     pub nullable: bool,
+    /// Max length of string value, None if unbounded or unknown
+    pub length: Option<u32>,
     pub type_kind: TypeKind,
 }
 
@@ -48,8 +51,31 @@ pub trait TypeResolver {
 
     fn name(&self) -> String;
 
+    /// Whether values of the two types can be used together, nullability is not considered
     fn cmp_type_info(&self, a: &TypeInfo, b: &TypeInfo) -> bool {
-        a == b
+        a.resolver_name == b.resolver_name
+            && a.field_type == b.field_type
+            && a.type_kind == b.type_kind
+    }
+
+    /// Whether value of type `value` can be assigned to column of type `target`
+    fn is_assignable(&self, target: &TypeInfo, value: &TypeInfo) -> bool {
+        self.cmp_type_info(target, value)
+    }
+
+    /// Type info of cast target type name, e.g. `i64` in `CAST(expr AS i64)`
+    fn cast_type_info(&self, _ty: &str) -> Option<TypeInfo> {
+        None
+    }
+
+    /// Type info of typed literal prefix, e.g. `date` in `date"2021-01-01"`
+    fn literal_type_info(&self, _prefix: &str) -> Option<TypeInfo> {
+        None
+    }
+
+    /// Whether function with the lowercase name is provided by the resolver
+    fn has_function(&self, _name: &str) -> bool {
+        false
     }
 
     fn wrap_lit(
@@ -89,6 +115,54 @@ pub trait TypeResolver {
         )))
     }
 
+    fn handle_cast(
+        &self,
+        item: ExprWrapper,
+        _type_info: TypeInfo,
+        location: Location,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        Err(location.error(SyntaxError::UnimplementedOperationForType(
+            "Cast".to_string(),
+            item.type_info.to_string(),
+        )))
+    }
+
+    /// Expected type of the function argument at `index` which cannot be inferred
+    fn function_argument_type_info(
+        &self,
+        _name: &str,
+        _arguments: &[Option<ExprWrapper>],
+        _index: usize,
+    ) -> Option<TypeInfo> {
+        None
+    }
+
+    /// Handle function call, `arguments` are the wrapped parameters, `None` for the parameters
+    /// whose type cannot be inferred (e.g. literals)
+    fn handle_function(
+        &self,
+        call: &FunctionCall,
+        _arguments: Vec<Option<ExprWrapper>>,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        Err(call
+            .location()
+            .error(SyntaxError::UnknownFunction(call.ident.clone())))
+    }
+
+    /// Handle path access of JSON value, e.g. `f.map->"key"`
+    fn handle_json_access(
+        &self,
+        item: ExprWrapper,
+        _access: &JsonAccess,
+    ) -> Result<ExprWrapper, SyntaxErrorWithPos> {
+        Err(item
+            .location()
+            .error(SyntaxError::UnimplementedOperationForType(
+                "JsonAccess".to_string(),
+                item.type_info.to_string(),
+            )))
+    }
+
     fn unwrap_expr(&self, mut wrapper: ExprWrapper) -> Result<Expr, SyntaxErrorWithPos> {
         Ok(wrapper.exprs.pop().unwrap())
     }
@@ -1,10 +1,42 @@
 extern crate proc_macro;
+mod query;
+
 use proc_macro::TokenStream;
+use syn::parse_macro_input;
 
 #[proc_macro_derive(
     Yukino,
-    attributes(Entity, Index, Field, ID, Ignore, Association, InverseAssociation)
+    attributes(
+        Entity,
+        Index,
+        Field,
+        ID,
+        Version,
+        CreatedAt,
+        UpdatedAt,
+        Ignore,
+        Association,
+        InverseAssociation
+    )
 )]
 pub fn entity_derive(_: TokenStream) -> TokenStream {
     TokenStream::new()
 }
+
+/// Parse and type check a query at compile time.
+///
+/// ```ignore
+/// let query = query!("select f.integer from Foo f where f.string = $name", name = name);
+/// ```
+///
+/// Entities are resolved from `schema/schema.rs` under the crate root by default, it can be
+/// changed by `query!(schema = "path/to/schema.rs", "...")`.
+#[proc_macro]
+pub fn query(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as query::QueryInput);
+
+    input
+        .expand()
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
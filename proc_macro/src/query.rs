@@ -0,0 +1,167 @@
+use core::query::ast::error::SyntaxErrorWithPos;
+use core::query::{check_query, default_type_resolvers, parse_query};
+use core::resolver::FileResolver;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, Expr, Ident, LitStr, Token, Type};
+
+const DEFAULT_SCHEMA_FILE: &str = "schema/schema.rs";
+
+pub struct QueryInput {
+    schema: Option<LitStr>,
+    query: LitStr,
+    params: Vec<(Ident, Expr)>,
+}
+
+impl Parse for QueryInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let schema = if input.peek(Ident) && input.peek2(Token![=]) {
+            let ident: Ident = input.parse()?;
+            if ident != "schema" {
+                return Err(Error::new_spanned(
+                    ident,
+                    "Expect `schema` or query string literal",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let schema = input.parse()?;
+            input.parse::<Token![,]>()?;
+
+            Some(schema)
+        } else {
+            None
+        };
+
+        let query = input.parse()?;
+        let mut params = vec![];
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            params.push((ident, input.parse()?));
+        }
+
+        Ok(QueryInput {
+            schema,
+            query,
+            params,
+        })
+    }
+}
+
+impl QueryInput {
+    pub fn expand(self) -> syn::Result<TokenStream> {
+        let query_string = self.query.value();
+        let query = parse_query(&query_string).map_err(|e| self.spanned_error(e))?;
+
+        let schema_file = format!(
+            "{}/{}",
+            std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string()),
+            self.schema
+                .as_ref()
+                .map(|lit| lit.value())
+                .unwrap_or_else(|| DEFAULT_SCHEMA_FILE.to_string())
+        );
+
+        let schema = FileResolver::new(vec![], vec![], schema_file)
+            .map_err(|e| Error::new(self.schema_span(), e))?
+            .resolve()
+            .map_err(|e| Error::new(self.schema_span(), e))?;
+
+        let checked = check_query(
+            query,
+            default_type_resolvers(),
+            |entity, field| schema.get_field_definition(entity, field),
+            schema.get_definitions(),
+        )
+        .map_err(|e| self.spanned_error(e))?;
+
+        let mut params: HashMap<_, _> = self
+            .params
+            .iter()
+            .map(|(ident, expr)| (ident.to_string(), (ident, expr)))
+            .collect();
+
+        let mut assertions: Vec<_> = checked.external_value_assertion.iter().collect();
+        assertions.sort();
+
+        let mut field_definitions = vec![];
+        let mut field_values = vec![];
+
+        for (name, ty) in assertions {
+            let (ident, expr) = params.remove(name).ok_or_else(|| {
+                Error::new(
+                    self.query.span(),
+                    format!("Missing value for external value `${}`", name),
+                )
+            })?;
+            let field_type: Type = syn::parse_str(ty)
+                .map_err(|_| Error::new_spanned(ident, format!("Unsupported type `{}`", ty)))?;
+
+            field_definitions.push(quote! {
+                pub #ident: #field_type
+            });
+            field_values.push(quote! {
+                #ident: #expr
+            });
+        }
+
+        if let Some((ident, _)) = params.values().next() {
+            return Err(Error::new_spanned(
+                ident,
+                format!("Unused external value `${}`", ident),
+            ));
+        }
+
+        let mut result_types: Vec<_> = checked.result_types.iter().collect();
+        result_types.sort();
+
+        let result_types = result_types.into_iter().map(|(alias, ty)| {
+            quote! {
+                (#alias, #ty)
+            }
+        });
+
+        let params_ident = format_ident!("QueryParams");
+
+        Ok(quote! {
+            {
+                pub struct #params_ident {
+                    #(#field_definitions,)*
+                }
+
+                yukino::query::TypedQuery::new(
+                    #query_string,
+                    vec![#(#result_types),*],
+                    #params_ident {
+                        #(#field_values,)*
+                    }
+                )
+            }
+        })
+    }
+
+    fn schema_span(&self) -> Span {
+        self.schema
+            .as_ref()
+            .map(|lit| lit.span())
+            .unwrap_or_else(|| self.query.span())
+    }
+
+    /// Error on the query literal, position in query is reported as `line:column` since offsets
+    /// of query string can not be mapped to the literal with escapes or raw string prefix
+    fn spanned_error(&self, error: SyntaxErrorWithPos) -> Error {
+        let (line, column) = error.location.line_col(&self.query.value());
+
+        Error::new(
+            self.query.span(),
+            format!("{} at {}:{}", error.error, line, column),
+        )
+    }
+}
@@ -1,4 +1,4 @@
 pub use core::*;
 
 pub use cli::*;
-pub use proc_macro::Yukino;
+pub use proc_macro::{query, Yukino};
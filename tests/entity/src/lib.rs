@@ -1,2 +1 @@
-#![feature(concat_idents)]
 pub mod schema;
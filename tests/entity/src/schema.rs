@@ -49,19 +49,29 @@ impl yukino::Entity for BarInner {
                 false,
                 true,
                 false,
+                None,
+                None,
+                None,
+                vec![],
             )],
             vec![],
             vec![],
+            vec![],
+            None,
+            None,
+            None,
         )]
     }
     fn get_field_definition(field_name: &str) -> Option<yukino::definitions::FieldDefinition> {
         match field_name {
             "id" => Some(yukino::definitions::FieldDefinition::new(
-                "id".to_string(),
+                "Bar".to_string(),
                 "id".to_string(),
                 "numeric".to_string(),
                 "u64".to_string(),
                 false,
+                None,
+                None,
                 vec!["id".to_string()],
                 vec![],
                 None,
@@ -81,6 +91,25 @@ impl yukino::Entity for BarInner {
         Ok(map)
     }
 }
+#[derive(Clone)]
+pub struct BarFields {
+    segments: Vec<String>,
+}
+impl BarFields {
+    pub fn from_segments(segments: Vec<String>) -> Self {
+        BarFields { segments }
+    }
+    pub fn id(&self) -> yukino::query::Field<BarInner, u64> {
+        let mut segments = self.segments.clone();
+        segments.push("id".to_string());
+        yukino::query::Field::new(segments)
+    }
+}
+impl BarInner {
+    pub fn fields(alias: &str) -> BarFields {
+        BarFields::from_segments(vec![alias.to_string()])
+    }
+}
 pub struct Bar<'t> {
     inner: std::cell::UnsafeCell<BarInner>,
     unique_id: Option<yukino::EntityUniqueID>,
@@ -150,49 +179,16 @@ pub struct FooInner {
     option_string: Option<String>,
     option_num: Option<u32>,
     bar: yukino::collection::AssociatedEntity<BarInner>,
+    __foo_id: String,
 }
 impl FooInner {
-    pub fn get_option_num_converter(
-    ) -> yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter {
-        yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter::new(
-            false,
-            "option_num".to_string(),
-            "Foo".to_string(),
-            "option_num".to_string(),
-            yukino::types::DatabaseType::UnsignedInteger,
-        )
-    }
-    pub fn get_list_converter() -> yukino::resolver::field_resolver_seeds::ListValueConverter {
-        yukino::resolver::field_resolver_seeds::ListValueConverter::new(
-            "Foo".to_string(),
-            "list".to_string(),
-            "list".to_string(),
-        )
-    }
-    pub fn get_integer_converter(
-    ) -> yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter {
-        yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter::new(
-            false,
-            "integer".to_string(),
-            "Foo".to_string(),
-            "integer".to_string(),
-            yukino::types::DatabaseType::UnsignedInteger,
-        )
-    }
-    pub fn get_map_converter() -> yukino::resolver::field_resolver_seeds::MapValueConverter {
-        yukino::resolver::field_resolver_seeds::MapValueConverter::new(
-            "Foo".to_string(),
-            "map".to_string(),
-            "map".to_string(),
-        )
-    }
-    pub fn get_option_string_converter(
-    ) -> yukino::resolver::field_resolver_seeds::StringValueConverter {
+    pub fn get_string_converter() -> yukino::resolver::field_resolver_seeds::StringValueConverter {
         yukino::resolver::field_resolver_seeds::StringValueConverter::new(
             false,
             "Foo".to_string(),
-            "option_string".to_string(),
-            "option_string".to_string(),
+            "string".to_string(),
+            "string".to_string(),
+            yukino::types::DatabaseType::String(255u32),
         )
     }
     pub fn get_int16_converter(
@@ -205,12 +201,14 @@ impl FooInner {
             yukino::types::DatabaseType::SmallInteger,
         )
     }
-    pub fn get_string_converter() -> yukino::resolver::field_resolver_seeds::StringValueConverter {
+    pub fn get_option_string_converter(
+    ) -> yukino::resolver::field_resolver_seeds::StringValueConverter {
         yukino::resolver::field_resolver_seeds::StringValueConverter::new(
             false,
             "Foo".to_string(),
-            "string".to_string(),
-            "string".to_string(),
+            "option_string".to_string(),
+            "option_string".to_string(),
+            yukino::types::DatabaseType::String(255u32),
         )
     }
     pub fn get_boolean_converter() -> yukino::resolver::field_resolver_seeds::BoolValueConverter {
@@ -239,6 +237,50 @@ impl FooInner {
             std::marker::PhantomData::default(),
         )
     }
+    pub fn get_integer_converter(
+    ) -> yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter {
+        yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter::new(
+            false,
+            "integer".to_string(),
+            "Foo".to_string(),
+            "integer".to_string(),
+            yukino::types::DatabaseType::UnsignedInteger,
+        )
+    }
+    pub fn get_map_converter() -> yukino::resolver::field_resolver_seeds::MapValueConverter {
+        yukino::resolver::field_resolver_seeds::MapValueConverter::new(
+            "Foo".to_string(),
+            "map".to_string(),
+            "map".to_string(),
+        )
+    }
+    pub fn get_list_converter() -> yukino::resolver::field_resolver_seeds::ListValueConverter {
+        yukino::resolver::field_resolver_seeds::ListValueConverter::new(
+            "Foo".to_string(),
+            "list".to_string(),
+            "list".to_string(),
+        )
+    }
+    pub fn get_option_num_converter(
+    ) -> yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter {
+        yukino::resolver::field_resolver_seeds::UnsignedIntegerValueConverter::new(
+            false,
+            "option_num".to_string(),
+            "Foo".to_string(),
+            "option_num".to_string(),
+            yukino::types::DatabaseType::UnsignedInteger,
+        )
+    }
+    pub fn get_hidden_primary_key_converter(
+    ) -> yukino::resolver::field_resolver_seeds::StringValueConverter {
+        yukino::resolver::field_resolver_seeds::StringValueConverter::new(
+            true,
+            "Foo".to_string(),
+            "__foo_id".to_string(),
+            "__foo_id".to_string(),
+            yukino::types::DatabaseType::String(255u32),
+        )
+    }
 }
 impl yukino::Entity for FooInner {
     fn from_database_value(
@@ -248,25 +290,27 @@ impl yukino::Entity for FooInner {
         Self: Sized,
     {
         use yukino::resolver::ValueConverter;
-        let option_num = Self::get_option_num_converter().to_field_value(result)?;
-        let list = Self::get_list_converter().to_field_value(result)?;
-        let integer = Self::get_integer_converter().to_field_value(result)?;
-        let map = Self::get_map_converter().to_field_value(result)?;
-        let option_string = Self::get_option_string_converter().to_field_value(result)?;
-        let int16 = Self::get_int16_converter().to_field_value(result)?;
         let string = Self::get_string_converter().to_field_value(result)?;
+        let int16 = Self::get_int16_converter().to_field_value(result)?;
+        let option_string = Self::get_option_string_converter().to_field_value(result)?;
         let boolean = Self::get_boolean_converter().to_field_value(result)?;
         let bar = Self::get_bar_converter().to_field_value(result)?;
+        let integer = Self::get_integer_converter().to_field_value(result)?;
+        let map = Self::get_map_converter().to_field_value(result)?;
+        let list = Self::get_list_converter().to_field_value(result)?;
+        let option_num = Self::get_option_num_converter().to_field_value(result)?;
+        let __foo_id = Self::get_hidden_primary_key_converter().to_field_value(result)?;
         Ok(FooInner {
-            option_num,
-            list,
-            integer,
-            map,
-            option_string,
-            int16,
             string,
+            int16,
+            option_string,
             boolean,
             bar,
+            integer,
+            map,
+            list,
+            option_num,
+            __foo_id,
         })
     }
     fn to_database_values(
@@ -277,17 +321,20 @@ impl yukino::Entity for FooInner {
     > {
         let mut map = std::collections::HashMap::new();
         use yukino::resolver::ValueConverter;
-        map.extend(Self::get_option_num_converter().to_database_values_by_ref(&self.option_num)?);
-        map.extend(Self::get_list_converter().to_database_values_by_ref(&self.list)?);
-        map.extend(Self::get_integer_converter().to_database_values_by_ref(&self.integer)?);
-        map.extend(Self::get_map_converter().to_database_values_by_ref(&self.map)?);
+        map.extend(Self::get_string_converter().to_database_values_by_ref(&self.string)?);
+        map.extend(Self::get_int16_converter().to_database_values_by_ref(&self.int16)?);
         map.extend(
             Self::get_option_string_converter().to_database_values_by_ref(&self.option_string)?,
         );
-        map.extend(Self::get_int16_converter().to_database_values_by_ref(&self.int16)?);
-        map.extend(Self::get_string_converter().to_database_values_by_ref(&self.string)?);
         map.extend(Self::get_boolean_converter().to_database_values_by_ref(&self.boolean)?);
         map.extend(Self::get_bar_converter().to_database_values_by_ref(&self.bar)?);
+        map.extend(Self::get_integer_converter().to_database_values_by_ref(&self.integer)?);
+        map.extend(Self::get_map_converter().to_database_values_by_ref(&self.map)?);
+        map.extend(Self::get_list_converter().to_database_values_by_ref(&self.list)?);
+        map.extend(Self::get_option_num_converter().to_database_values_by_ref(&self.option_num)?);
+        map.extend(
+            Self::get_hidden_primary_key_converter().to_database_values_by_ref(&self.__foo_id)?,
+        );
         Ok(map)
     }
     fn get_definitions() -> Vec<yukino::definitions::TableDefinition> {
@@ -296,94 +343,134 @@ impl yukino::Entity for FooInner {
             yukino::definitions::TableType::NormalEntityTable("Foo".to_string()),
             vec![
                 yukino::definitions::ColumnDefinition::new(
-                    "option_num".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("option_num".to_string()),
-                    yukino::types::DatabaseType::UnsignedInteger,
+                    "string".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("Foo".to_string()),
+                    yukino::types::DatabaseType::String(255u32),
                     false,
                     false,
                     false,
-                    true,
+                    false,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "list".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("list".to_string()),
-                    yukino::types::DatabaseType::Json,
+                    "int16".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("int16".to_string()),
+                    yukino::types::DatabaseType::SmallInteger,
                     false,
                     false,
                     false,
                     false,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "integer".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("integer".to_string()),
-                    yukino::types::DatabaseType::UnsignedInteger,
-                    false,
+                    "option_string".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("Foo".to_string()),
+                    yukino::types::DatabaseType::String(255u32),
                     false,
                     false,
                     false,
+                    true,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "map".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("map".to_string()),
-                    yukino::types::DatabaseType::Json,
+                    "boolean".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("Foo".to_string()),
+                    yukino::types::DatabaseType::Bool,
                     false,
                     false,
                     false,
                     false,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "option_string".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("Foo".to_string()),
-                    yukino::types::DatabaseType::String,
+                    "bar_id".to_string(),
+                    yukino::definitions::ColumnType::VisualColumn,
+                    yukino::types::DatabaseType::UnsignedBigInteger,
+                    true,
                     false,
                     false,
                     false,
-                    true,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "int16".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("int16".to_string()),
-                    yukino::types::DatabaseType::SmallInteger,
+                    "integer".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("integer".to_string()),
+                    yukino::types::DatabaseType::UnsignedInteger,
                     false,
                     false,
                     false,
                     false,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "string".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("Foo".to_string()),
-                    yukino::types::DatabaseType::String,
+                    "map".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("map".to_string()),
+                    yukino::types::DatabaseType::Json,
                     false,
                     false,
                     false,
                     false,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "boolean".to_string(),
-                    yukino::definitions::ColumnType::NormalColumn("Foo".to_string()),
-                    yukino::types::DatabaseType::Bool,
+                    "list".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("list".to_string()),
+                    yukino::types::DatabaseType::Json,
                     false,
                     false,
                     false,
                     false,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
-                    "bar_id".to_string(),
-                    yukino::definitions::ColumnType::VisualColumn,
-                    yukino::types::DatabaseType::UnsignedBigInteger,
-                    true,
+                    "option_num".to_string(),
+                    yukino::definitions::ColumnType::NormalColumn("option_num".to_string()),
+                    yukino::types::DatabaseType::UnsignedInteger,
                     false,
                     false,
                     false,
+                    true,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
                 yukino::definitions::ColumnDefinition::new(
                     "__foo_id".to_string(),
                     yukino::definitions::ColumnType::VisualColumn,
-                    yukino::types::DatabaseType::String,
+                    yukino::types::DatabaseType::String(255u32),
                     true,
                     false,
                     true,
                     false,
+                    None,
+                    None,
+                    None,
+                    vec![],
                 ),
             ],
             vec![],
@@ -391,105 +478,130 @@ impl yukino::Entity for FooInner {
                 "__bar".to_string(),
                 "bar".to_string(),
                 vec![("bar_id".to_string(), "id".to_string())],
+                yukino::annotations::ReferentialAction::NoAction,
+                yukino::annotations::ReferentialAction::NoAction,
             )],
+            vec![],
+            None,
+            None,
+            None,
         )]
     }
     fn get_field_definition(field_name: &str) -> Option<yukino::definitions::FieldDefinition> {
         match field_name {
-            "option_num" => Some(yukino::definitions::FieldDefinition::new(
-                "option_num".to_string(),
-                "option_num".to_string(),
-                "numeric".to_string(),
-                "u32".to_string(),
-                true,
-                vec!["option_num".to_string()],
-                vec![],
-                None,
-            )),
-            "list" => Some(yukino::definitions::FieldDefinition::new(
-                "list".to_string(),
-                "list".to_string(),
-                "".to_string(),
-                "Vec < String >".to_string(),
-                false,
-                vec!["list".to_string()],
-                vec![],
-                None,
-            )),
-            "integer" => Some(yukino::definitions::FieldDefinition::new(
-                "integer".to_string(),
-                "integer".to_string(),
-                "numeric".to_string(),
-                "u32".to_string(),
-                false,
-                vec!["integer".to_string()],
-                vec![],
-                None,
-            )),
-            "map" => Some(yukino::definitions::FieldDefinition::new(
-                "map".to_string(),
-                "map".to_string(),
-                "".to_string(),
-                "std :: collections :: HashMap < String , String >".to_string(),
-                false,
-                vec!["map".to_string()],
-                vec![],
-                None,
-            )),
-            "option_string" => Some(yukino::definitions::FieldDefinition::new(
-                "option_string".to_string(),
-                "option_string".to_string(),
+            "string" => Some(yukino::definitions::FieldDefinition::new(
+                "Foo".to_string(),
                 "string".to_string(),
                 "string".to_string(),
-                true,
-                vec!["option_string".to_string()],
+                "String".to_string(),
+                false,
+                Some(255u32),
+                None,
+                vec!["string".to_string()],
                 vec![],
                 None,
             )),
             "int16" => Some(yukino::definitions::FieldDefinition::new(
-                "int16".to_string(),
+                "Foo".to_string(),
                 "int16".to_string(),
                 "numeric".to_string(),
                 "i16".to_string(),
                 false,
+                None,
+                None,
                 vec!["int16".to_string()],
                 vec![],
                 None,
             )),
-            "string" => Some(yukino::definitions::FieldDefinition::new(
-                "string".to_string(),
-                "string".to_string(),
-                "string".to_string(),
+            "option_string" => Some(yukino::definitions::FieldDefinition::new(
+                "Foo".to_string(),
+                "option_string".to_string(),
                 "string".to_string(),
-                false,
-                vec!["string".to_string()],
+                "String".to_string(),
+                true,
+                Some(255u32),
+                None,
+                vec!["option_string".to_string()],
                 vec![],
                 None,
             )),
             "boolean" => Some(yukino::definitions::FieldDefinition::new(
-                "boolean".to_string(),
+                "Foo".to_string(),
                 "boolean".to_string(),
                 "bool".to_string(),
-                "string".to_string(),
+                "bool".to_string(),
                 false,
+                None,
+                None,
                 vec!["boolean".to_string()],
                 vec![],
                 None,
             )),
             "bar" => Some(yukino::definitions::FieldDefinition::new(
+                "Foo".to_string(),
                 "bar".to_string(),
-                "bar".to_string(),
-                "".to_string(),
+                "associated_object".to_string(),
                 "Bar".to_string(),
                 false,
+                None,
+                None,
                 vec!["bar_id".to_string()],
                 vec![],
                 Some(yukino::definitions::AssociationDefinition::new(
                     "Bar".to_string(),
                     false,
                     vec![("bar_id".to_string(), "id".to_string())],
+                    None,
                 )),
             )),
+            "integer" => Some(yukino::definitions::FieldDefinition::new(
+                "Foo".to_string(),
+                "integer".to_string(),
+                "numeric".to_string(),
+                "u32".to_string(),
+                false,
+                None,
+                None,
+                vec!["integer".to_string()],
+                vec![],
+                None,
+            )),
+            "map" => Some(yukino::definitions::FieldDefinition::new(
+                "Foo".to_string(),
+                "map".to_string(),
+                "json".to_string(),
+                "std :: collections :: HashMap < String , String >".to_string(),
+                false,
+                None,
+                None,
+                vec!["map".to_string()],
+                vec![],
+                None,
+            )),
+            "list" => Some(yukino::definitions::FieldDefinition::new(
+                "Foo".to_string(),
+                "list".to_string(),
+                "json".to_string(),
+                "Vec < String >".to_string(),
+                false,
+                None,
+                None,
+                vec!["list".to_string()],
+                vec![],
+                None,
+            )),
+            "option_num" => Some(yukino::definitions::FieldDefinition::new(
+                "Foo".to_string(),
+                "option_num".to_string(),
+                "numeric".to_string(),
+                "u32".to_string(),
+                true,
+                None,
+                None,
+                vec!["option_num".to_string()],
+                vec![],
+                None,
+            )),
             _ => None,
         }
     }
@@ -499,10 +611,74 @@ impl yukino::Entity for FooInner {
         std::collections::HashMap<String, yukino::types::DatabaseValue>,
         yukino::resolver::error::DataConvertError,
     > {
-        let map = std::collections::HashMap::new();
+        let mut map = std::collections::HashMap::new();
+        use yukino::resolver::ValueConverter;
+        map.extend(
+            Self::get_hidden_primary_key_converter()
+                .primary_column_values_by_ref(&self.__foo_id)?,
+        );
         Ok(map)
     }
 }
+#[derive(Clone)]
+pub struct FooFields {
+    segments: Vec<String>,
+}
+impl FooFields {
+    pub fn from_segments(segments: Vec<String>) -> Self {
+        FooFields { segments }
+    }
+    pub fn bar(&self) -> BarFields {
+        let mut segments = self.segments.clone();
+        segments.push("bar".to_string());
+        BarFields::from_segments(segments)
+    }
+    pub fn boolean(&self) -> yukino::query::Field<FooInner, bool> {
+        let mut segments = self.segments.clone();
+        segments.push("boolean".to_string());
+        yukino::query::Field::new(segments)
+    }
+    pub fn int16(&self) -> yukino::query::Field<FooInner, i16> {
+        let mut segments = self.segments.clone();
+        segments.push("int16".to_string());
+        yukino::query::Field::new(segments)
+    }
+    pub fn integer(&self) -> yukino::query::Field<FooInner, u32> {
+        let mut segments = self.segments.clone();
+        segments.push("integer".to_string());
+        yukino::query::Field::new(segments)
+    }
+    pub fn list(&self) -> yukino::query::Field<FooInner, Vec<String>> {
+        let mut segments = self.segments.clone();
+        segments.push("list".to_string());
+        yukino::query::Field::new(segments)
+    }
+    pub fn map(&self) -> yukino::query::Field<FooInner, std::collections::HashMap<String, String>> {
+        let mut segments = self.segments.clone();
+        segments.push("map".to_string());
+        yukino::query::Field::new(segments)
+    }
+    pub fn option_num(&self) -> yukino::query::Field<FooInner, Option<u32>> {
+        let mut segments = self.segments.clone();
+        segments.push("option_num".to_string());
+        yukino::query::Field::new(segments)
+    }
+    pub fn option_string(&self) -> yukino::query::Field<FooInner, Option<String>> {
+        let mut segments = self.segments.clone();
+        segments.push("option_string".to_string());
+        yukino::query::Field::new(segments)
+    }
+    pub fn string(&self) -> yukino::query::Field<FooInner, String> {
+        let mut segments = self.segments.clone();
+        segments.push("string".to_string());
+        yukino::query::Field::new(segments)
+    }
+}
+impl FooInner {
+    pub fn fields(alias: &str) -> FooFields {
+        FooFields::from_segments(vec![alias.to_string()])
+    }
+}
 pub struct Foo<'t> {
     inner: std::cell::UnsafeCell<FooInner>,
     unique_id: Option<yukino::EntityUniqueID>,
@@ -536,49 +712,13 @@ impl<'t> yukino::EntityProxy<'t, FooInner> for Foo<'t> {
     }
 }
 impl<'t> Foo<'t> {
-    pub fn get_option_num(&self) -> Option<u32> {
-        let inner = self.get_inner();
-        inner.option_num
-    }
-    pub fn set_option_num(&mut self, value: u32) -> &mut Self {
-        let inner = self.get_inner_mut();
-        inner.option_num = Some(value);
-        self
-    }
-    pub fn get_list(&self) -> &Vec<String> {
-        let inner = self.get_inner();
-        &inner.list
-    }
-    pub fn set_list(&mut self, value: Vec<String>) -> &mut Self {
-        let inner = self.get_inner_mut();
-        inner.list = value;
-        self
-    }
-    pub fn get_integer(&self) -> u32 {
-        let inner = self.get_inner();
-        inner.integer
-    }
-    pub fn set_integer(&mut self, value: u32) -> &mut Self {
-        let inner = self.get_inner_mut();
-        inner.integer = value;
-        self
-    }
-    pub fn get_map(&self) -> &std::collections::HashMap<String, String> {
-        let inner = self.get_inner();
-        &inner.map
-    }
-    pub fn set_map(&mut self, value: std::collections::HashMap<String, String>) -> &mut Self {
-        let inner = self.get_inner_mut();
-        inner.map = value;
-        self
-    }
-    pub fn get_option_string(&self) -> &Option<String> {
+    pub fn get_string(&self) -> &String {
         let inner = self.get_inner();
-        &inner.option_string
+        &inner.string
     }
-    pub fn set_option_string(&mut self, value: String) -> &mut Self {
+    pub fn set_string(&mut self, value: String) -> &mut Self {
         let inner = self.get_inner_mut();
-        inner.option_string = Some(value);
+        inner.string = value;
         self
     }
     pub fn get_int16(&self) -> i16 {
@@ -590,13 +730,13 @@ impl<'t> Foo<'t> {
         inner.int16 = value;
         self
     }
-    pub fn get_string(&self) -> &String {
+    pub fn get_option_string(&self) -> &Option<String> {
         let inner = self.get_inner();
-        &inner.string
+        &inner.option_string
     }
-    pub fn set_string(&mut self, value: String) -> &mut Self {
+    pub fn set_option_string(&mut self, value: String) -> &mut Self {
         let inner = self.get_inner_mut();
-        inner.string = value;
+        inner.option_string = Some(value);
         self
     }
     pub fn get_boolean(&self) -> &bool {
@@ -629,27 +769,64 @@ impl<'t> Foo<'t> {
         mut_inner.bar = yukino::collection::AssociatedEntity::Resolved(value.inner());
         self
     }
+    pub fn get_integer(&self) -> u32 {
+        let inner = self.get_inner();
+        inner.integer
+    }
+    pub fn set_integer(&mut self, value: u32) -> &mut Self {
+        let inner = self.get_inner_mut();
+        inner.integer = value;
+        self
+    }
+    pub fn get_map(&self) -> &std::collections::HashMap<String, String> {
+        let inner = self.get_inner();
+        &inner.map
+    }
+    pub fn set_map(&mut self, value: std::collections::HashMap<String, String>) -> &mut Self {
+        let inner = self.get_inner_mut();
+        inner.map = value;
+        self
+    }
+    pub fn get_list(&self) -> &Vec<String> {
+        let inner = self.get_inner();
+        &inner.list
+    }
+    pub fn set_list(&mut self, value: Vec<String>) -> &mut Self {
+        let inner = self.get_inner_mut();
+        inner.list = value;
+        self
+    }
+    pub fn get_option_num(&self) -> Option<u32> {
+        let inner = self.get_inner();
+        inner.option_num
+    }
+    pub fn set_option_num(&mut self, value: u32) -> &mut Self {
+        let inner = self.get_inner_mut();
+        inner.option_num = Some(value);
+        self
+    }
     pub fn with_value(
-        option_num: Option<u32>,
-        list: Vec<String>,
-        integer: u32,
-        map: std::collections::HashMap<String, String>,
-        option_string: Option<String>,
-        int16: i16,
         string: String,
+        int16: i16,
+        option_string: Option<String>,
         boolean: bool,
         bar: yukino::collection::AssociatedEntity<BarInner>,
+        integer: u32,
+        map: std::collections::HashMap<String, String>,
+        list: Vec<String>,
+        option_num: Option<u32>,
     ) -> impl FnOnce() -> FooInner {
         move || FooInner {
-            option_num,
-            list,
-            integer,
-            map,
-            option_string,
-            int16,
             string,
+            int16,
+            option_string,
             boolean,
             bar,
+            integer,
+            map,
+            list,
+            option_num,
+            __foo_id: String::new(),
         }
     }
     fn get_inner(&self) -> &FooInner {